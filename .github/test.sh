@@ -27,6 +27,9 @@ cargo test --features rc,unstable__schema 'schema::test_rc'
 cargo test --features indexmap 'roundtrip::test_indexmap'
 # checking with `derive` too just for the sake of redundancy https://github.com/near/borsh-rs/pull/346
 cargo test --features indexmap,derive 'roundtrip::test_indexmap'
+########## features = ["hashbrown"] group
+cargo test --features hashbrown
+cargo test --features hashbrown,unstable__schema
 ########## features = ["de_strict_order"] group
 cargo test --features de_strict_order 'roundtrip::test_hash_map'
 cargo test --features de_strict_order 'roundtrip::test_btree_map'
@@ -34,8 +37,35 @@ cargo test --features de_strict_order 'roundtrip::test_btree_map'
 cargo test --features bson,derive 'roundtrip::requires_derive_category::test_bson_object_ids'
 ########## features = ["bytes"] group
 cargo test --features bytes,derive 'roundtrip::requires_derive_category::test_ultimate_many_features_combined'
+cargo test --features bytes 'roundtrip::test_bytes_buf'
 ########## features = ["uuid"] group
 cargo test --features uuid,derive 'roundtrip::test_uuid'
+########## features = ["primitive-types", "ethnum", "num-bigint"] group
+cargo test --features primitive-types,ethnum,num-bigint
+cargo test --features primitive-types,ethnum,num-bigint,unstable__schema 'schema::test_big_integers'
+########## features = ["chrono", "time", "jiff"] group
+cargo test --features chrono,time,jiff
+cargo test --features chrono,time,jiff,unstable__schema 'schema::test_date_time'
+########## features = ["smallvec", "arrayvec", "tinyvec", "heapless"] group
+cargo test --features smallvec,arrayvec,tinyvec,heapless
+cargo test --features smallvec,arrayvec,tinyvec,heapless,unstable__schema 'schema::test_inline_collections'
+########## features = ["serde_json"] group
+cargo test --features serde_json,derive
+cargo test --features serde_json,de_strict_order 'deserialization_errors::test_serde_json'
+cargo test --features serde_json,unstable__schema 'schema::test_serde_json'
+########## features = ["rust_decimal", "ordered-float", "half"] group
+cargo test --features rust_decimal,ordered-float,half
+cargo test --features rust_decimal,ordered-float,half,unstable__schema 'schema::test_decimal_and_floats'
+########## features = ["bitflags", "bitvec"] group
+cargo test --features bitflags,bitvec,derive
+cargo test --features bitflags,bitvec,unstable__schema 'schema::test_bit_types'
+########## features = ["im", "rpds"] group
+cargo test --features im,rpds
+cargo test --features im,rpds,de_strict_order
+cargo test --features im,rpds,unstable__schema 'schema::test_persistent_collections'
+########## features = ["smol_str", "compact_str"] group
+cargo test --features smol_str,compact_str
+cargo test --features smol_str,compact_str,unstable__schema 'schema::test_compact_strings'
 
 ############################ borsh `default-features = false` group #########################
 ########## general group
@@ -59,8 +89,33 @@ cargo test --no-default-features --features hashbrown,derive
 cargo test --no-default-features --features hashbrown,unstable__schema
 ########## features = ["bytes"] group
 cargo test --no-default-features --features bytes,derive 'roundtrip::requires_derive_category::test_ultimate_many_features_combined'
+cargo test --no-default-features --features bytes 'roundtrip::test_bytes_buf'
 ########## features = ["uuid"] group
 cargo test --no-default-features --features uuid,derive 'roundtrip::test_uuid'
+########## features = ["primitive-types", "ethnum", "num-bigint"] group
+cargo test --no-default-features --features primitive-types,ethnum,num-bigint
+cargo test --no-default-features --features primitive-types,ethnum,num-bigint,unstable__schema 'schema::test_big_integers'
+########## features = ["chrono", "time", "jiff"] group
+cargo test --no-default-features --features chrono,time,jiff
+cargo test --no-default-features --features chrono,time,jiff,unstable__schema 'schema::test_date_time'
+########## features = ["smallvec", "arrayvec", "tinyvec", "heapless"] group
+cargo test --no-default-features --features smallvec,arrayvec,tinyvec,heapless
+cargo test --no-default-features --features smallvec,arrayvec,tinyvec,heapless,unstable__schema 'schema::test_inline_collections'
+########## features = ["serde_json"] group
+cargo test --no-default-features --features serde_json
+cargo test --no-default-features --features serde_json,unstable__schema 'schema::test_serde_json'
+########## features = ["rust_decimal", "ordered-float", "half"] group
+cargo test --no-default-features --features rust_decimal,ordered-float,half
+cargo test --no-default-features --features rust_decimal,ordered-float,half,unstable__schema 'schema::test_decimal_and_floats'
+########## features = ["bitflags", "bitvec"] group
+cargo test --no-default-features --features bitflags,bitvec,derive
+cargo test --no-default-features --features bitflags,bitvec,unstable__schema 'schema::test_bit_types'
+########## features = ["rpds"] group
+cargo test --no-default-features --features rpds
+cargo test --no-default-features --features rpds,unstable__schema 'schema::test_persistent_collections'
+########## features = ["smol_str", "compact_str"] group
+cargo test --no-default-features --features smol_str,compact_str
+cargo test --no-default-features --features smol_str,compact_str,unstable__schema 'schema::test_compact_strings'
 popd
 pushd borsh-derive
 ############################ borsh-derive group #########################
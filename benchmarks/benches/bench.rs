@@ -1,5 +1,5 @@
 use benchmarks::{Account, Block, BlockHeader, Generate, SignedTransaction};
-use borsh::{from_slice, to_vec, BorshDeserialize, BorshSerialize};
+use borsh::{from_reader, from_slice, to_vec, BorshDeserialize, BorshSerialize};
 use rand::SeedableRng;
 use serde::{Deserialize as SerdeDeserialize, Serialize as SerdeSerialize};
 use speedy::Endianness;
@@ -126,6 +126,13 @@ where
                 b.iter(|| from_slice::<T>(d).unwrap());
             },
         );
+        group.bench_with_input(
+            BenchmarkId::new("borsh_reader", benchmark_param_display.clone()),
+            borsh_data,
+            |b, d| {
+                b.iter(|| from_reader::<_, T>(&mut d.as_slice()).unwrap());
+            },
+        );
         group.bench_with_input(
             BenchmarkId::new("speedy", benchmark_param_display),
             speedy_data,
@@ -0,0 +1,136 @@
+//! Compares bulk encoding of vectors and arrays of primitive numbers
+//! with encoding them element by element.
+use borsh::{from_slice, to_vec, BorshDeserialize, BorshSerialize};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::distributions::{Distribution, Standard};
+use rand::{Rng, SeedableRng};
+
+fn ser_elementwise<T: BorshSerialize>(data: &[T]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(4 + data.len() * core::mem::size_of::<T>());
+    (data.len() as u32).serialize(&mut buf).unwrap();
+    for el in data {
+        el.serialize(&mut buf).unwrap();
+    }
+    buf
+}
+
+fn de_elementwise<T: BorshDeserialize>(mut buf: &[u8]) -> Vec<T> {
+    let len = u32::deserialize(&mut buf).unwrap();
+    (0..len)
+        .map(|_| T::deserialize(&mut buf).unwrap())
+        .collect()
+}
+
+fn bulk_vec<T>(group_name: &str, lens: &[usize], c: &mut Criterion)
+where
+    T: BorshSerialize + BorshDeserialize + 'static,
+    Standard: Distribution<T>,
+{
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([0u8; 16]);
+    let mut group = c.benchmark_group(group_name);
+
+    for &len in lens {
+        let data: Vec<T> = (0..len).map(|_| rng.gen()).collect();
+        let encoded = to_vec(&data).unwrap();
+        assert_eq!(encoded, ser_elementwise(&data));
+
+        let benchmark_param_display = format!("len={}; size={}", len, encoded.len());
+
+        group.throughput(Throughput::Bytes(encoded.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("ser_bulk", benchmark_param_display.clone()),
+            &data,
+            |b, d| {
+                b.iter(|| to_vec(d).unwrap());
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("ser_elementwise", benchmark_param_display.clone()),
+            &data,
+            |b, d| {
+                b.iter(|| ser_elementwise(d));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("de_bulk", benchmark_param_display.clone()),
+            &encoded,
+            |b, d| {
+                b.iter(|| from_slice::<Vec<T>>(d).unwrap());
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("de_elementwise", benchmark_param_display),
+            &encoded,
+            |b, d| {
+                b.iter(|| de_elementwise::<T>(d));
+            },
+        );
+    }
+    group.finish();
+}
+
+fn vec_u64(c: &mut Criterion) {
+    bulk_vec::<u64>("vec_u64", &[16, 1024, 65536], c);
+}
+
+fn vec_i128(c: &mut Criterion) {
+    bulk_vec::<i128>("vec_i128", &[16, 1024, 65536], c);
+}
+
+fn vec_f32(c: &mut Criterion) {
+    bulk_vec::<f32>("vec_f32", &[16, 1024, 65536], c);
+}
+
+/// Matrix-like data: rows of a `Vec<Vec<f64>>` and of a `[[f32; 32]; 32]`
+/// are encoded in bulk.
+fn matrix(c: &mut Criterion) {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([0u8; 16]);
+    let mut group = c.benchmark_group("matrix");
+
+    let rows: Vec<Vec<f64>> = (0..256)
+        .map(|_| (0..256).map(|_| rng.gen()).collect())
+        .collect();
+    let encoded = to_vec(&rows).unwrap();
+    group.throughput(Throughput::Bytes(encoded.len() as u64));
+    group.bench_with_input(
+        BenchmarkId::new("ser", "Vec<Vec<f64>>; 256x256"),
+        &rows,
+        |b, d| {
+            b.iter(|| to_vec(d).unwrap());
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("de", "Vec<Vec<f64>>; 256x256"),
+        &encoded,
+        |b, d| {
+            b.iter(|| from_slice::<Vec<Vec<f64>>>(d).unwrap());
+        },
+    );
+
+    let mut square = [[0f32; 32]; 32];
+    for row in square.iter_mut() {
+        for el in row.iter_mut() {
+            *el = rng.gen();
+        }
+    }
+    let encoded = to_vec(&square).unwrap();
+    group.throughput(Throughput::Bytes(encoded.len() as u64));
+    group.bench_with_input(
+        BenchmarkId::new("ser", "[[f32; 32]; 32]"),
+        &square,
+        |b, d| {
+            b.iter(|| to_vec(d).unwrap());
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("de", "[[f32; 32]; 32]"),
+        &encoded,
+        |b, d| {
+            b.iter(|| from_slice::<[[f32; 32]; 32]>(d).unwrap());
+        },
+    );
+    group.finish();
+}
+
+criterion_group!(primitive_slices, vec_u64, vec_i128, vec_f32, matrix);
+criterion_main!(primitive_slices);
@@ -1,14 +1,14 @@
 use std::collections::BTreeMap;
 
 use once_cell::sync::Lazy;
-use syn::{meta::ParseNestedMeta, Attribute, WherePredicate};
+use syn::{meta::ParseNestedMeta, spanned::Spanned, Attribute, Fields, WherePredicate};
 
 use self::bounds::BOUNDS_FIELD_PARSE_MAP;
 
 use super::{
     get_one_attribute,
     parsing::{attr_get_by_symbol_keys, meta_get_by_symbol_keys, parse_lit_into},
-    BoundType, Symbol, BORSH, BOUND, DESERIALIZE_WITH, SERIALIZE_WITH, SKIP,
+    BoundType, Symbol, BORSH, BOUND, DESERIALIZE_WITH, SERIALIZE_WITH, SKIP, TRAILING_DEFAULT,
 };
 
 #[cfg(feature = "schema")]
@@ -26,6 +26,7 @@ enum Variants {
     SerializeWith(syn::ExprPath),
     DeserializeWith(syn::ExprPath),
     Skip(()),
+    TrailingDefault(()),
     #[cfg(feature = "schema")]
     Schema(schema::Attributes),
 }
@@ -64,7 +65,10 @@ static BORSH_FIELD_PARSE_MAP: Lazy<BTreeMap<Symbol, Box<ParseFn>>> = Lazy::new(|
     m.insert(BOUND, f_bounds);
     m.insert(SERIALIZE_WITH, f_serialize_with);
     m.insert(DESERIALIZE_WITH, f_deserialize_with);
+    let f_trailing_default: Box<ParseFn> =
+        Box::new(|_attr_name, _meta_item_name, _meta| Ok(Variants::TrailingDefault(())));
     m.insert(SKIP, f_skip);
+    m.insert(TRAILING_DEFAULT, f_trailing_default);
     #[cfg(feature = "schema")]
     m.insert(SCHEMA, f_schema);
     m
@@ -76,6 +80,7 @@ pub(crate) struct Attributes {
     pub serialize_with: Option<syn::ExprPath>,
     pub deserialize_with: Option<syn::ExprPath>,
     pub skip: bool,
+    pub trailing_default: bool,
     #[cfg(feature = "schema")]
     pub schema: Option<schema::Attributes>,
 }
@@ -86,6 +91,7 @@ impl From<BTreeMap<Symbol, Variants>> for Attributes {
         let serialize_with = map.remove(&SERIALIZE_WITH);
         let deserialize_with = map.remove(&DESERIALIZE_WITH);
         let skip = map.remove(&SKIP);
+        let trailing_default = map.remove(&TRAILING_DEFAULT);
         let bounds = bounds.map(|variant| match variant {
             Variants::Bounds(bounds) => bounds,
             _ => unreachable!("only one enum variant is expected to correspond to given map key"),
@@ -106,6 +112,11 @@ impl From<BTreeMap<Symbol, Variants>> for Attributes {
             _ => unreachable!("only one enum variant is expected to correspond to given map key"),
         });
 
+        let trailing_default = trailing_default.map(|variant| match variant {
+            Variants::TrailingDefault(trailing_default) => trailing_default,
+            _ => unreachable!("only one enum variant is expected to correspond to given map key"),
+        });
+
         #[cfg(feature = "schema")]
         let schema = {
             let schema = map.remove(&SCHEMA);
@@ -121,6 +132,7 @@ impl From<BTreeMap<Symbol, Variants>> for Attributes {
             serialize_with,
             deserialize_with,
             skip: skip.is_some(),
+            trailing_default: trailing_default.is_some(),
             #[cfg(feature = "schema")]
             schema,
         }
@@ -145,6 +157,15 @@ impl Attributes {
                 ),
             ));
         }
+        if self.skip && self.trailing_default {
+            return Err(syn::Error::new_spanned(
+                attr,
+                format!(
+                    "`{}` cannot be used at the same time as `{}`",
+                    SKIP.0, TRAILING_DEFAULT.0
+                ),
+            ));
+        }
 
         #[cfg(feature = "schema")]
         self.check_schema(attr)?;
@@ -182,6 +203,27 @@ impl Attributes {
     }
 }
 
+/// checks that fields, annotated with `#[borsh(trailing_default)]`, are only followed
+/// by other such fields or by skipped fields
+pub(crate) fn check_trailing_default(fields: &Fields) -> Result<(), syn::Error> {
+    let mut trailing = false;
+    for field in fields {
+        let parsed = Attributes::parse(&field.attrs)?;
+        if parsed.trailing_default {
+            trailing = true;
+        } else if trailing && !parsed.skip {
+            return Err(syn::Error::new(
+                field.span(),
+                format!(
+                    "field without `{0}` cannot follow a field with `{0}`",
+                    TRAILING_DEFAULT.0
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
 #[cfg(feature = "schema")]
 impl Attributes {
     fn check_schema(&self, attr: &Attribute) -> Result<(), syn::Error> {
@@ -451,6 +493,47 @@ mod tests {
         assert!(result.skip);
     }
     #[test]
+    fn test_borsh_trailing_default() {
+        let item_struct: ItemStruct = syn::parse2(quote! {
+            struct A {
+                x: u64,
+                #[borsh(trailing_default)]
+                y: String,
+            }
+        })
+        .unwrap();
+
+        let fields = item_struct.fields.iter().collect::<Vec<_>>();
+        assert!(
+            !Attributes::parse(&fields[0].attrs)
+                .unwrap()
+                .trailing_default
+        );
+        assert!(
+            Attributes::parse(&fields[1].attrs)
+                .unwrap()
+                .trailing_default
+        );
+        assert!(super::check_trailing_default(&item_struct.fields).is_ok());
+    }
+    #[test]
+    fn test_borsh_trailing_default_with_skip() {
+        let item_struct: ItemStruct = syn::parse2(quote! {
+            struct A {
+                #[borsh(skip, trailing_default)]
+                x: u64,
+            }
+        })
+        .unwrap();
+
+        let first_field = &item_struct.fields.into_iter().collect::<Vec<_>>()[0];
+        let err = match Attributes::parse(&first_field.attrs) {
+            Ok(..) => unreachable!("expecting error here"),
+            Err(err) => err,
+        };
+        local_insta_assert_debug_snapshot!(err);
+    }
+    #[test]
     fn test_borsh_no_skip() {
         let item_struct: ItemStruct = syn::parse2(quote! {
             struct A {
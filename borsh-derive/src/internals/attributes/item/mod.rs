@@ -1,6 +1,9 @@
-use crate::internals::attributes::{BORSH, CRATE, INIT, USE_DISCRIMINANT};
+use crate::internals::attributes::{BORSH, CRATE, INIT, MIGRATE_FROM, USE_DISCRIMINANT, VERSION};
 use quote::ToTokens;
-use syn::{spanned::Spanned, Attribute, DeriveInput, Error, Expr, ItemEnum, Path};
+use syn::{
+    meta::ParseNestedMeta, spanned::Spanned, Attribute, DeriveInput, Error, Expr, ItemEnum, LitInt,
+    Path,
+};
 
 use super::{get_one_attribute, parsing};
 
@@ -9,10 +12,15 @@ pub fn check_attributes(derive_input: &DeriveInput) -> Result<(), Error> {
 
     if let Some(attr) = borsh {
         attr.parse_nested_meta(|meta| {
-            if meta.path != USE_DISCRIMINANT && meta.path != INIT && meta.path != CRATE {
+            if meta.path != USE_DISCRIMINANT
+                && meta.path != INIT
+                && meta.path != CRATE
+                && meta.path != VERSION
+                && meta.path != MIGRATE_FROM
+            {
                 return Err(syn::Error::new(
                     meta.path.span(),
-                    "`crate`, `use_discriminant` or `init` are the only supported attributes for `borsh`",
+                    "`crate`, `use_discriminant`, `init`, `version` or `migrate_from` are the only supported attributes for `borsh`",
                 ));
             }
             if meta.path == USE_DISCRIMINANT {
@@ -23,6 +31,18 @@ pub fn check_attributes(derive_input: &DeriveInput) -> Result<(), Error> {
                         "borsh(use_discriminant=<bool>) does not support structs",
                     ));
                 }
+            } else if meta.path == VERSION || meta.path == MIGRATE_FROM {
+                if meta.path == VERSION {
+                    let _expr: Expr = meta.value()?.parse()?;
+                } else {
+                    skip_migrate_from(&meta)?;
+                }
+                if !matches!(derive_input.data, syn::Data::Struct(..)) {
+                    return Err(syn::Error::new(
+                        derive_input.ident.span(),
+                        "borsh(version=<u8>) and borsh(migrate_from(...)) support only structs",
+                    ));
+                }
             } else if meta.path == INIT || meta.path == CRATE {
                 let _expr: Expr = meta.value()?.parse()?;
             }
@@ -33,6 +53,14 @@ pub fn check_attributes(derive_input: &DeriveInput) -> Result<(), Error> {
     Ok(())
 }
 
+/// consumes `migrate_from(...)` nested meta, which is parsed by [contains_version]
+fn skip_migrate_from(meta: &ParseNestedMeta) -> syn::Result<()> {
+    meta.parse_nested_meta(|meta| {
+        let _value_expr: Expr = meta.value()?.parse()?;
+        Ok(())
+    })
+}
+
 pub(crate) fn contains_use_discriminant(input: &ItemEnum) -> Result<bool, syn::Error> {
     if input.variants.len() > 256 {
         return Err(syn::Error::new(
@@ -61,8 +89,10 @@ pub(crate) fn contains_use_discriminant(input: &ItemEnum) -> Result<bool, syn::E
                         ));
                     }
                 };
-            } else if meta.path == INIT || meta.path == CRATE {
+            } else if meta.path == INIT || meta.path == CRATE || meta.path == VERSION {
                 let _value_expr: Expr = meta.value()?.parse()?;
+            } else if meta.path == MIGRATE_FROM {
+                skip_migrate_from(&meta)?;
             }
             Ok(())
         })?;
@@ -88,8 +118,10 @@ pub(crate) fn contains_initialize_with(attrs: &[Attribute]) -> Result<Option<Pat
             if meta.path == INIT {
                 let value_expr: Path = meta.value()?.parse()?;
                 res = Some(value_expr);
-            } else if meta.path == USE_DISCRIMINANT || meta.path == CRATE {
+            } else if meta.path == USE_DISCRIMINANT || meta.path == CRATE || meta.path == VERSION {
                 let _value_expr: Expr = meta.value()?.parse()?;
+            } else if meta.path == MIGRATE_FROM {
+                skip_migrate_from(&meta)?;
             }
 
             Ok(())
@@ -107,8 +139,10 @@ pub(crate) fn get_crate(attrs: &[Attribute]) -> Result<Option<Path>, Error> {
             if meta.path == CRATE {
                 let value_expr: Path = parsing::parse_lit_into(BORSH, CRATE, &meta)?;
                 res = Some(value_expr);
-            } else if meta.path == USE_DISCRIMINANT || meta.path == INIT {
+            } else if meta.path == USE_DISCRIMINANT || meta.path == INIT || meta.path == VERSION {
                 let _value_expr: Expr = meta.value()?.parse()?;
+            } else if meta.path == MIGRATE_FROM {
+                skip_migrate_from(&meta)?;
             }
 
             Ok(())
@@ -118,6 +152,67 @@ pub(crate) fn get_crate(attrs: &[Attribute]) -> Result<Option<Path>, Error> {
     Ok(res)
 }
 
+/// version of a struct, annotated with `#[borsh(version = ...)]`, which prefixes its serialized
+/// representation, and previous versions, listed in `#[borsh(migrate_from(...))]`
+pub(crate) struct Version {
+    pub current: u8,
+    /// types of previous versions, paired with their version numbers
+    pub migrate_from: Vec<(Path, u8)>,
+}
+
+pub(crate) fn contains_version(attrs: &[Attribute]) -> Result<Option<Version>, Error> {
+    let mut current = None;
+    let mut migrate_from: Vec<(Path, LitInt)> = vec![];
+    let attr = attrs.iter().find(|attr| attr.path() == BORSH);
+    if let Some(attr) = attr {
+        attr.parse_nested_meta(|meta| {
+            if meta.path == VERSION {
+                let value: LitInt = meta.value()?.parse()?;
+                current = Some(value);
+            } else if meta.path == MIGRATE_FROM {
+                meta.parse_nested_meta(|meta| {
+                    let value: LitInt = meta.value()?.parse()?;
+                    migrate_from.push((meta.path, value));
+                    Ok(())
+                })?;
+            } else if meta.path == USE_DISCRIMINANT || meta.path == INIT || meta.path == CRATE {
+                let _value_expr: Expr = meta.value()?.parse()?;
+            }
+            Ok(())
+        })?;
+    }
+    let current = match (current, migrate_from.first()) {
+        (Some(current), _) => current,
+        (None, Some((path, _))) => {
+            return Err(syn::Error::new_spanned(
+                path,
+                format!("`{}` requires `{}`", MIGRATE_FROM.0, VERSION.1),
+            ));
+        }
+        (None, None) => return Ok(None),
+    };
+    let mut result = Version {
+        current: current.base10_parse()?,
+        migrate_from: vec![],
+    };
+    for (path, value) in migrate_from {
+        let version: u8 = value.base10_parse()?;
+        if version == result.current
+            || result
+                .migrate_from
+                .iter()
+                .any(|(_, previous)| *previous == version)
+        {
+            return Err(syn::Error::new(
+                value.span(),
+                format!("version {} is specified more than once", version),
+            ));
+        }
+        result.migrate_from.push((path, version));
+    }
+    Ok(Some(result))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::internals::test_helpers::local_insta_assert_debug_snapshot;
@@ -408,4 +503,74 @@ mod tests {
             "reexporter :: borsh"
         );
     }
+
+    #[test]
+    fn test_version() {
+        let item_struct = syn::parse2::<DeriveInput>(quote! {
+            #[borsh(version = 3, migrate_from(StateV1 = 1, old::StateV2 = 2), crate = "borsh")]
+            struct State {
+                x: u64,
+            }
+        })
+        .unwrap();
+        assert!(check_attributes(&item_struct).is_ok());
+        assert!(contains_initialize_with(&item_struct.attrs)
+            .unwrap()
+            .is_none());
+        assert!(get_crate(&item_struct.attrs).unwrap().is_some());
+
+        let version = contains_version(&item_struct.attrs).unwrap().unwrap();
+        assert_eq!(version.current, 3);
+        let migrate_from = version
+            .migrate_from
+            .iter()
+            .map(|(path, version)| (path.to_token_stream().to_string(), *version))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            migrate_from,
+            vec![
+                ("StateV1".to_string(), 1),
+                ("old :: StateV2".to_string(), 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_version_duplicate() {
+        let item_struct = syn::parse2::<DeriveInput>(quote! {
+            #[borsh(version = 2, migrate_from(StateV1 = 1, StateV2 = 2))]
+            struct State {
+                x: u64,
+            }
+        })
+        .unwrap();
+        let actual = contains_version(&item_struct.attrs);
+        local_insta_assert_debug_snapshot!(actual.err().unwrap());
+    }
+
+    #[test]
+    fn test_migrate_from_without_version() {
+        let item_struct = syn::parse2::<DeriveInput>(quote! {
+            #[borsh(migrate_from(StateV1 = 1))]
+            struct State {
+                x: u64,
+            }
+        })
+        .unwrap();
+        let actual = contains_version(&item_struct.attrs);
+        local_insta_assert_debug_snapshot!(actual.err().unwrap());
+    }
+
+    #[test]
+    fn test_check_attrs_version_on_enum() {
+        let item_enum = syn::parse2::<DeriveInput>(quote! {
+            #[borsh(version = 1)]
+            enum A {
+                B,
+            }
+        })
+        .unwrap();
+        let actual = check_attributes(&item_enum);
+        local_insta_assert_debug_snapshot!(actual.unwrap_err());
+    }
 }
@@ -3,6 +3,7 @@ use syn::{Attribute, Path};
 pub mod field;
 pub mod item;
 pub mod parsing;
+pub mod variant;
 
 /// first field is attr name
 /// second field is its expected value format representation for error printing
@@ -19,9 +20,15 @@ pub const USE_DISCRIMINANT: Symbol = Symbol("use_discriminant", "use_discriminan
 pub const SERIALIZE: Symbol = Symbol("serialize", "serialize = ...");
 /// deserialize - sub-bound nested meta attribute
 pub const DESERIALIZE: Symbol = Symbol("deserialize", "deserialize = ...");
-/// skip - sub-borsh nested meta, field-level only attribute, `BorshSerialize`, `BorshDeserialize`, `BorshSchema` contexts
+/// skip - sub-borsh nested meta, field-level and variant-level attribute, `BorshSerialize`, `BorshDeserialize`, `BorshSchema` contexts
 pub const SKIP: Symbol = Symbol("skip", "skip");
+/// trailing_default - sub-borsh nested meta, field-level only attribute, `BorshDeserialize`, `BorshSchema` contexts
+pub const TRAILING_DEFAULT: Symbol = Symbol("trailing_default", "trailing_default");
 /// init - sub-borsh nested meta, item-level only attribute  `BorshDeserialize` context
+/// version - sub-borsh nested meta, item-level only, structs only, `BorshSerialize`, `BorshDeserialize`, `BorshSchema` contexts
+pub const VERSION: Symbol = Symbol("version", "version = ...");
+/// migrate_from - sub-borsh nested meta, item-level only, structs only, `BorshDeserialize`, `BorshSchema` contexts
+pub const MIGRATE_FROM: Symbol = Symbol("migrate_from", "migrate_from(...)");
 pub const INIT: Symbol = Symbol("init", "init = ...");
 /// serialize_with - sub-borsh nested meta, field-level only, `BorshSerialize` context
 pub const SERIALIZE_WITH: Symbol = Symbol("serialize_with", "serialize_with = ...");
@@ -29,6 +36,10 @@ pub const SERIALIZE_WITH: Symbol = Symbol("serialize_with", "serialize_with = ..
 pub const DESERIALIZE_WITH: Symbol = Symbol("deserialize_with", "deserialize_with = ...");
 /// crate - sub-borsh nested meta, item-level only, `BorshSerialize`, `BorshDeserialize`, `BorshSchema` contexts
 pub const CRATE: Symbol = Symbol("crate", "crate = ...");
+/// unknown - sub-borsh nested meta, variant-level only, `BorshSerialize`, `BorshDeserialize`, `BorshSchema` contexts
+pub const UNKNOWN: Symbol = Symbol("unknown", "unknown");
+/// tag - sub-borsh nested meta, variant-level only, `BorshSerialize`, `BorshDeserialize`, `BorshSchema` contexts
+pub const TAG: Symbol = Symbol("tag", "tag = ...");
 
 #[cfg(feature = "schema")]
 pub mod schema_keys {
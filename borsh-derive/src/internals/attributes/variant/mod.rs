@@ -0,0 +1,361 @@
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+use proc_macro2::Span;
+use syn::{meta::ParseNestedMeta, Attribute, Fields, Ident, Index, ItemEnum, LitInt, Member};
+
+use super::{
+    get_one_attribute, parsing::attr_get_by_symbol_keys, Symbol, BORSH, SKIP, TAG, UNKNOWN,
+};
+
+enum Variants {
+    Unknown(()),
+    Tag(u8),
+    Skip(()),
+}
+
+type ParseFn = dyn Fn(Symbol, Symbol, &ParseNestedMeta) -> syn::Result<Variants> + Send + Sync;
+
+static BORSH_VARIANT_PARSE_MAP: Lazy<BTreeMap<Symbol, Box<ParseFn>>> = Lazy::new(|| {
+    let mut m = BTreeMap::new();
+    let f_unknown: Box<ParseFn> =
+        Box::new(|_attr_name, _meta_item_name, _meta| Ok(Variants::Unknown(())));
+    let f_tag: Box<ParseFn> = Box::new(|_attr_name, _meta_item_name, meta| {
+        let lit: LitInt = meta.value()?.parse()?;
+        let tag = lit.base10_parse::<u8>()?;
+        Ok(Variants::Tag(tag))
+    });
+    let f_skip: Box<ParseFn> =
+        Box::new(|_attr_name, _meta_item_name, _meta| Ok(Variants::Skip(())));
+    m.insert(UNKNOWN, f_unknown);
+    m.insert(TAG, f_tag);
+    m.insert(SKIP, f_skip);
+    m
+});
+
+#[derive(Default, Clone)]
+pub(crate) struct Attributes {
+    pub unknown: bool,
+    /// explicit wire tag of the variant
+    pub tag: Option<u8>,
+    /// variant is never serialized or deserialized, but still occupies its tag
+    pub skip: bool,
+}
+
+impl From<BTreeMap<Symbol, Variants>> for Attributes {
+    fn from(mut map: BTreeMap<Symbol, Variants>) -> Self {
+        let unknown = map.remove(&UNKNOWN);
+        let unknown = unknown.map(|variant| match variant {
+            Variants::Unknown(unknown) => unknown,
+            _ => unreachable!("only one enum variant is expected to correspond to given map key"),
+        });
+        let tag = map.remove(&TAG);
+        let tag = tag.map(|variant| match variant {
+            Variants::Tag(tag) => tag,
+            _ => unreachable!("only one enum variant is expected to correspond to given map key"),
+        });
+        let skip = map.remove(&SKIP);
+        let skip = skip.map(|variant| match variant {
+            Variants::Skip(skip) => skip,
+            _ => unreachable!("only one enum variant is expected to correspond to given map key"),
+        });
+        Self {
+            unknown: unknown.is_some(),
+            tag,
+            skip: skip.is_some(),
+        }
+    }
+}
+
+impl Attributes {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<Self, syn::Error> {
+        let borsh = get_one_attribute(attrs)?;
+
+        let result: Self = if let Some(attr) = borsh {
+            attr_get_by_symbol_keys(BORSH, attr, &BORSH_VARIANT_PARSE_MAP)?.into()
+        } else {
+            BTreeMap::new().into()
+        };
+
+        Ok(result)
+    }
+}
+
+/// variant, annotated with `#[borsh(unknown)]`, which captures any tag not claimed
+/// by other variants of the enum
+pub(crate) struct UnknownVariant {
+    pub ident: Ident,
+    /// field, which holds the unrecognized tag
+    pub tag: Member,
+    /// field, which holds all of the input, following the tag
+    pub payload: Member,
+}
+
+fn members(fields: &Fields) -> Vec<Member> {
+    match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| Member::Named(field.ident.clone().unwrap()))
+            .collect(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len())
+            .map(|index| {
+                Member::Unnamed(Index {
+                    index: index as u32,
+                    span: Span::call_site(),
+                })
+            })
+            .collect(),
+        Fields::Unit => vec![],
+    }
+}
+
+/// finds the variant, annotated with `#[borsh(unknown)]`, and checks that it's the last
+/// variant of the enum and has exactly two fields: the tag and the payload
+pub(crate) fn unknown_variant(input: &ItemEnum) -> syn::Result<Option<UnknownVariant>> {
+    let mut result = None;
+    for (variant_idx, variant) in input.variants.iter().enumerate() {
+        let parsed = Attributes::parse(&variant.attrs)?;
+        if !parsed.unknown {
+            continue;
+        }
+        if variant_idx + 1 != input.variants.len() {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                format!(
+                    "`{}` variant must be the last variant of the enum",
+                    UNKNOWN.0
+                ),
+            ));
+        }
+        if parsed.tag.is_some() {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                format!(
+                    "`{}` variant captures all unclaimed tags and can't have a `{}`",
+                    UNKNOWN.0, TAG.0
+                ),
+            ));
+        }
+        if parsed.skip {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                format!(
+                    "`{}` variant can't be annotated with `{}`",
+                    UNKNOWN.0, SKIP.0
+                ),
+            ));
+        }
+        let mut members = members(&variant.fields).into_iter();
+        let (tag, payload) = match (members.next(), members.next(), members.next()) {
+            (Some(tag), Some(payload), None) => (tag, payload),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &variant.ident,
+                    format!(
+                        "`{}` variant must have exactly two fields: the tag (`u8`) and the payload (`Vec<u8>`)",
+                        UNKNOWN.0
+                    ),
+                ));
+            }
+        };
+        result = Some(UnknownVariant {
+            ident: variant.ident.clone(),
+            tag,
+            payload,
+        });
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::{quote, ToTokens};
+    use syn::ItemEnum;
+
+    use crate::internals::test_helpers::local_insta_assert_debug_snapshot;
+
+    use super::{unknown_variant, Attributes};
+
+    #[test]
+    fn test_unknown_parsing() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum A {
+                B(u64),
+                #[borsh(unknown)]
+                Unknown { tag: u8, payload: Vec<u8> },
+            }
+        })
+        .unwrap();
+
+        let variants = item_enum.variants.iter().collect::<Vec<_>>();
+        assert!(!Attributes::parse(&variants[0].attrs).unwrap().unknown);
+        assert!(Attributes::parse(&variants[1].attrs).unwrap().unknown);
+
+        let unknown = unknown_variant(&item_enum).unwrap().unwrap();
+        assert_eq!(unknown.ident.to_string(), "Unknown");
+        assert_eq!(unknown.tag.to_token_stream().to_string(), "tag");
+        assert_eq!(unknown.payload.to_token_stream().to_string(), "payload");
+    }
+
+    #[test]
+    fn test_unknown_parsing_tuple_variant() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum A {
+                B(u64),
+                #[borsh(unknown)]
+                Unknown(u8, Vec<u8>),
+            }
+        })
+        .unwrap();
+
+        let unknown = unknown_variant(&item_enum).unwrap().unwrap();
+        assert_eq!(unknown.tag.to_token_stream().to_string(), "0");
+        assert_eq!(unknown.payload.to_token_stream().to_string(), "1");
+    }
+
+    #[test]
+    fn test_unknown_not_last() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum A {
+                #[borsh(unknown)]
+                Unknown { tag: u8, payload: Vec<u8> },
+                B(u64),
+            }
+        })
+        .unwrap();
+
+        let err = match unknown_variant(&item_enum) {
+            Ok(..) => unreachable!("expecting error here"),
+            Err(err) => err,
+        };
+        local_insta_assert_debug_snapshot!(err);
+    }
+
+    #[test]
+    fn test_unknown_wrong_fields() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum A {
+                B(u64),
+                #[borsh(unknown)]
+                Unknown(Vec<u8>),
+            }
+        })
+        .unwrap();
+
+        let err = match unknown_variant(&item_enum) {
+            Ok(..) => unreachable!("expecting error here"),
+            Err(err) => err,
+        };
+        local_insta_assert_debug_snapshot!(err);
+    }
+
+    #[test]
+    fn test_unknown_with_tag() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum A {
+                B(u64),
+                #[borsh(unknown, tag = 5)]
+                Unknown { tag: u8, payload: Vec<u8> },
+            }
+        })
+        .unwrap();
+
+        let err = match unknown_variant(&item_enum) {
+            Ok(..) => unreachable!("expecting error here"),
+            Err(err) => err,
+        };
+        local_insta_assert_debug_snapshot!(err);
+    }
+
+    #[test]
+    fn test_tag_parsing() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum A {
+                #[borsh(tag = 42)]
+                B(u64),
+                C,
+            }
+        })
+        .unwrap();
+
+        let variants = item_enum.variants.iter().collect::<Vec<_>>();
+        assert_eq!(Attributes::parse(&variants[0].attrs).unwrap().tag, Some(42));
+        assert_eq!(Attributes::parse(&variants[1].attrs).unwrap().tag, None);
+    }
+
+    #[test]
+    fn test_tag_out_of_range() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum A {
+                #[borsh(tag = 256)]
+                B(u64),
+            }
+        })
+        .unwrap();
+
+        let variants = item_enum.variants.iter().collect::<Vec<_>>();
+        let err = match Attributes::parse(&variants[0].attrs) {
+            Ok(..) => unreachable!("expecting error here"),
+            Err(err) => err,
+        };
+        local_insta_assert_debug_snapshot!(err);
+    }
+
+    #[test]
+    fn test_skip_parsing() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum A {
+                #[borsh(skip)]
+                B(Box<dyn Future<Output = ()>>),
+                #[borsh(skip, tag = 3)]
+                C,
+                D,
+            }
+        })
+        .unwrap();
+
+        let variants = item_enum.variants.iter().collect::<Vec<_>>();
+        assert!(Attributes::parse(&variants[0].attrs).unwrap().skip);
+        let parsed = Attributes::parse(&variants[1].attrs).unwrap();
+        assert!(parsed.skip);
+        assert_eq!(parsed.tag, Some(3));
+        assert!(!Attributes::parse(&variants[2].attrs).unwrap().skip);
+    }
+
+    #[test]
+    fn test_unknown_with_skip() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum A {
+                B(u64),
+                #[borsh(unknown, skip)]
+                Unknown { tag: u8, payload: Vec<u8> },
+            }
+        })
+        .unwrap();
+
+        let err = match unknown_variant(&item_enum) {
+            Ok(..) => unreachable!("expecting error here"),
+            Err(err) => err,
+        };
+        local_insta_assert_debug_snapshot!(err);
+    }
+
+    #[test]
+    fn test_variant_attr_invalid() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum A {
+                #[borsh(invalid)]
+                B(u64),
+            }
+        })
+        .unwrap();
+
+        let variants = item_enum.variants.iter().collect::<Vec<_>>();
+        let err = match Attributes::parse(&variants[0].attrs) {
+            Ok(..) => unreachable!("expecting error here"),
+            Err(err) => err,
+        };
+        local_insta_assert_debug_snapshot!(err);
+    }
+}
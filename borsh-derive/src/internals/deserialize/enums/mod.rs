@@ -2,7 +2,12 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use syn::{Fields, ItemEnum, Path, Variant};
 
-use crate::internals::{attributes::item, deserialize, enum_discriminant::Discriminants, generics};
+use crate::internals::{
+    attributes::{field, item, variant},
+    deserialize,
+    enum_discriminant::Discriminants,
+    generics,
+};
 
 pub fn process(input: &ItemEnum, cratename: Path) -> syn::Result<TokenStream2> {
     let name = &input.ident;
@@ -10,19 +15,50 @@ pub fn process(input: &ItemEnum, cratename: Path) -> syn::Result<TokenStream2> {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let mut where_clause = generics::default_where(where_clause);
     let mut variant_arms = TokenStream2::new();
+    let mut variant_arms_from = TokenStream2::new();
     let use_discriminant = item::contains_use_discriminant(input)?;
-    let discriminants = Discriminants::new(&input.variants);
+    let discriminants = Discriminants::new(&input.variants)?;
+    let unknown = variant::unknown_variant(input)?;
     let mut generics_output = deserialize::GenericsOutput::new(&generics);
 
     for (variant_idx, variant) in input.variants.iter().enumerate() {
-        let variant_body = process_variant(variant, &cratename, &mut generics_output)?;
+        if unknown
+            .as_ref()
+            .is_some_and(|unknown| unknown.ident == variant.ident)
+            || variant::Attributes::parse(&variant.attrs)?.skip
+        {
+            continue;
+        }
+        let (variant_body, variant_body_from) =
+            process_variant(variant, &cratename, &mut generics_output)?;
         let variant_ident = &variant.ident;
 
         let discriminant_value = discriminants.get(variant_ident, use_discriminant, variant_idx)?;
         variant_arms.extend(quote! {
             if variant_tag == #discriminant_value { #name::#variant_ident #variant_body } else
         });
+        variant_arms_from.extend(quote! {
+            if variant_tag == #discriminant_value { #name::#variant_ident #variant_body_from } else
+        });
     }
+    let fallback = if let Some(unknown) = unknown {
+        let variant_ident = &unknown.ident;
+        let tag = &unknown.tag;
+        let payload = &unknown.payload;
+        quote! {
+            #name::#variant_ident {
+                #tag: variant_tag,
+                #payload: ::core::convert::From::from(#cratename::de::read_remaining(reader)?),
+            }
+        }
+    } else {
+        quote! {
+            return Err(#cratename::io::Error::new(
+                #cratename::io::ErrorKind::InvalidData,
+                #cratename::__private::maybestd::format!("Unexpected variant tag: {:?}", variant_tag),
+            ))
+        }
+    };
     let init = if let Some(method_ident) = item::contains_initialize_with(&input.attrs)? {
         quote! {
             return_value.#method_ident();
@@ -39,6 +75,11 @@ pub fn process(input: &ItemEnum, cratename: Path) -> syn::Result<TokenStream2> {
                 let tag = <u8 as #cratename::de::BorshDeserialize>::deserialize_reader(reader)?;
                 <Self as #cratename::de::EnumExt>::deserialize_variant(reader, tag)
             }
+
+            fn deserialize_from<__B: #cratename::de::BorshRead>(reader: &mut __B) -> ::core::result::Result<Self, #cratename::io::Error> {
+                let tag = <u8 as #cratename::de::BorshDeserialize>::deserialize_from(reader)?;
+                <Self as #cratename::de::EnumExt>::deserialize_variant_from(reader, tag)
+            }
         }
 
         #[automatically_derived]
@@ -49,10 +90,19 @@ pub fn process(input: &ItemEnum, cratename: Path) -> syn::Result<TokenStream2> {
             ) -> ::core::result::Result<Self, #cratename::io::Error> {
                 let mut return_value =
                     #variant_arms {
-                    return Err(#cratename::io::Error::new(
-                        #cratename::io::ErrorKind::InvalidData,
-                        #cratename::__private::maybestd::format!("Unexpected variant tag: {:?}", variant_tag),
-                    ))
+                    #fallback
+                };
+                #init
+                Ok(return_value)
+            }
+
+            fn deserialize_variant_from<__B: #cratename::de::BorshRead>(
+                reader: &mut __B,
+                variant_tag: u8,
+            ) -> ::core::result::Result<Self, #cratename::io::Error> {
+                let mut return_value =
+                    #variant_arms_from {
+                    #fallback
                 };
                 #init
                 Ok(return_value)
@@ -61,28 +111,33 @@ pub fn process(input: &ItemEnum, cratename: Path) -> syn::Result<TokenStream2> {
     })
 }
 
+/// returns bodies of the variant for `deserialize_variant` and `deserialize_variant_from`
 fn process_variant(
     variant: &Variant,
     cratename: &Path,
     generics: &mut deserialize::GenericsOutput,
-) -> syn::Result<TokenStream2> {
+) -> syn::Result<(TokenStream2, TokenStream2)> {
     let mut body = TokenStream2::new();
+    let mut body_from = TokenStream2::new();
+    field::check_trailing_default(&variant.fields)?;
     match &variant.fields {
         Fields::Named(fields) => {
             for field in &fields.named {
-                deserialize::process_field(field, cratename, &mut body, generics)?;
+                deserialize::process_field(field, cratename, &mut body, &mut body_from, generics)?;
             }
             body = quote! { { #body }};
+            body_from = quote! { { #body_from }};
         }
         Fields::Unnamed(fields) => {
             for field in fields.unnamed.iter() {
-                deserialize::process_field(field, cratename, &mut body, generics)?;
+                deserialize::process_field(field, cratename, &mut body, &mut body_from, generics)?;
             }
             body = quote! { ( #body )};
+            body_from = quote! { ( #body_from )};
         }
         Fields::Unit => {}
     }
-    Ok(body)
+    Ok((body, body_from))
 }
 
 #[cfg(test)]
@@ -330,4 +385,54 @@ mod tests {
         let actual = process(&item_enum, default_cratename()).unwrap();
         local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
     }
+
+    #[test]
+    fn unknown_variant() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum X {
+                A(u16),
+                B,
+                #[borsh(unknown)]
+                Unknown(u8, Vec<u8>),
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn explicit_tags() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum X {
+                #[borsh(tag = 7)]
+                A(u16),
+                B,
+                #[borsh(tag = 2)]
+                C {x: i32, y: i32},
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn skipped_variant() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum X<F> {
+                A(u16),
+                #[borsh(skip)]
+                Pending(F),
+                B { x: i32 },
+                C,
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
 }
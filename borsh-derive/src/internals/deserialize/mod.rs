@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
 use syn::{ExprPath, Generics, Ident, Path};
 
@@ -11,6 +11,25 @@ pub mod enums;
 pub mod structs;
 pub mod unions;
 
+/// method of `BorshDeserialize`, which derived code implements and calls on fields
+#[derive(Clone, Copy)]
+enum Method {
+    /// `deserialize_reader`, which reads from any `io::Read`
+    Reader,
+    /// `deserialize_from`, which reads from an in-memory `de::BorshRead` source
+    BorshRead,
+}
+
+impl Method {
+    fn ident(self) -> Ident {
+        let name = match self {
+            Self::Reader => "deserialize_reader",
+            Self::BorshRead => "deserialize_from",
+        };
+        Ident::new(name, Span::call_site())
+    }
+}
+
 struct GenericsOutput {
     overrides: Vec<syn::WherePredicate>,
     default_visitor: generics::FindTyParams,
@@ -38,10 +57,13 @@ impl GenericsOutput {
     }
 }
 
+/// extends bodies of `deserialize_reader` (`body`) and `deserialize_from` (`body_from`)
+/// with deserialization of `field`
 fn process_field(
     field: &syn::Field,
     cratename: &Path,
     body: &mut TokenStream2,
+    body_from: &mut TokenStream2,
     generics: &mut GenericsOutput,
 ) -> syn::Result<()> {
     let parsed = field::Attributes::parse(&field.attrs)?;
@@ -52,38 +74,113 @@ fn process_field(
     let needs_bounds_derive = parsed.needs_bounds_derive(BoundType::Deserialize);
 
     let field_name = field.ident.as_ref();
-    let delta = if parsed.skip {
+    if parsed.skip {
         if needs_bounds_derive {
             generics.default_visitor.visit_field(field);
         }
-        field_default_output(field_name)
+        body.extend(field_default_output(field_name));
+        body_from.extend(field_default_output(field_name));
     } else {
         if needs_bounds_derive {
             generics.deserialize_visitor.visit_field(field);
+            if parsed.trailing_default {
+                generics.default_visitor.visit_field(field);
+            }
         }
-        field_output(field_name, cratename, parsed.deserialize_with)
-    };
-    body.extend(delta);
+        for (body, method) in [(body, Method::Reader), (body_from, Method::BorshRead)] {
+            body.extend(field_output(
+                field_name,
+                cratename,
+                parsed.deserialize_with.clone(),
+                parsed.trailing_default,
+                method,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// extends body of `skip` (`skip_body`) with skipping of `field`, and sum of sizes of
+/// fields (`fixed_size`) with size of `field`, or resets it to `None`, if the size isn't fixed
+fn process_field_skip(
+    field: &syn::Field,
+    cratename: &Path,
+    skip_body: &mut TokenStream2,
+    fixed_size: &mut Option<TokenStream2>,
+) -> syn::Result<()> {
+    let parsed = field::Attributes::parse(&field.attrs)?;
+    if parsed.skip {
+        return Ok(());
+    }
+    if parsed.trailing_default {
+        skip_body.extend(quote! {
+            if #cratename::de::BorshRead::remaining(reader) == 0 {
+                return Ok(());
+            }
+        });
+    }
+    skip_body.extend(field_skip_output(
+        &field.ty,
+        cratename,
+        parsed.deserialize_with.as_ref(),
+    ));
+    if parsed.deserialize_with.is_some() || parsed.trailing_default {
+        *fixed_size = None;
+    } else if let Some(fixed_size) = fixed_size {
+        let ty = &field.ty;
+        fixed_size.extend(quote! {
+            + <#ty as #cratename::BorshDeserialize>::fixed_serialized_size()?
+        });
+    }
     Ok(())
 }
 
+/// function which computes derive output [proc_macro2::TokenStream]
+/// of code, which advances `reader` past single field
+pub(crate) fn field_skip_output(
+    ty: &syn::Type,
+    cratename: &Path,
+    deserialize_with: Option<&ExprPath>,
+) -> TokenStream2 {
+    match deserialize_with {
+        // there's no way to skip the field, other than to deserialize it
+        Some(path) => quote! { let _: #ty = #path(reader)?; },
+        None => quote! { <#ty as #cratename::BorshDeserialize>::skip(reader)?; },
+    }
+}
+
 /// function which computes derive output [proc_macro2::TokenStream]
 /// of code, which deserializes single field
 fn field_output(
     field_name: Option<&Ident>,
     cratename: &Path,
     deserialize_with: Option<ExprPath>,
+    trailing_default: bool,
+    method: Method,
 ) -> TokenStream2 {
+    let method_ident = method.ident();
     let default_path: ExprPath =
-        syn::parse2(quote! { #cratename::BorshDeserialize::deserialize_reader }).unwrap();
+        syn::parse2(quote! { #cratename::BorshDeserialize::#method_ident }).unwrap();
     let path: ExprPath = deserialize_with.unwrap_or(default_path);
+    let value = if trailing_default {
+        match method {
+            Method::Reader => {
+                quote! { #cratename::de::deserialize_trailing_default(reader, #path)? }
+            }
+            Method::BorshRead => {
+                quote! { #cratename::de::deserialize_trailing_default_from(reader, #path)? }
+            }
+        }
+    } else {
+        quote! { #path(reader)? }
+    };
     if let Some(field_name) = field_name {
         quote! {
-            #field_name: #path(reader)?,
+            #field_name: #value,
         }
     } else {
         quote! {
-            #path(reader)?,
+            #value,
         }
     }
 }
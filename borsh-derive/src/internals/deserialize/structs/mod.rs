@@ -2,7 +2,11 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use syn::{Fields, ItemStruct, Path};
 
-use crate::internals::{attributes::item, deserialize, generics};
+use crate::internals::{
+    attributes::{field, item},
+    deserialize::{self, Method},
+    generics,
+};
 
 pub fn process(input: &ItemStruct, cratename: Path) -> syn::Result<TokenStream2> {
     let name = &input.ident;
@@ -10,32 +14,57 @@ pub fn process(input: &ItemStruct, cratename: Path) -> syn::Result<TokenStream2>
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let mut where_clause = generics::default_where(where_clause);
     let mut body = TokenStream2::new();
+    let mut body_from = TokenStream2::new();
     let mut generics_output = deserialize::GenericsOutput::new(&generics);
+    let mut skip_body = TokenStream2::new();
+    let mut fixed_size = Some(quote! { 0 });
+    field::check_trailing_default(&input.fields)?;
+    for field in &input.fields {
+        deserialize::process_field_skip(field, &cratename, &mut skip_body, &mut fixed_size)?;
+    }
 
-    let return_value = match &input.fields {
+    let (return_value, return_value_from) = match &input.fields {
         Fields::Named(fields) => {
             for field in &fields.named {
-                deserialize::process_field(field, &cratename, &mut body, &mut generics_output)?;
-            }
-            quote! {
-                Self { #body }
+                deserialize::process_field(
+                    field,
+                    &cratename,
+                    &mut body,
+                    &mut body_from,
+                    &mut generics_output,
+                )?;
             }
+            (quote! { Self { #body } }, quote! { Self { #body_from } })
         }
         Fields::Unnamed(fields) => {
             for field in fields.unnamed.iter() {
-                deserialize::process_field(field, &cratename, &mut body, &mut generics_output)?;
-            }
-            quote! {
-                Self( #body )
-            }
-        }
-        Fields::Unit => {
-            quote! {
-                Self {}
+                deserialize::process_field(
+                    field,
+                    &cratename,
+                    &mut body,
+                    &mut body_from,
+                    &mut generics_output,
+                )?;
             }
+            (quote! { Self( #body ) }, quote! { Self( #body_from ) })
         }
+        Fields::Unit => (quote! { Self {} }, quote! { Self {} }),
     };
     generics_output.extend(&mut where_clause, &cratename);
+    let (return_value, return_value_from, skip_methods) =
+        match item::contains_version(&input.attrs)? {
+            // skipping of versioned structs falls back to deserialization
+            Some(version) => (
+                versioned_output(&version, return_value, &cratename, Method::Reader),
+                versioned_output(&version, return_value_from, &cratename, Method::BorshRead),
+                TokenStream2::new(),
+            ),
+            None => (
+                return_value,
+                return_value_from,
+                skip_output(skip_body, fixed_size, &cratename),
+            ),
+        };
 
     if let Some(method_ident) = item::contains_initialize_with(&input.attrs)? {
         Ok(quote! {
@@ -46,6 +75,13 @@ pub fn process(input: &ItemStruct, cratename: Path) -> syn::Result<TokenStream2>
                     return_value.#method_ident();
                     Ok(return_value)
                 }
+
+                fn deserialize_from<__B: #cratename::de::BorshRead>(reader: &mut __B) -> ::core::result::Result<Self, #cratename::io::Error> {
+                    let mut return_value = #return_value_from;
+                    return_value.#method_ident();
+                    Ok(return_value)
+                }
+                #skip_methods
             }
         })
     } else {
@@ -55,15 +91,81 @@ pub fn process(input: &ItemStruct, cratename: Path) -> syn::Result<TokenStream2>
                 fn deserialize_reader<__R: #cratename::io::Read>(reader: &mut __R) -> ::core::result::Result<Self, #cratename::io::Error> {
                     Ok(#return_value)
                 }
+
+                fn deserialize_from<__B: #cratename::de::BorshRead>(reader: &mut __B) -> ::core::result::Result<Self, #cratename::io::Error> {
+                    Ok(#return_value_from)
+                }
+                #skip_methods
             }
         })
     }
 }
 
+/// `skip` and `fixed_serialized_size` methods, which skip fields one after another
+fn skip_output(
+    mut skip_body: TokenStream2,
+    fixed_size: Option<TokenStream2>,
+    cratename: &Path,
+) -> TokenStream2 {
+    let fixed_size = match fixed_size {
+        Some(fixed_size) => quote! { ::core::option::Option::Some(#fixed_size) },
+        None => quote! { ::core::option::Option::None },
+    };
+    if skip_body.is_empty() {
+        skip_body = quote! { let _ = reader; };
+    }
+    quote! {
+        fn skip<__B: #cratename::de::BorshRead>(reader: &mut __B) -> ::core::result::Result<(), #cratename::io::Error> {
+            #skip_body
+            Ok(())
+        }
+
+        fn fixed_serialized_size() -> ::core::option::Option<usize> {
+            #fixed_size
+        }
+    }
+}
+
+/// reads the version prefix, then either the current version of the struct, or one of the
+/// previous versions, which is migrated to the current one with `From`
+fn versioned_output(
+    version: &item::Version,
+    return_value: TokenStream2,
+    cratename: &Path,
+    method: Method,
+) -> TokenStream2 {
+    let method_ident = method.ident();
+    let current = version.current;
+    let mut arms = quote! {
+        if version == #current { #return_value } else
+    };
+    for (previous, previous_version) in &version.migrate_from {
+        arms.extend(quote! {
+            if version == #previous_version {
+                <Self as ::core::convert::From<#previous>>::from(
+                    <#previous as #cratename::BorshDeserialize>::#method_ident(reader)?,
+                )
+            } else
+        });
+    }
+    quote! {
+        {
+            let version = <u8 as #cratename::de::BorshDeserialize>::#method_ident(reader)?;
+            #arms {
+                return Err(#cratename::io::Error::new(
+                    #cratename::io::ErrorKind::InvalidData,
+                    #cratename::__private::maybestd::format!("Unexpected version: {:?}", version),
+                ));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::internals::test_helpers::{
-        default_cratename, local_insta_assert_snapshot, pretty_print_syn_str,
+        default_cratename, local_insta_assert_debug_snapshot, local_insta_assert_snapshot,
+        pretty_print_syn_str,
     };
 
     use super::*;
@@ -264,4 +366,69 @@ mod tests {
         let actual = process(&item_enum, default_cratename()).unwrap();
         local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
     }
+
+    #[test]
+    fn trailing_default() {
+        let item_struct: ItemStruct = syn::parse2(quote! {
+            struct A<T> {
+                x: u64,
+                #[borsh(trailing_default)]
+                y: T,
+                #[borsh(trailing_default, deserialize_with = "third_party_impl::deserialize_third_party")]
+                z: ThirdParty,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&item_struct, default_cratename()).unwrap();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn trailing_default_not_last() {
+        let item_struct: ItemStruct = syn::parse2(quote! {
+            struct A {
+                #[borsh(trailing_default)]
+                x: u64,
+                y: String,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&item_struct, default_cratename());
+        let err = match actual {
+            Ok(..) => unreachable!("expecting error here"),
+            Err(err) => err,
+        };
+        local_insta_assert_debug_snapshot!(err);
+    }
+
+    #[test]
+    fn versioned_struct() {
+        let item_struct: ItemStruct = syn::parse2(quote! {
+            #[borsh(version = 3, migrate_from(StateV1 = 1, old::StateV2 = 2))]
+            struct State {
+                x: u64,
+                y: String,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&item_struct, default_cratename()).unwrap();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn versioned_struct_with_init() {
+        let item_struct: ItemStruct = syn::parse2(quote! {
+            #[borsh(init = initialization_method, version = 2, migrate_from(StateV1 = 1))]
+            struct State {
+                x: u64,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&item_struct, default_cratename()).unwrap();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
 }
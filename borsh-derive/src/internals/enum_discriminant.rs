@@ -5,11 +5,20 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use syn::{punctuated::Punctuated, token::Comma, Variant};
 
-pub struct Discriminants(HashMap<Ident, TokenStream>);
+use crate::internals::attributes::{variant, TAG, USE_DISCRIMINANT};
+
+pub struct Discriminants {
+    discriminants: HashMap<Ident, TokenStream>,
+    /// wire tags, if any of the variants is annotated with `#[borsh(tag = ...)]`
+    tags: Option<HashMap<Ident, u8>>,
+}
 impl Discriminants {
     /// Calculates the discriminant that will be assigned by the compiler.
     /// See: https://doc.rust-lang.org/reference/items/enumerations.html#assigning-discriminant-values
-    pub fn new(variants: &Punctuated<Variant, Comma>) -> Self {
+    ///
+    /// If any of the variants is annotated with `#[borsh(tag = ...)]`, wire tags are assigned
+    /// by the same rule: a variant without explicit tag gets the tag of the previous variant plus one.
+    pub fn new(variants: &Punctuated<Variant, Comma>) -> syn::Result<Self> {
         let mut map = HashMap::new();
         let mut next_discriminant_if_not_specified = quote! {0};
 
@@ -23,7 +32,52 @@ impl Discriminants {
             map.insert(variant.ident.clone(), this_discriminant);
         }
 
-        Self(map)
+        Ok(Self {
+            discriminants: map,
+            tags: Self::tags(variants)?,
+        })
+    }
+
+    fn tags(variants: &Punctuated<Variant, Comma>) -> syn::Result<Option<HashMap<Ident, u8>>> {
+        let mut attrs = Vec::with_capacity(variants.len());
+        for variant in variants {
+            let parsed = variant::Attributes::parse(&variant.attrs)?;
+            // the `unknown` variant doesn't occupy a tag
+            if !parsed.unknown {
+                attrs.push((variant, parsed.tag));
+            }
+        }
+        if attrs.iter().all(|(_, tag)| tag.is_none()) {
+            return Ok(None);
+        }
+
+        let mut tags = HashMap::new();
+        let mut claimed: HashMap<u8, &Ident> = HashMap::new();
+        let mut next_tag_if_not_specified = Some(0u8);
+        for (variant, tag) in attrs {
+            let tag = tag.or(next_tag_if_not_specified).ok_or_else(|| {
+                syn::Error::new(
+                    variant.ident.span(),
+                    format!(
+                        "tag of `{}` variant overflows `u8`, specify it explicitly with `#[borsh({} = ...)]`",
+                        variant.ident,
+                        TAG.0
+                    ),
+                )
+            })?;
+            if let Some(other) = claimed.insert(tag, &variant.ident) {
+                return Err(syn::Error::new(
+                    variant.ident.span(),
+                    format!(
+                        "tag {} of `{}` variant is already used by `{}` variant",
+                        tag, variant.ident, other
+                    ),
+                ));
+            }
+            next_tag_if_not_specified = tag.checked_add(1);
+            tags.insert(variant.ident.clone(), tag);
+        }
+        Ok(Some(tags))
     }
 
     pub fn get(
@@ -32,6 +86,19 @@ impl Discriminants {
         use_discriminant: bool,
         variant_idx: usize,
     ) -> syn::Result<TokenStream> {
+        if let Some(tags) = &self.tags {
+            if use_discriminant {
+                return Err(syn::Error::new(
+                    variant_ident.span(),
+                    format!(
+                        "`{}` attribute can't be used together with `{} = true`",
+                        TAG.0, USE_DISCRIMINANT.0
+                    ),
+                ));
+            }
+            let tag = tags.get(variant_ident).unwrap();
+            return Ok(quote! { #tag });
+        }
         let variant_idx = u8::try_from(variant_idx).map_err(|err| {
             syn::Error::new(
                 variant_ident.span(),
@@ -39,7 +106,7 @@ impl Discriminants {
             )
         })?;
         let result = if use_discriminant {
-            let discriminant_value = self.0.get(variant_ident).unwrap();
+            let discriminant_value = self.discriminants.get(variant_ident).unwrap();
             quote! { #discriminant_value }
         } else {
             quote! { #variant_idx }
@@ -2,9 +2,11 @@ pub mod attributes;
 pub mod deserialize;
 mod enum_discriminant;
 mod generics;
+pub mod pod;
 #[cfg(feature = "schema")]
 pub mod schema;
 pub mod serialize;
+pub mod view;
 
 pub mod cratename;
 
@@ -0,0 +1,236 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{token::Paren, Attribute, Data, DeriveInput, Path};
+
+use crate::internals::attributes::{BORSH, CRATE};
+
+pub fn process(input: &DeriveInput, cratename: Path) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`BorshPod` can only be derived for structs",
+            ))
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`BorshPod` can't be derived for generic structs",
+        ));
+    }
+    if !has_stable_layout(&input.attrs)? {
+        return Err(syn::Error::new_spanned(
+            name,
+            "`BorshPod` requires `#[repr(C)]` or `#[repr(transparent)]`",
+        ));
+    }
+    if let Some(attr) = input.attrs.iter().find(|attr| attr.path() == BORSH) {
+        check_item_attr(attr)?;
+    }
+
+    let mut validate_body = TokenStream2::new();
+    let mut fields_size = quote! { 0 };
+    for field in fields {
+        if let Some(attr) = field.attrs.iter().find(|attr| attr.path() == BORSH) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`BorshPod` doesn't support `borsh` field attributes",
+            ));
+        }
+        let ty = &field.ty;
+        validate_body.extend(quote! {
+            let (field, bytes) = bytes.split_at(::core::mem::size_of::<#ty>());
+            <#ty as #cratename::pod::BorshPod>::validate(field)?;
+        });
+        fields_size.extend(quote! { + ::core::mem::size_of::<#ty>() });
+    }
+    let padding_error = format!(
+        "`{}` has padding, which `BorshPod` doesn't allow; reorder or resize its fields",
+        name
+    );
+
+    Ok(quote! {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<#name>() == #fields_size,
+            #padding_error
+        );
+
+        #[automatically_derived]
+        unsafe impl #cratename::pod::BorshPod for #name {
+            fn validate(bytes: &[u8]) -> ::core::result::Result<(), #cratename::io::Error> {
+                #validate_body
+                let _ = bytes;
+                Ok(())
+            }
+        }
+    })
+}
+
+/// checks that the layout of the struct is `#[repr(C)]` or `#[repr(transparent)]`,
+/// so that fields are laid out in the order of declaration
+fn has_stable_layout(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut stable = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") || meta.path.is_ident("transparent") {
+                stable = true;
+            }
+            // skip arguments of `align(..)` and `packed(..)`
+            if meta.input.peek(Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<TokenStream2>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(stable)
+}
+
+/// only `#[borsh(crate = ...)]` is allowed, as other item attributes change the encoding
+/// or the way values are constructed, which views bypass
+fn check_item_attr(attr: &Attribute) -> syn::Result<()> {
+    attr.parse_nested_meta(|meta| {
+        if meta.path == CRATE {
+            let _value: syn::Expr = meta.value()?.parse()?;
+            Ok(())
+        } else {
+            Err(meta.error("`BorshPod` only supports `crate` item attribute"))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::internals::test_helpers::{
+        default_cratename, local_insta_assert_debug_snapshot, local_insta_assert_snapshot,
+        pretty_print_syn_str,
+    };
+
+    use super::*;
+
+    #[test]
+    fn simple_struct() {
+        let input: DeriveInput = syn::parse2(quote! {
+            #[repr(C)]
+            struct A {
+                owner: [u8; 32],
+                balance: u64,
+                frozen: bool,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&input, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn transparent_tuple_struct_with_custom_crate() {
+        let input: DeriveInput = syn::parse2(quote! {
+            #[repr(transparent)]
+            #[borsh(crate = "reexporter::borsh")]
+            struct A(u64);
+        })
+        .unwrap();
+
+        let crate_: Path = syn::parse2(quote! { reexporter::borsh }).unwrap();
+        let actual = process(&input, crate_).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn unit_struct_packed() {
+        let input: DeriveInput = syn::parse2(quote! {
+            #[repr(C, packed(1))]
+            struct A;
+        })
+        .unwrap();
+
+        let actual = process(&input, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn check_repr_required() {
+        let input: DeriveInput = syn::parse2(quote! {
+            #[repr(align(8))]
+            struct A {
+                x: u64,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&input, default_cratename());
+
+        local_insta_assert_debug_snapshot!(actual.unwrap_err());
+    }
+
+    #[test]
+    fn check_enum_rejected() {
+        let input: DeriveInput = syn::parse2(quote! {
+            #[repr(C)]
+            enum A {
+                B,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&input, default_cratename());
+
+        local_insta_assert_debug_snapshot!(actual.unwrap_err());
+    }
+
+    #[test]
+    fn check_generics_rejected() {
+        let input: DeriveInput = syn::parse2(quote! {
+            #[repr(C)]
+            struct A<T> {
+                x: T,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&input, default_cratename());
+
+        local_insta_assert_debug_snapshot!(actual.unwrap_err());
+    }
+
+    #[test]
+    fn check_field_attr_rejected() {
+        let input: DeriveInput = syn::parse2(quote! {
+            #[repr(C)]
+            struct A {
+                #[borsh(skip)]
+                x: u64,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&input, default_cratename());
+
+        local_insta_assert_debug_snapshot!(actual.unwrap_err());
+    }
+
+    #[test]
+    fn check_version_attr_rejected() {
+        let input: DeriveInput = syn::parse2(quote! {
+            #[repr(C)]
+            #[borsh(version = 2)]
+            struct A {
+                x: u64,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&input, default_cratename());
+
+        local_insta_assert_debug_snapshot!(actual.unwrap_err());
+    }
+}
@@ -4,7 +4,7 @@ use std::collections::HashSet;
 use syn::{Fields, Generics, Ident, ItemEnum, ItemStruct, Path, Variant, Visibility};
 
 use crate::internals::{
-    attributes::{field, item},
+    attributes::{field, item, variant},
     enum_discriminant::Discriminants,
     generics, schema,
 };
@@ -36,13 +36,26 @@ pub fn process(input: &ItemEnum, cratename: Path) -> syn::Result<TokenStream2> {
     let mut where_clause = generics::default_where(where_clause);
     let mut generics_output = schema::GenericsOutput::new(&generics);
     let use_discriminant = item::contains_use_discriminant(input)?;
-    let discriminants = Discriminants::new(&input.variants);
+    let discriminants = Discriminants::new(&input.variants)?;
+    let unknown = variant::unknown_variant(input)?;
 
     // Generate functions that return the schema for variants.
     let mut variants_defs = vec![];
     let mut inner_defs = TokenStream2::new();
     let mut add_recursive_defs = TokenStream2::new();
     for (variant_idx, variant) in input.variants.iter().enumerate() {
+        if unknown
+            .as_ref()
+            .is_some_and(|unknown| unknown.ident == variant.ident)
+        {
+            let variant_output = unknown_variant_output(variant, &cratename);
+            add_recursive_defs.extend(variant_output.add_definitions_recursively_call);
+            variants_defs.push(variant_output.variant_entry);
+            continue;
+        }
+        if variant::Attributes::parse(&variant.attrs)?.skip {
+            continue;
+        }
         let discriminant_info = DiscriminantInfo {
             variant_idx,
             discriminants: &discriminants,
@@ -144,6 +157,26 @@ fn process_variant(
     })
 }
 
+/// `#[borsh(unknown)]` variant doesn't occupy a tag of its own, its entry is marked with
+/// `Definition::UNKNOWN_VARIANT_DISCRIMINANT` and declares the untagged payload
+fn unknown_variant_output(variant: &Variant, cratename: &Path) -> VariantOutput {
+    let variant_name = variant.ident.to_token_stream().to_string();
+    let payload_type = quote! {
+        <#cratename::schema::UnknownVariantPayload as #cratename::BorshSchema>
+    };
+    VariantOutput {
+        inner_struct: TokenStream2::new(),
+        add_definitions_recursively_call: quote! {
+            #payload_type::add_definitions_recursively(definitions);
+        },
+        variant_entry: quote! {
+            (#cratename::schema::Definition::UNKNOWN_VARIANT_DISCRIMINANT,
+             #variant_name.into(),
+             #payload_type::declaration())
+        },
+    }
+}
+
 fn inner_struct_definition(
     variant: &Variant,
     cratename: &Path,
@@ -516,4 +549,54 @@ mod tests {
 
         local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
     }
+
+    #[test]
+    fn unknown_variant() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum X {
+                A(u16),
+                B,
+                #[borsh(unknown)]
+                Unknown { tag: u8, payload: Vec<u8> },
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn explicit_tags() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum X {
+                #[borsh(tag = 7)]
+                A(u16),
+                B,
+                #[borsh(tag = 2)]
+                C {x: i32, y: i32},
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn skipped_variant() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum X<F> {
+                A(u16),
+                #[borsh(skip)]
+                Pending(F),
+                B { x: i32 },
+                C,
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
 }
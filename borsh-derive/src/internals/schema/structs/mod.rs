@@ -2,7 +2,10 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote, ToTokens};
 use syn::{ExprPath, Fields, Ident, ItemStruct, Path, Type};
 
-use crate::internals::{attributes::field, generics, schema};
+use crate::internals::{
+    attributes::{field, item},
+    generics, schema,
+};
 
 /// function which computes derive output [proc_macro2::TokenStream]
 /// of code, which computes declaration of a single field, which is later added to
@@ -58,8 +61,14 @@ pub fn process(input: &ItemStruct, cratename: Path) -> syn::Result<TokenStream2>
     let (struct_fields, add_definitions_recursively) =
         process_fields(&cratename, &input.fields, &mut generics_output)?;
 
-    let add_definitions_recursively = quote! {
-        fn add_definitions_recursively(definitions: &mut #cratename::__private::maybestd::collections::BTreeMap<#cratename::schema::Declaration, #cratename::schema::Definition>) {
+    let add_definitions_recursively = match item::contains_version(&input.attrs)? {
+        Some(version) => versioned_output(
+            &version,
+            struct_fields,
+            add_definitions_recursively,
+            &cratename,
+        ),
+        None => quote! {
             #struct_fields
             let definition = #cratename::schema::Definition::Struct { fields };
 
@@ -68,6 +77,11 @@ pub fn process(input: &ItemStruct, cratename: Path) -> syn::Result<TokenStream2>
             if no_recursion_flag {
                 #add_definitions_recursively
             }
+        },
+    };
+    let add_definitions_recursively = quote! {
+        fn add_definitions_recursively(definitions: &mut #cratename::__private::maybestd::collections::BTreeMap<#cratename::schema::Declaration, #cratename::schema::Definition>) {
+            #add_definitions_recursively
         }
     };
 
@@ -84,6 +98,58 @@ pub fn process(input: &ItemStruct, cratename: Path) -> syn::Result<TokenStream2>
     })
 }
 
+/// versioned struct is described as an enum, tagged with the version, with a variant per each
+/// version: previous versions are declared as their types, the current one as `<declaration>__V<version>`
+fn versioned_output(
+    version: &item::Version,
+    struct_fields: TokenStream2,
+    add_definitions_recursively: TokenStream2,
+    cratename: &Path,
+) -> TokenStream2 {
+    let current = version.current;
+    let current_variant = format!("V{}", current);
+    let mut variants = vec![(
+        current,
+        quote! {
+            (#current as i64, #current_variant.into(), current_declaration.clone())
+        },
+    )];
+    let mut add_previous_definitions = TokenStream2::new();
+    for (previous, previous_version) in &version.migrate_from {
+        let previous_variant = format!("V{}", previous_version);
+        variants.push((
+            *previous_version,
+            quote! {
+                (#previous_version as i64, #previous_variant.into(), <#previous as #cratename::BorshSchema>::declaration())
+            },
+        ));
+        add_previous_definitions.extend(quote! {
+            <#previous as #cratename::BorshSchema>::add_definitions_recursively(definitions);
+        });
+    }
+    variants.sort_by_key(|(version, _)| *version);
+    let variants = variants.into_iter().map(|(_, variant)| variant);
+
+    quote! {
+        #struct_fields
+        let current_declaration = #cratename::__private::maybestd::format!(
+            "{}__{}", <Self as #cratename::BorshSchema>::declaration(), #current_variant
+        );
+        let definition = #cratename::schema::Definition::Enum {
+            tag_width: 1,
+            variants: #cratename::__private::maybestd::vec![#(#variants),*],
+        };
+
+        let no_recursion_flag = definitions.get(&<Self as #cratename::BorshSchema>::declaration()).is_none();
+        #cratename::schema::add_definition(<Self as #cratename::BorshSchema>::declaration(), definition, definitions);
+        #cratename::schema::add_definition(current_declaration, #cratename::schema::Definition::Struct { fields }, definitions);
+        if no_recursion_flag {
+            #add_definitions_recursively
+            #add_previous_definitions
+        }
+    }
+}
+
 fn process_fields(
     cratename: &Path,
     fields: &Fields,
@@ -94,6 +160,7 @@ fn process_fields(
 
     // Generate function that returns the schema of required types.
     let mut fields_vec = vec![];
+    field::check_trailing_default(fields)?;
     schema::visit_struct_fields(fields, &mut generics.params_visitor)?;
     match fields {
         Fields::Named(fields) => {
@@ -146,14 +213,20 @@ fn process_field(
     if !parsed.skip {
         let field_name = field.ident.as_ref();
         let field_type = &field.ty;
+        // fields, which may be absent from the end of input, are marked in schema
+        let field_type: Type = if parsed.trailing_default {
+            syn::parse2(quote! { #cratename::schema::TrailingDefault<#field_type> }).unwrap()
+        } else {
+            field_type.clone()
+        };
         fields_vec.push(field_declaration_output(
             field_name,
-            field_type,
+            &field_type,
             cratename,
             parsed.schema_declaration(),
         ));
         add_definitions_recursively.extend(field_definitions_output(
-            field_type,
+            &field_type,
             cratename,
             parsed.schema_definitions(),
         ));
@@ -526,4 +599,34 @@ mod tests {
 
         local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
     }
+
+    #[test]
+    fn trailing_default() {
+        let item_struct: ItemStruct = syn::parse2(quote! {
+            struct A {
+                x: u64,
+                #[borsh(trailing_default)]
+                y: String,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&item_struct, default_cratename()).unwrap();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn versioned_struct() {
+        let item_struct: ItemStruct = syn::parse2(quote! {
+            #[borsh(version = 3, migrate_from(StateV1 = 1, old::StateV2 = 2))]
+            struct State {
+                x: u64,
+                y: String,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&item_struct, default_cratename()).unwrap();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
 }
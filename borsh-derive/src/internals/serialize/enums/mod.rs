@@ -1,9 +1,9 @@
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
 use syn::{Fields, Ident, ItemEnum, Path, Variant};
 
 use crate::internals::{
-    attributes::{field, item, BoundType},
+    attributes::{field, item, variant, BoundType},
     enum_discriminant::Discriminants,
     generics, serialize,
 };
@@ -16,31 +16,60 @@ pub fn process(input: &ItemEnum, cratename: Path) -> syn::Result<TokenStream2> {
     let mut generics_output = serialize::GenericsOutput::new(&generics);
     let mut all_variants_idx_body = TokenStream2::new();
     let mut fields_body = TokenStream2::new();
+    let mut fields_size_body = TokenStream2::new();
     let use_discriminant = item::contains_use_discriminant(input)?;
-    let discriminants = Discriminants::new(&input.variants);
+    let discriminants = Discriminants::new(&input.variants)?;
+    let unknown = variant::unknown_variant(input)?;
     let mut has_unit_variant = false;
 
     for (variant_idx, variant) in input.variants.iter().enumerate() {
         let variant_ident = &variant.ident;
-        let discriminant_value = discriminants.get(variant_ident, use_discriminant, variant_idx)?;
-        let variant_output = process_variant(
-            variant,
-            enum_ident,
-            &discriminant_value,
-            &cratename,
-            &mut generics_output,
-        )?;
+        let variant_output = if let Some(unknown) = unknown
+            .as_ref()
+            .filter(|unknown| unknown.ident == *variant_ident)
+        {
+            unknown_variant_output(unknown, enum_ident)
+        } else if variant::Attributes::parse(&variant.attrs)?.skip {
+            skipped_variant_output(variant, enum_ident, &cratename)
+        } else {
+            let discriminant_value =
+                discriminants.get(variant_ident, use_discriminant, variant_idx)?;
+            process_variant(
+                variant,
+                enum_ident,
+                &discriminant_value,
+                &cratename,
+                &mut generics_output,
+            )?
+        };
         all_variants_idx_body.extend(variant_output.variant_idx_body);
         match variant_output.body {
             VariantBody::Unit => has_unit_variant = true,
-            VariantBody::Fields(VariantFields { header, body }) => fields_body.extend(quote!(
-                #enum_ident::#variant_ident #header => {
-                    #body
-                }
-            )),
+            VariantBody::Fields(VariantFields { header, body, size }) => {
+                fields_body.extend(quote!(
+                    #enum_ident::#variant_ident #header => {
+                        #body
+                    }
+                ));
+                fields_size_body.extend(match size {
+                    Some(size) => quote!(
+                        #enum_ident::#variant_ident #header => 0 #size,
+                    ),
+                    None => {
+                        let any_fields = match &variant.fields {
+                            Fields::Unnamed(..) => quote! { (..) },
+                            _ => quote! { { .. } },
+                        };
+                        quote!(
+                            #enum_ident::#variant_ident #any_fields => return ::core::option::Option::None,
+                        )
+                    }
+                });
+            }
         }
     }
     let fields_body = optimize_fields_body(fields_body, has_unit_variant);
+    let fields_size_body = optimize_fields_size_body(fields_size_body, has_unit_variant);
     generics_output.extend(&mut where_clause, &cratename);
 
     Ok(quote! {
@@ -55,6 +84,10 @@ pub fn process(input: &ItemEnum, cratename: Path) -> syn::Result<TokenStream2> {
                 #fields_body
                 Ok(())
             }
+
+            fn serialized_size_hint(&self) -> ::core::option::Option<usize> {
+                ::core::option::Option::Some(1 #fields_size_body)
+            }
         }
     })
 }
@@ -86,10 +119,47 @@ fn optimize_fields_body(fields_body: TokenStream2, has_unit_variant: bool) -> To
     }
 }
 
-#[derive(Default)]
+/// Same as [optimize_fields_body], but for the sum of serialized sizes of the fields,
+/// which is added to the size of variant tag.
+fn optimize_fields_size_body(
+    fields_size_body: TokenStream2,
+    has_unit_variant: bool,
+) -> TokenStream2 {
+    if fields_size_body.is_empty() {
+        fields_size_body
+    } else {
+        let unit_fields_catchall = if has_unit_variant {
+            quote!(
+                _ => 0,
+            )
+        } else {
+            TokenStream2::new()
+        };
+        quote!(
+            + match self {
+                #fields_size_body
+                #unit_fields_catchall
+            }
+        )
+    }
+}
+
 struct VariantFields {
     header: TokenStream2,
     body: TokenStream2,
+    /// sum of size hints of the fields, each prefixed with `+`,
+    /// or `None`, if some field has no size hint
+    size: Option<TokenStream2>,
+}
+
+impl Default for VariantFields {
+    fn default() -> Self {
+        Self {
+            header: TokenStream2::new(),
+            body: TokenStream2::new(),
+            size: Some(TokenStream2::new()),
+        }
+    }
 }
 
 impl VariantFields {
@@ -100,6 +170,7 @@ impl VariantFields {
             // `..` pattern matching works even if all fields were specified
             header: quote! { { #header.. }},
             body: self.body,
+            size: self.size,
         }
     }
     fn unnamed_header(self) -> Self {
@@ -108,6 +179,7 @@ impl VariantFields {
         VariantFields {
             header: quote! { ( #header )},
             body: self.body,
+            size: self.size,
         }
     }
 }
@@ -169,6 +241,54 @@ fn process_variant(
     Ok(variant_output)
 }
 
+/// `#[borsh(unknown)]` variant writes back the tag it was deserialized with, followed
+/// by its raw payload, so that relayed data stays byte-for-byte identical
+fn unknown_variant_output(unknown: &variant::UnknownVariant, enum_ident: &Ident) -> VariantOutput {
+    let variant_ident = &unknown.ident;
+    let tag = &unknown.tag;
+    let payload = &unknown.payload;
+    let tag_binding = Ident::new("tag", Span::mixed_site());
+    let payload_binding = Ident::new("payload", Span::mixed_site());
+    VariantOutput {
+        body: VariantBody::Fields(VariantFields {
+            header: quote! { { #payload: #payload_binding, .. } },
+            body: quote! {
+                writer.write_all(::core::convert::AsRef::<[u8]>::as_ref(#payload_binding))?;
+            },
+            size: Some(quote! {
+                + ::core::convert::AsRef::<[u8]>::as_ref(#payload_binding).len()
+            }),
+        }),
+        variant_idx_body: quote!(
+            #enum_ident::#variant_ident { #tag: #tag_binding, .. } => *#tag_binding,
+        ),
+    }
+}
+
+/// `#[borsh(skip)]` variant can't be written, serialization of it fails with an error
+fn skipped_variant_output(
+    variant: &Variant,
+    enum_ident: &Ident,
+    cratename: &Path,
+) -> VariantOutput {
+    let variant_ident = &variant.ident;
+    let message = format!(
+        "enum variant `{}::{}` is skipped and can't be serialized",
+        enum_ident, variant_ident
+    );
+    VariantOutput {
+        body: VariantBody::Unit,
+        variant_idx_body: quote!(
+            #enum_ident::#variant_ident { .. } => {
+                return Err(#cratename::io::Error::new(
+                    #cratename::io::ErrorKind::InvalidInput,
+                    #message,
+                ));
+            }
+        ),
+    }
+}
+
 fn process_field(
     field: &syn::Field,
     field_id: serialize::FieldId,
@@ -189,6 +309,14 @@ fn process_field(
     }
 
     if !parsed.skip {
+        match field_id.size_hint_output(cratename, parsed.serialize_with.as_ref()) {
+            Some(field_size) => {
+                if let Some(size) = &mut output.size {
+                    size.extend(field_size);
+                }
+            }
+            None => output.size = None,
+        }
         let delta = field_id.serialize_output(cratename, parsed.serialize_with);
         output.body.extend(delta);
         if needs_bounds_derive {
@@ -201,7 +329,8 @@ fn process_field(
 #[cfg(test)]
 mod tests {
     use crate::internals::test_helpers::{
-        default_cratename, local_insta_assert_snapshot, pretty_print_syn_str,
+        default_cratename, local_insta_assert_debug_snapshot, local_insta_assert_snapshot,
+        pretty_print_syn_str,
     };
 
     use super::*;
@@ -496,4 +625,115 @@ mod tests {
 
         local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
     }
+
+    #[test]
+    fn unknown_variant() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum X {
+                A(u16),
+                B,
+                #[borsh(unknown)]
+                Unknown { tag: u8, payload: Vec<u8> },
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn explicit_tags() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum X {
+                #[borsh(tag = 7)]
+                A(u16),
+                B,
+                #[borsh(tag = 2)]
+                C {x: i32, y: i32},
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn explicit_tags_duplicate() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum X {
+                #[borsh(tag = 1)]
+                A(u16),
+                B,
+                C,
+                #[borsh(tag = 2)]
+                D,
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename());
+
+        let err = match actual {
+            Ok(..) => unreachable!("expecting error here"),
+            Err(err) => err,
+        };
+        local_insta_assert_debug_snapshot!(err);
+    }
+
+    #[test]
+    fn explicit_tags_overflow() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum X {
+                #[borsh(tag = 255)]
+                A(u16),
+                B,
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename());
+
+        let err = match actual {
+            Ok(..) => unreachable!("expecting error here"),
+            Err(err) => err,
+        };
+        local_insta_assert_debug_snapshot!(err);
+    }
+
+    #[test]
+    fn explicit_tags_with_use_discriminant() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            #[borsh(use_discriminant = true)]
+            enum X {
+                #[borsh(tag = 3)]
+                A = 1,
+                B,
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename());
+
+        let err = match actual {
+            Ok(..) => unreachable!("expecting error here"),
+            Err(err) => err,
+        };
+        local_insta_assert_debug_snapshot!(err);
+    }
+
+    #[test]
+    fn skipped_variant() {
+        let item_enum: ItemEnum = syn::parse2(quote! {
+            enum X<F> {
+                A(u16),
+                #[borsh(skip)]
+                Pending(F),
+                B { x: i32 },
+                C,
+            }
+        })
+        .unwrap();
+        let actual = process(&item_enum, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
 }
@@ -88,6 +88,21 @@ impl FieldId {
             quote! { #cratename::BorshSerialize::serialize(#arg, writer)?; }
         }
     }
+    /// function which computes derive output [proc_macro2::TokenStream]
+    /// of code, which adds size hint of single field to the sum,
+    /// or `None`, if the field has no size hint
+    pub fn size_hint_output(
+        &self,
+        cratename: &Path,
+        serialize_with: Option<&ExprPath>,
+    ) -> Option<TokenStream2> {
+        // size of a field, serialized with a custom function, is unknown without serializing it
+        if serialize_with.is_some() {
+            return None;
+        }
+        let arg: Expr = self.serialize_arg();
+        Some(quote! { + #cratename::BorshSerialize::serialized_size_hint(#arg)? })
+    }
     pub fn enum_variant_header(&self, skipped: bool) -> Option<TokenStream2> {
         match self {
             Self::Struct(..) | Self::StructUnnamed(..) => unreachable!("no variant header"),
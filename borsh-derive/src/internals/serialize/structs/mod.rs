@@ -3,7 +3,7 @@ use quote::quote;
 use syn::{Fields, ItemStruct, Path};
 
 use crate::internals::{
-    attributes::{field, BoundType},
+    attributes::{field, item, BoundType},
     generics, serialize,
 };
 
@@ -13,25 +13,64 @@ pub fn process(input: &ItemStruct, cratename: Path) -> syn::Result<TokenStream2>
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let mut where_clause = generics::default_where(where_clause);
     let mut body = TokenStream2::new();
+    // `None` if some field has no size hint
+    let mut size_body = Some(TokenStream2::new());
+    // `None` if size of some field isn't known from its type
+    let mut fixed_size_body = Some(TokenStream2::new());
     let mut generics_output = serialize::GenericsOutput::new(&generics);
     match &input.fields {
         Fields::Named(fields) => {
             for field in &fields.named {
                 let field_id = serialize::FieldId::Struct(field.ident.clone().unwrap());
 
-                process_field(field, field_id, &cratename, &mut generics_output, &mut body)?;
+                process_field(
+                    field,
+                    field_id,
+                    &cratename,
+                    &mut generics_output,
+                    &mut body,
+                    &mut size_body,
+                    &mut fixed_size_body,
+                )?;
             }
         }
         Fields::Unnamed(fields) => {
             for (field_idx, field) in fields.unnamed.iter().enumerate() {
                 let field_id = serialize::FieldId::new_struct_unnamed(field_idx)?;
 
-                process_field(field, field_id, &cratename, &mut generics_output, &mut body)?;
+                process_field(
+                    field,
+                    field_id,
+                    &cratename,
+                    &mut generics_output,
+                    &mut body,
+                    &mut size_body,
+                    &mut fixed_size_body,
+                )?;
             }
         }
         Fields::Unit => {}
     }
     generics_output.extend(&mut where_clause, &cratename);
+    let mut base_size = quote! { 0 };
+    if let Some(version) = item::contains_version(&input.attrs)? {
+        let current = version.current;
+        body = quote! {
+            writer.write_all(&#current.to_le_bytes())?;
+            #body
+        };
+        base_size = quote! { 1 };
+    }
+    let serialized_size_hint = match size_body {
+        Some(size_body) => quote! { ::core::option::Option::Some(#base_size #size_body) },
+        None => quote! { ::core::option::Option::None },
+    };
+    let fixed_size = match fixed_size_body {
+        Some(fixed_size_body) => {
+            quote! { ::core::option::Option::Some(#base_size #fixed_size_body) }
+        }
+        None => quote! { ::core::option::Option::None },
+    };
 
     Ok(quote! {
         #[automatically_derived]
@@ -40,6 +79,14 @@ pub fn process(input: &ItemStruct, cratename: Path) -> syn::Result<TokenStream2>
                 #body
                 Ok(())
             }
+
+            fn serialized_size_hint(&self) -> ::core::option::Option<usize> {
+                #serialized_size_hint
+            }
+
+            fn fixed_serialized_size() -> ::core::option::Option<usize> {
+                #fixed_size
+            }
         }
     })
 }
@@ -50,6 +97,8 @@ fn process_field(
     cratename: &Path,
     generics: &mut serialize::GenericsOutput,
     body: &mut TokenStream2,
+    size_body: &mut Option<TokenStream2>,
+    fixed_size_body: &mut Option<TokenStream2>,
 ) -> syn::Result<()> {
     let parsed = field::Attributes::parse(&field.attrs)?;
     let needs_bounds_derive = parsed.needs_bounds_derive(BoundType::Serialize);
@@ -58,6 +107,22 @@ fn process_field(
         .overrides
         .extend(parsed.collect_bounds(BoundType::Serialize));
     if !parsed.skip {
+        match field_id.size_hint_output(cratename, parsed.serialize_with.as_ref()) {
+            Some(field_size) => {
+                if let Some(size_body) = size_body {
+                    size_body.extend(field_size);
+                }
+            }
+            None => *size_body = None,
+        }
+        if parsed.serialize_with.is_some() {
+            *fixed_size_body = None;
+        } else if let Some(fixed_size_body) = fixed_size_body {
+            let field_type = &field.ty;
+            fixed_size_body.extend(quote! {
+                + <#field_type as #cratename::BorshSerialize>::fixed_serialized_size()?
+            });
+        }
         let delta = field_id.serialize_output(cratename, parsed.serialize_with);
         body.extend(delta);
 
@@ -300,4 +365,19 @@ mod tests {
         };
         local_insta_assert_debug_snapshot!(err);
     }
+
+    #[test]
+    fn versioned_struct() {
+        let item_struct: ItemStruct = syn::parse2(quote! {
+            #[borsh(version = 3, migrate_from(StateV1 = 1, old::StateV2 = 2))]
+            struct State {
+                x: u64,
+                y: String,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&item_struct, default_cratename()).unwrap();
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
 }
@@ -0,0 +1,200 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Fields, ItemStruct, Path};
+
+use crate::internals::{
+    attributes::{field, item},
+    deserialize,
+};
+
+pub fn process(input: &ItemStruct, cratename: Path) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let fields = match &input.fields {
+        Fields::Named(fields) => &fields.named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`BorshView` can only be derived for structs with named fields",
+            ))
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`BorshView` can't be derived for generic structs",
+        ));
+    }
+    if item::contains_version(&input.attrs)?.is_some() {
+        return Err(syn::Error::new_spanned(
+            name,
+            "`BorshView` can't be derived for versioned structs",
+        ));
+    }
+    field::check_trailing_default(&input.fields)?;
+
+    let view_name = format_ident!("{}View", name);
+    let doc = format!(
+        "Lazy view of borsh-encoded [`{}`], whose accessors decode single fields.",
+        name
+    );
+    let mut accessors = TokenStream2::new();
+    // code, which advances `reader` past the fields preceding the current one
+    let mut skip_preceding = TokenStream2::new();
+    for field in fields {
+        let parsed = field::Attributes::parse(&field.attrs)?;
+        if parsed.skip {
+            continue;
+        }
+        let field_name = field.ident.as_ref().unwrap();
+        let field_vis = &field.vis;
+        let ty = &field.ty;
+        let trailing_default = if parsed.trailing_default {
+            quote! {
+                if reader.is_empty() {
+                    return Ok(::core::default::Default::default());
+                }
+            }
+        } else {
+            TokenStream2::new()
+        };
+        let value = match &parsed.deserialize_with {
+            Some(path) => quote! { #path(reader) },
+            None => quote! { <#ty as #cratename::BorshDeserialize>::deserialize(reader) },
+        };
+        let accessor_doc = format!(
+            "Decodes `{}` field, skipping the fields before it.",
+            field_name
+        );
+        accessors.extend(quote! {
+            #[doc = #accessor_doc]
+            #field_vis fn #field_name(&self) -> ::core::result::Result<#ty, #cratename::io::Error> {
+                let reader = &mut &self.bytes[..];
+                #skip_preceding
+                #trailing_default
+                #value
+            }
+        });
+        skip_preceding.extend(trailing_default);
+        skip_preceding.extend(deserialize::field_skip_output(
+            ty,
+            &cratename,
+            parsed.deserialize_with.as_ref(),
+        ));
+    }
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy)]
+        #vis struct #view_name<'a> {
+            bytes: &'a [u8],
+        }
+
+        #[automatically_derived]
+        impl<'a> #view_name<'a> {
+            /// Creates a view of `bytes`, which are validated only as far as accessors decode them.
+            #vis fn new(bytes: &'a [u8]) -> Self {
+                Self { bytes }
+            }
+
+            /// Returns the viewed bytes.
+            #vis fn as_bytes(&self) -> &'a [u8] {
+                self.bytes
+            }
+
+            #accessors
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::internals::test_helpers::{
+        default_cratename, local_insta_assert_debug_snapshot, local_insta_assert_snapshot,
+        pretty_print_syn_str,
+    };
+
+    use super::*;
+
+    #[test]
+    fn simple_struct() {
+        let input: ItemStruct = syn::parse2(quote! {
+            pub struct A {
+                pub owner: [u8; 32],
+                balance: u64,
+                name: String,
+                tags: Vec<String>,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&input, default_cratename()).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn field_attributes_with_custom_crate() {
+        let input: ItemStruct = syn::parse2(quote! {
+            #[borsh(crate = "reexporter::borsh")]
+            struct A {
+                #[borsh(skip)]
+                cache: u64,
+                #[borsh(deserialize_with = "third_party_impl::deserialize_third_party")]
+                x: ThirdParty<u64, String>,
+                y: u8,
+                #[borsh(trailing_default)]
+                z: Option<u32>,
+                #[borsh(trailing_default)]
+                w: Vec<u8>,
+            }
+        })
+        .unwrap();
+
+        let crate_: Path = syn::parse2(quote! { reexporter::borsh }).unwrap();
+        let actual = process(&input, crate_).unwrap();
+
+        local_insta_assert_snapshot!(pretty_print_syn_str(&actual).unwrap());
+    }
+
+    #[test]
+    fn check_tuple_struct_rejected() {
+        let input: ItemStruct = syn::parse2(quote! {
+            struct A(u64, String);
+        })
+        .unwrap();
+
+        let actual = process(&input, default_cratename());
+
+        local_insta_assert_debug_snapshot!(actual.unwrap_err());
+    }
+
+    #[test]
+    fn check_generics_rejected() {
+        let input: ItemStruct = syn::parse2(quote! {
+            struct A<T> {
+                x: T,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&input, default_cratename());
+
+        local_insta_assert_debug_snapshot!(actual.unwrap_err());
+    }
+
+    #[test]
+    fn check_version_rejected() {
+        let input: ItemStruct = syn::parse2(quote! {
+            #[borsh(version = 1)]
+            struct A {
+                x: u64,
+            }
+        })
+        .unwrap();
+
+        let actual = process(&input, default_cratename());
+
+        local_insta_assert_debug_snapshot!(actual.unwrap_err());
+    }
+}
@@ -18,7 +18,7 @@ use crate::internals::attributes::item;
 
 #[cfg(feature = "schema")]
 use internals::schema;
-use internals::{cratename, deserialize, serialize};
+use internals::{cratename, deserialize, pod, serialize, view};
 
 fn check_attrs_get_cratename(input: &TokenStream) -> Result<Path, Error> {
     let input = input.clone();
@@ -86,6 +86,50 @@ pub fn borsh_deserialize(input: TokenStream) -> TokenStream {
     })
 }
 
+/// ---
+///
+/// moved to docs of **Derive Macro** `BorshPod` in `borsh` crate
+#[proc_macro_derive(BorshPod, attributes(borsh))]
+pub fn borsh_pod(input: TokenStream) -> TokenStream {
+    let cratename = match check_attrs_get_cratename(&input) {
+        Ok(cratename) => cratename,
+        Err(err) => {
+            return err.to_compile_error().into();
+        }
+    };
+
+    let res = syn::parse::<DeriveInput>(input).and_then(|input| pod::process(&input, cratename));
+    TokenStream::from(match res {
+        Ok(res) => res,
+        Err(err) => err.to_compile_error(),
+    })
+}
+
+/// ---
+///
+/// moved to docs of **Derive Macro** `BorshView` in `borsh` crate
+#[proc_macro_derive(BorshView, attributes(borsh))]
+pub fn borsh_view(input: TokenStream) -> TokenStream {
+    let cratename = match check_attrs_get_cratename(&input) {
+        Ok(cratename) => cratename,
+        Err(err) => {
+            return err.to_compile_error().into();
+        }
+    };
+
+    let res = match syn::parse::<ItemStruct>(input) {
+        Ok(input) => view::process(&input, cratename),
+        Err(_) => Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`BorshView` can only be derived for structs with named fields",
+        )),
+    };
+    TokenStream::from(match res {
+        Ok(res) => res,
+        Err(err) => err.to_compile_error(),
+    })
+}
+
 /// ---
 ///
 /// moved to docs of **Derive Macro** `BorshSchema` in `borsh` crate
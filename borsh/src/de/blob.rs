@@ -0,0 +1,114 @@
+use crate::io::{Read, Result, Write};
+
+use super::{unexpected_eof_to_unexpected_length_of_input, BorshDeserialize};
+
+/// Size of the stack buffer bytes are copied through.
+const COPY_BUFFER_SIZE: usize = 8 * 1024;
+
+/// Reader of the contents of a byte blob, which doesn't read past its end.
+///
+/// Handed to the callback of [read_blob].
+pub struct BlobReader<'a, R> {
+    inner: &'a mut R,
+    remaining: usize,
+}
+
+impl<R: Read> BlobReader<'_, R> {
+    /// Returns the number of bytes of the blob not yet read.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<R: Read> Read for BlobReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let len = buf.len().min(self.remaining);
+        let read = self.inner.read(&mut buf[..len])?;
+        self.remaining -= read;
+        Ok(read)
+    }
+}
+
+/// Reads a byte blob encoded as `Vec<u8>` from `reader`, handing its contents to `f` as a
+/// [BlobReader], instead of collecting them in memory.
+///
+/// The length of the blob is available as [BlobReader::remaining]. Contents left unread by `f`
+/// are skipped, so that `reader` is positioned past the blob afterwards. It may be used in
+/// functions passed to `#[borsh(deserialize_with = ...)]`.
+///
+/// ```
+/// use borsh::io::Read;
+///
+/// let bytes = borsh::to_vec(&(vec![1u8, 2, 3, 4], 5u8)).unwrap();
+/// let mut reader = &bytes[..];
+/// let (len, first) = borsh::de::read_blob(&mut reader, |blob| {
+///     let len = blob.remaining();
+///     let mut first = [0u8; 1];
+///     blob.read_exact(&mut first)?;
+///     Ok((len, first[0]))
+/// })
+/// .unwrap();
+/// assert_eq!((len, first), (4, 1));
+/// assert_eq!(reader, &[5]);
+/// ```
+pub fn read_blob<R, F, O>(reader: &mut R, f: F) -> Result<O>
+where
+    R: Read,
+    F: FnOnce(&mut BlobReader<'_, R>) -> Result<O>,
+{
+    let len = u32::deserialize_reader(reader)? as usize;
+    let mut blob = BlobReader {
+        inner: reader,
+        remaining: len,
+    };
+    let output = f(&mut blob)?;
+    copy_exact(&mut blob, &mut Discard)?;
+    Ok(output)
+}
+
+/// Reads a byte blob encoded as `Vec<u8>` from `reader`, copying its contents into `sink`
+/// instead of collecting them in memory.
+///
+/// Returns the length of the blob.
+///
+/// ```
+/// let bytes = borsh::to_vec(&vec![1u8, 2, 3]).unwrap();
+/// let mut sink = Vec::new();
+/// assert_eq!(borsh::de::copy_blob(&mut &bytes[..], &mut sink).unwrap(), 3);
+/// assert_eq!(sink, vec![1, 2, 3]);
+/// ```
+pub fn copy_blob<R: Read, W: Write + ?Sized>(reader: &mut R, sink: &mut W) -> Result<usize> {
+    read_blob(reader, |blob| {
+        let len = blob.remaining();
+        copy_exact(blob, sink)?;
+        Ok(len)
+    })
+}
+
+/// Copies all of the remaining contents of `blob` to `sink`.
+fn copy_exact<R: Read, W: Write + ?Sized>(
+    blob: &mut BlobReader<'_, R>,
+    sink: &mut W,
+) -> Result<()> {
+    let mut buf = [0u8; COPY_BUFFER_SIZE];
+    while blob.remaining > 0 {
+        let len = blob.remaining.min(COPY_BUFFER_SIZE);
+        blob.read_exact(&mut buf[..len])
+            .map_err(unexpected_eof_to_unexpected_length_of_input)?;
+        sink.write_all(&buf[..len])?;
+    }
+    Ok(())
+}
+
+/// Sink which drops everything written to it.
+struct Discard;
+
+impl Write for Discard {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -11,7 +11,8 @@ use bytes::{BufMut, BytesMut};
 use crate::__private::maybestd::{
     borrow::{Borrow, Cow, ToOwned},
     boxed::Box,
-    collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    ffi::CString,
     format,
     string::{String, ToString},
     vec,
@@ -21,13 +22,34 @@ use crate::io::{Error, ErrorKind, Read, Result};
 
 use crate::error::check_zst;
 
+mod blob;
 mod hint;
+#[cfg(feature = "unstable__schema")]
+mod push;
+mod read;
+mod seq;
+
+pub use blob::{copy_blob, read_blob, BlobReader};
+#[cfg(feature = "unstable__schema")]
+pub use push::{Progress, PushDecoder};
+pub use read::BorshRead;
+#[cfg(feature = "bytes")]
+use read::BufSource;
+pub use seq::SeqReader;
 
 const ERROR_NOT_ALL_BYTES_READ: &str = "Not all bytes read";
 const ERROR_UNEXPECTED_LENGTH_OF_INPUT: &str = "Unexpected length of input";
 const ERROR_OVERFLOW_ON_MACHINE_WITH_32_BIT_ISIZE: &str = "Overflow on machine with 32 bit isize";
 const ERROR_OVERFLOW_ON_MACHINE_WITH_32_BIT_USIZE: &str = "Overflow on machine with 32 bit usize";
 const ERROR_INVALID_ZERO_VALUE: &str = "Expected a non-zero value";
+const ERROR_FLOAT_NAN: &str = "For portability reasons we do not allow to deserialize NaNs.";
+const ERROR_INVALID_NANOSECONDS: &str = "Nanoseconds must be less than one second";
+#[cfg(feature = "std")]
+const ERROR_SYSTEM_TIME_OUT_OF_RANGE: &str = "SystemTime is out of range";
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+const ERROR_TIMESTAMP_OUT_OF_RANGE: &str = "Timestamp is out of range";
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+const ERROR_DATE_OUT_OF_RANGE: &str = "Date is out of range";
 
 #[cfg(feature = "de_strict_order")]
 const ERROR_WRONG_ORDER_OF_KEYS: &str = "keys were not serialized in ascending order";
@@ -37,11 +59,21 @@ pub trait BorshDeserialize: Sized {
     /// Deserializes this instance from a given slice of bytes.
     /// Updates the buffer to point at the remaining bytes.
     fn deserialize(buf: &mut &[u8]) -> Result<Self> {
-        Self::deserialize_reader(&mut *buf)
+        Self::deserialize_from(buf)
     }
 
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self>;
 
+    /// Deserializes this instance from an in-memory [BorshRead] source.
+    ///
+    /// Defaults to [BorshDeserialize::deserialize_reader]. Built-in and derived implementations
+    /// override it to read primitive values directly from the source, and must produce the same
+    /// result as [BorshDeserialize::deserialize_reader].
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        Self::deserialize_reader(reader)
+    }
+
     /// Deserialize this instance from a slice of bytes.
     fn try_from_slice(v: &[u8]) -> Result<Self> {
         let mut v_mut = v;
@@ -61,6 +93,36 @@ pub trait BorshDeserialize: Sized {
         }
     }
 
+    /// Advances `reader` past an encoded value of the type, without constructing it.
+    ///
+    /// Defaults to only checking that `reader` is long enough for types, all values of which are
+    /// serialized into the same number of bytes, and to deserializing the value and dropping it
+    /// otherwise. Implementations for length-prefixed built-in types, and derived implementations
+    /// for structs, override it to skip their parts the same way, so contents of skipped values,
+    /// e.g. of `bool`s or utf-8 strings, aren't validated.
+    /// ```
+    /// use borsh::BorshDeserialize;
+    ///
+    /// let bytes = borsh::to_vec(&(String::from("skipped"), 7u32)).unwrap();
+    /// let mut buf = &bytes[..];
+    /// String::skip(&mut buf).unwrap();
+    /// assert_eq!(u32::deserialize(&mut buf).unwrap(), 7);
+    /// ```
+    #[inline]
+    fn skip<B: BorshRead>(reader: &mut B) -> Result<()> {
+        match Self::fixed_serialized_size() {
+            Some(size) => reader.take(size).map(drop),
+            None => Self::deserialize_from(reader).map(drop),
+        }
+    }
+
+    /// Returns the serialized size, which is the same for all values of the type, if there is one.
+    #[inline]
+    #[doc(hidden)]
+    fn fixed_serialized_size() -> Option<usize> {
+        None
+    }
+
     #[inline]
     #[doc(hidden)]
     fn vec_from_reader<R: Read>(len: u32, reader: &mut R) -> Result<Option<Vec<Self>>> {
@@ -75,6 +137,12 @@ pub trait BorshDeserialize: Sized {
         let _ = reader;
         Ok(None)
     }
+
+    #[inline]
+    #[doc(hidden)]
+    fn vec_from_borsh_read<B: BorshRead>(len: u32, reader: &mut B) -> Result<Option<Vec<Self>>> {
+        Self::vec_from_reader(len, reader)
+    }
 }
 
 /// Additional methods offered on enums which is used by `[derive(BorshDeserialize)]`.
@@ -133,6 +201,15 @@ pub trait EnumExt: BorshDeserialize {
     /// assert!(from_slice::<OneOrZero>(&data[..]).is_err());
     /// ```
     fn deserialize_variant<R: Read>(reader: &mut R, tag: u8) -> Result<Self>;
+
+    /// Deserialises given variant of an enum from an in-memory [BorshRead] source.
+    ///
+    /// Defaults to [EnumExt::deserialize_variant], derived implementations override it
+    /// the same way as [BorshDeserialize::deserialize_from].
+    #[inline]
+    fn deserialize_variant_from<B: BorshRead>(reader: &mut B, tag: u8) -> Result<Self> {
+        Self::deserialize_variant(reader, tag)
+    }
 }
 
 fn unexpected_eof_to_unexpected_length_of_input(e: Error) -> Error {
@@ -143,7 +220,34 @@ fn unexpected_eof_to_unexpected_length_of_input(e: Error) -> Error {
     }
 }
 
+/// Implements [BorshDeserialize::fixed_serialized_size] for a type, all values of which are
+/// serialized into `$size` bytes.
+macro_rules! fixed_serialized_size {
+    ($size: expr) => {
+        #[inline]
+        #[doc(hidden)]
+        fn fixed_serialized_size() -> Option<usize> {
+            Some($size)
+        }
+    };
+}
+
+/// skips `len` values of `T`, at once if they're of fixed size
+fn skip_items<T: BorshDeserialize, B: BorshRead>(len: usize, reader: &mut B) -> Result<()> {
+    match T::fixed_serialized_size() {
+        Some(size) => {
+            let total = len.checked_mul(size).ok_or_else(|| {
+                Error::new(ErrorKind::InvalidData, ERROR_UNEXPECTED_LENGTH_OF_INPUT)
+            })?;
+            reader.take(total).map(drop)
+        }
+        None => (0..len).try_for_each(|_| T::skip(reader)),
+    }
+}
+
 impl BorshDeserialize for u8 {
+    fixed_serialized_size!(1);
+
     #[inline]
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
         let mut buf = [0u8; 1];
@@ -153,50 +257,128 @@ impl BorshDeserialize for u8 {
         Ok(buf[0])
     }
 
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        let [byte] = reader.take_array()?;
+        Ok(byte)
+    }
+
     #[inline]
     #[doc(hidden)]
     fn vec_from_reader<R: Read>(len: u32, reader: &mut R) -> Result<Option<Vec<Self>>> {
-        let len: usize = len.try_into().map_err(|_| ErrorKind::InvalidData)?;
-        // Avoid OOM by limiting the size of allocation.  This makes the read
-        // less efficient (since we need to loop and reallocate) but it protects
-        // us from someone sending us [0xff, 0xff, 0xff, 0xff] and forcing us to
-        // allocate 4GiB of memory.
-        let mut vec = vec![0u8; len.min(1024 * 1024)];
-        let mut pos = 0;
-        while pos < len {
-            if pos == vec.len() {
-                vec.resize(vec.len().saturating_mul(2).min(len), 0)
-            }
-            // TODO(mina86): Convert this to read_buf once that stabilises.
-            match reader.read(&mut vec.as_mut_slice()[pos..])? {
-                0 => {
-                    return Err(Error::new(
-                        ErrorKind::InvalidData,
-                        ERROR_UNEXPECTED_LENGTH_OF_INPUT,
-                    ))
-                }
-                read => {
-                    pos += read;
-                }
-            }
-        }
-        Ok(Some(vec))
+        // SAFETY: `u8` is serialized as is.
+        unsafe { primitive_vec_from_reader(len, reader) }.map(Some)
+    }
+
+    #[inline]
+    #[doc(hidden)]
+    fn vec_from_borsh_read<B: BorshRead>(len: u32, reader: &mut B) -> Result<Option<Vec<Self>>> {
+        // SAFETY: `u8` is serialized as is.
+        unsafe { primitive_vec_from_borsh_read(len, reader) }.map(Some)
     }
 
     #[inline]
     #[doc(hidden)]
     fn array_from_reader<R: Read, const N: usize>(reader: &mut R) -> Result<Option<[Self; N]>> {
-        let mut arr = [0u8; N];
-        reader
-            .read_exact(&mut arr)
-            .map_err(unexpected_eof_to_unexpected_length_of_input)?;
-        Ok(Some(arr))
+        // SAFETY: `u8` is serialized as is.
+        unsafe { primitive_array_from_reader(reader) }.map(Some)
+    }
+}
+
+/// Returns in-memory representation of a slice of primitive numbers.
+///
+/// # Safety
+///
+/// Any bit pattern must be a valid value of `T`.
+#[inline]
+unsafe fn primitives_as_bytes_mut<T: Copy>(slice: &mut [T]) -> &mut [u8] {
+    core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), core::mem::size_of_val(slice))
+}
+
+/// Reads `len` primitive numbers directly into the memory of a vector.
+///
+/// # Safety
+///
+/// Any bit pattern must be a valid value of `T`, and in-memory representation of `T`
+/// on the current target must be the same as its serialized representation.
+#[inline]
+unsafe fn primitive_vec_from_reader<T: Copy + Default, R: Read>(
+    len: u32,
+    reader: &mut R,
+) -> Result<Vec<T>> {
+    let len: usize = len.try_into().map_err(|_| ErrorKind::InvalidData)?;
+    let size = len
+        .checked_mul(size_of::<T>())
+        .ok_or(ErrorKind::InvalidData)?;
+    // Avoid OOM by limiting the size of allocation.  This makes the read
+    // less efficient (since we need to loop and reallocate) but it protects
+    // us from someone sending us [0xff, 0xff, 0xff, 0xff] and forcing us to
+    // allocate 4GiB of memory.
+    let mut vec = vec![T::default(); len.min(1024 * 1024 / size_of::<T>())];
+    let mut pos = 0;
+    while pos < size {
+        if pos == core::mem::size_of_val(vec.as_slice()) {
+            vec.resize(vec.len().saturating_mul(2).min(len), T::default())
+        }
+        // TODO(mina86): Convert this to read_buf once that stabilises.
+        match reader.read(&mut primitives_as_bytes_mut(vec.as_mut_slice())[pos..])? {
+            0 => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    ERROR_UNEXPECTED_LENGTH_OF_INPUT,
+                ))
+            }
+            read => {
+                pos += read;
+            }
+        }
     }
+    Ok(vec)
+}
+
+/// Copies `len` primitive numbers from a [BorshRead] source into the memory of a vector,
+/// which is allocated only after the source is checked to contain all of them.
+///
+/// # Safety
+///
+/// Same as for [primitive_vec_from_reader].
+#[inline]
+unsafe fn primitive_vec_from_borsh_read<T: Copy, B: BorshRead>(
+    len: u32,
+    reader: &mut B,
+) -> Result<Vec<T>> {
+    let len: usize = len.try_into().map_err(|_| ErrorKind::InvalidData)?;
+    let size = len
+        .checked_mul(size_of::<T>())
+        .ok_or(ErrorKind::InvalidData)?;
+    let bytes = reader.take(size)?;
+    let mut vec = Vec::<T>::with_capacity(len);
+    core::ptr::copy_nonoverlapping(bytes.as_ptr(), vec.as_mut_ptr().cast::<u8>(), size);
+    vec.set_len(len);
+    Ok(vec)
+}
+
+/// Reads `N` primitive numbers directly into the memory of an array.
+///
+/// # Safety
+///
+/// Same as for [primitive_vec_from_reader].
+#[inline]
+unsafe fn primitive_array_from_reader<T: Copy + Default, R: Read, const N: usize>(
+    reader: &mut R,
+) -> Result<[T; N]> {
+    let mut arr = [T::default(); N];
+    reader
+        .read_exact(primitives_as_bytes_mut(&mut arr))
+        .map_err(unexpected_eof_to_unexpected_length_of_input)?;
+    Ok(arr)
 }
 
 macro_rules! impl_for_integer {
     ($type: ident) => {
         impl BorshDeserialize for $type {
+            fixed_serialized_size!(size_of::<$type>());
+
             #[inline]
             fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
                 let mut buf = [0u8; size_of::<$type>()];
@@ -206,6 +388,41 @@ macro_rules! impl_for_integer {
                 let res = $type::from_le_bytes(buf.try_into().unwrap());
                 Ok(res)
             }
+
+            #[inline]
+            fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+                Ok($type::from_le_bytes(reader.take_array()?))
+            }
+
+            #[inline]
+            #[doc(hidden)]
+            #[cfg(target_endian = "little")]
+            fn vec_from_reader<R: Read>(len: u32, reader: &mut R) -> Result<Option<Vec<Self>>> {
+                // SAFETY: integers are valid for any bit pattern, and are serialized
+                // as their in-memory representation on little-endian targets.
+                unsafe { primitive_vec_from_reader(len, reader) }.map(Some)
+            }
+
+            #[inline]
+            #[doc(hidden)]
+            #[cfg(target_endian = "little")]
+            fn vec_from_borsh_read<B: BorshRead>(
+                len: u32,
+                reader: &mut B,
+            ) -> Result<Option<Vec<Self>>> {
+                // SAFETY: same as above.
+                unsafe { primitive_vec_from_borsh_read(len, reader) }.map(Some)
+            }
+
+            #[inline]
+            #[doc(hidden)]
+            #[cfg(target_endian = "little")]
+            fn array_from_reader<R: Read, const N: usize>(
+                reader: &mut R,
+            ) -> Result<Option<[Self; N]>> {
+                // SAFETY: same as above.
+                unsafe { primitive_array_from_reader(reader) }.map(Some)
+            }
         }
     };
 }
@@ -223,11 +440,19 @@ impl_for_integer!(u128);
 macro_rules! impl_for_nonzero_integer {
     ($type: ty) => {
         impl BorshDeserialize for $type {
+            fixed_serialized_size!(size_of::<$type>());
+
             #[inline]
             fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
                 <$type>::new(BorshDeserialize::deserialize_reader(reader)?)
                     .ok_or_else(|| Error::new(ErrorKind::InvalidData, ERROR_INVALID_ZERO_VALUE))
             }
+
+            #[inline]
+            fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+                <$type>::new(BorshDeserialize::deserialize_from(reader)?)
+                    .ok_or_else(|| Error::new(ErrorKind::InvalidData, ERROR_INVALID_ZERO_VALUE))
+            }
         }
     };
 }
@@ -245,29 +470,47 @@ impl_for_nonzero_integer!(core::num::NonZeroU128);
 impl_for_nonzero_integer!(core::num::NonZeroUsize);
 
 impl BorshDeserialize for isize {
+    fixed_serialized_size!(8);
+
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
-        let i: i64 = BorshDeserialize::deserialize_reader(reader)?;
-        let i = isize::try_from(i).map_err(|_| {
-            Error::new(
-                ErrorKind::InvalidData,
-                ERROR_OVERFLOW_ON_MACHINE_WITH_32_BIT_ISIZE,
-            )
-        })?;
-        Ok(i)
+        isize_from_i64(BorshDeserialize::deserialize_reader(reader)?)
+    }
+
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        isize_from_i64(BorshDeserialize::deserialize_from(reader)?)
     }
 }
 
+fn isize_from_i64(i: i64) -> Result<isize> {
+    isize::try_from(i).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidData,
+            ERROR_OVERFLOW_ON_MACHINE_WITH_32_BIT_ISIZE,
+        )
+    })
+}
+
 impl BorshDeserialize for usize {
+    fixed_serialized_size!(8);
+
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
-        let u: u64 = BorshDeserialize::deserialize_reader(reader)?;
-        let u = usize::try_from(u).map_err(|_| {
-            Error::new(
-                ErrorKind::InvalidData,
-                ERROR_OVERFLOW_ON_MACHINE_WITH_32_BIT_USIZE,
-            )
-        })?;
-        Ok(u)
+        usize_from_u64(BorshDeserialize::deserialize_reader(reader)?)
     }
+
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        usize_from_u64(BorshDeserialize::deserialize_from(reader)?)
+    }
+}
+
+fn usize_from_u64(u: u64) -> Result<usize> {
+    usize::try_from(u).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidData,
+            ERROR_OVERFLOW_ON_MACHINE_WITH_32_BIT_USIZE,
+        )
+    })
 }
 
 // Note NaNs have a portability issue. Specifically, signalling NaNs on MIPS are quiet NaNs on x86,
@@ -275,6 +518,8 @@ impl BorshDeserialize for usize {
 macro_rules! impl_for_float {
     ($type: ident, $int_type: ident) => {
         impl BorshDeserialize for $type {
+            fixed_serialized_size!(size_of::<$type>());
+
             #[inline]
             fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
                 let mut buf = [0u8; size_of::<$type>()];
@@ -283,13 +528,62 @@ macro_rules! impl_for_float {
                     .map_err(unexpected_eof_to_unexpected_length_of_input)?;
                 let res = $type::from_bits($int_type::from_le_bytes(buf.try_into().unwrap()));
                 if res.is_nan() {
-                    return Err(Error::new(
-                        ErrorKind::InvalidData,
-                        "For portability reasons we do not allow to deserialize NaNs.",
-                    ));
+                    return Err(Error::new(ErrorKind::InvalidData, ERROR_FLOAT_NAN));
+                }
+                Ok(res)
+            }
+
+            #[inline]
+            fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+                let res = $type::from_le_bytes(reader.take_array()?);
+                if res.is_nan() {
+                    return Err(Error::new(ErrorKind::InvalidData, ERROR_FLOAT_NAN));
                 }
                 Ok(res)
             }
+
+            #[inline]
+            #[doc(hidden)]
+            #[cfg(target_endian = "little")]
+            fn vec_from_reader<R: Read>(len: u32, reader: &mut R) -> Result<Option<Vec<Self>>> {
+                // SAFETY: floats are valid for any bit pattern, and are serialized
+                // as their in-memory representation on little-endian targets.
+                let vec: Vec<Self> = unsafe { primitive_vec_from_reader(len, reader) }?;
+                // the check doesn't short-circuit, so that it's vectorized
+                if vec.iter().fold(false, |nan, x| nan | x.is_nan()) {
+                    return Err(Error::new(ErrorKind::InvalidData, ERROR_FLOAT_NAN));
+                }
+                Ok(Some(vec))
+            }
+
+            #[inline]
+            #[doc(hidden)]
+            #[cfg(target_endian = "little")]
+            fn vec_from_borsh_read<B: BorshRead>(
+                len: u32,
+                reader: &mut B,
+            ) -> Result<Option<Vec<Self>>> {
+                // SAFETY: same as above.
+                let vec: Vec<Self> = unsafe { primitive_vec_from_borsh_read(len, reader) }?;
+                if vec.iter().fold(false, |nan, x| nan | x.is_nan()) {
+                    return Err(Error::new(ErrorKind::InvalidData, ERROR_FLOAT_NAN));
+                }
+                Ok(Some(vec))
+            }
+
+            #[inline]
+            #[doc(hidden)]
+            #[cfg(target_endian = "little")]
+            fn array_from_reader<R: Read, const N: usize>(
+                reader: &mut R,
+            ) -> Result<Option<[Self; N]>> {
+                // SAFETY: same as above.
+                let arr: [Self; N] = unsafe { primitive_array_from_reader(reader) }?;
+                if arr.iter().fold(false, |nan, x| nan | x.is_nan()) {
+                    return Err(Error::new(ErrorKind::InvalidData, ERROR_FLOAT_NAN));
+                }
+                Ok(Some(arr))
+            }
         }
     };
 }
@@ -298,21 +592,55 @@ impl_for_float!(f32, u32);
 impl_for_float!(f64, u64);
 
 impl BorshDeserialize for bool {
+    fixed_serialized_size!(1);
+
     #[inline]
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
-        let b: u8 = BorshDeserialize::deserialize_reader(reader)?;
-        if b == 0 {
-            Ok(false)
-        } else if b == 1 {
-            Ok(true)
-        } else {
-            let msg = format!("Invalid bool representation: {}", b);
+        bool_from_u8(BorshDeserialize::deserialize_reader(reader)?)
+    }
 
-            Err(Error::new(ErrorKind::InvalidData, msg))
-        }
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        bool_from_u8(BorshDeserialize::deserialize_from(reader)?)
+    }
+}
+
+#[inline]
+fn bool_from_u8(b: u8) -> Result<bool> {
+    if b == 0 {
+        Ok(false)
+    } else if b == 1 {
+        Ok(true)
+    } else {
+        let msg = format!("Invalid bool representation: {}", b);
+
+        Err(Error::new(ErrorKind::InvalidData, msg))
+    }
+}
+
+impl BorshDeserialize for char {
+    fixed_serialized_size!(4);
+
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        char_from_u32(BorshDeserialize::deserialize_reader(reader)?)
+    }
+
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        char_from_u32(BorshDeserialize::deserialize_from(reader)?)
     }
 }
 
+#[inline]
+fn char_from_u32(c: u32) -> Result<char> {
+    char::from_u32(c).ok_or_else(|| {
+        let msg = format!("Invalid char representation: {:#x}", c);
+
+        Error::new(ErrorKind::InvalidData, msg)
+    })
+}
+
 impl<T> BorshDeserialize for Option<T>
 where
     T: BorshDeserialize,
@@ -325,16 +653,40 @@ where
         } else if flag == 1 {
             Ok(Some(T::deserialize_reader(reader)?))
         } else {
-            let msg = format!(
-                "Invalid Option representation: {}. The first byte must be 0 or 1",
-                flag
-            );
+            Err(invalid_flag_error("Option", flag))
+        }
+    }
+
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        let flag: u8 = BorshDeserialize::deserialize_from(reader)?;
+        if flag == 0 {
+            Ok(None)
+        } else if flag == 1 {
+            Ok(Some(T::deserialize_from(reader)?))
+        } else {
+            Err(invalid_flag_error("Option", flag))
+        }
+    }
 
-            Err(Error::new(ErrorKind::InvalidData, msg))
+    fn skip<B: BorshRead>(reader: &mut B) -> Result<()> {
+        match u8::deserialize_from(reader)? {
+            0 => Ok(()),
+            1 => T::skip(reader),
+            flag => Err(invalid_flag_error("Option", flag)),
         }
     }
 }
 
+fn invalid_flag_error(type_name: &str, flag: u8) -> Error {
+    let msg = format!(
+        "Invalid {} representation: {}. The first byte must be 0 or 1",
+        type_name, flag
+    );
+
+    Error::new(ErrorKind::InvalidData, msg)
+}
+
 impl<T, E> BorshDeserialize for core::result::Result<T, E>
 where
     T: BorshDeserialize,
@@ -348,12 +700,27 @@ where
         } else if flag == 1 {
             Ok(Ok(T::deserialize_reader(reader)?))
         } else {
-            let msg = format!(
-                "Invalid Result representation: {}. The first byte must be 0 or 1",
-                flag
-            );
+            Err(invalid_flag_error("Result", flag))
+        }
+    }
+
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        let flag: u8 = BorshDeserialize::deserialize_from(reader)?;
+        if flag == 0 {
+            Ok(Err(E::deserialize_from(reader)?))
+        } else if flag == 1 {
+            Ok(Ok(T::deserialize_from(reader)?))
+        } else {
+            Err(invalid_flag_error("Result", flag))
+        }
+    }
 
-            Err(Error::new(ErrorKind::InvalidData, msg))
+    fn skip<B: BorshRead>(reader: &mut B) -> Result<()> {
+        match u8::deserialize_from(reader)? {
+            0 => E::skip(reader),
+            1 => T::skip(reader),
+            flag => Err(invalid_flag_error("Result", flag)),
         }
     }
 }
@@ -366,6 +733,21 @@ impl BorshDeserialize for String {
             Error::new(ErrorKind::InvalidData, msg)
         })
     }
+
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        let len = u32::deserialize_from(reader)?;
+        let len: usize = len.try_into().map_err(|_| ErrorKind::InvalidData)?;
+        // the bytes are validated in place, before being copied into a `String`
+        core::str::from_utf8(reader.take(len)?)
+            .map(String::from)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))
+    }
+
+    #[inline]
+    fn skip<B: BorshRead>(reader: &mut B) -> Result<()> {
+        <Vec<u8>>::skip(reader)
+    }
 }
 
 /// Module is available if borsh is built with `features = ["ascii"]`.
@@ -388,6 +770,8 @@ pub mod ascii {
     }
 
     impl BorshDeserialize for ascii::AsciiChar {
+        fixed_serialized_size!(1);
+
         #[inline]
         fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
             let byte = u8::deserialize_reader(reader)?;
@@ -419,6 +803,32 @@ where
             Ok(result)
         }
     }
+
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        check_zst::<T>()?;
+
+        let len = u32::deserialize_from(reader)?;
+        if len == 0 {
+            Ok(Vec::new())
+        } else if let Some(vec) = T::vec_from_borsh_read(len, reader)? {
+            Ok(vec)
+        } else {
+            let mut result = Vec::with_capacity(hint::cautious::<T>(len));
+            for _ in 0..len {
+                result.push(T::deserialize_from(reader)?);
+            }
+            Ok(result)
+        }
+    }
+
+    #[inline]
+    fn skip<B: BorshRead>(reader: &mut B) -> Result<()> {
+        check_zst::<T>()?;
+
+        let len = u32::deserialize_from(reader)?;
+        skip_items::<T, _>(len as usize, reader)
+    }
 }
 
 #[cfg(feature = "bytes")]
@@ -428,6 +838,12 @@ impl BorshDeserialize for bytes::Bytes {
         let vec = <Vec<u8>>::deserialize_reader(reader)?;
         Ok(vec.into())
     }
+
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        let len = u32::deserialize_from(reader)?;
+        reader.take_bytes(len as usize)
+    }
 }
 
 #[cfg(feature = "bytes")]
@@ -445,6 +861,8 @@ impl BorshDeserialize for bytes::BytesMut {
 
 #[cfg(feature = "bson")]
 impl BorshDeserialize for bson::oid::ObjectId {
+    fixed_serialized_size!(12);
+
     #[inline]
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
         let mut buf = [0u8; 12];
@@ -488,6 +906,8 @@ where
 
 #[cfg(feature = "uuid")]
 impl BorshDeserialize for uuid::Uuid {
+    fixed_serialized_size!(16);
+
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
         Ok(uuid::Uuid::from_bytes(
             BorshDeserialize::deserialize_reader(reader)?,
@@ -495,124 +915,1066 @@ impl BorshDeserialize for uuid::Uuid {
     }
 }
 
-impl<T> BorshDeserialize for Cow<'_, T>
-where
-    T: ToOwned + ?Sized,
-    T::Owned: BorshDeserialize,
-{
-    #[inline]
-    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
-        Ok(Cow::Owned(BorshDeserialize::deserialize_reader(reader)?))
-    }
-}
+/// Module is available if borsh is built with `features = ["primitive-types"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// unsigned integers from [primitive_types](::primitive_types) crate.
+#[cfg(feature = "primitive-types")]
+pub mod primitive_types {
+    use super::unexpected_eof_to_unexpected_length_of_input;
+    use crate::io::{Read, Result};
+    use crate::BorshDeserialize;
 
-impl<T> BorshDeserialize for VecDeque<T>
-where
-    T: BorshDeserialize,
-{
-    #[inline]
-    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
-        let vec = <Vec<T>>::deserialize_reader(reader)?;
-        Ok(vec.into())
+    macro_rules! impl_for_uint {
+        ($($type:ident : $size:expr),+) => {
+        $(
+            impl BorshDeserialize for ::primitive_types::$type {
+                fixed_serialized_size!(size_of::<Self>());
+
+                #[inline]
+                fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                    let mut buf = [0u8; $size];
+                    reader
+                        .read_exact(&mut buf)
+                        .map_err(unexpected_eof_to_unexpected_length_of_input)?;
+                    Ok(::primitive_types::$type::from_little_endian(&buf))
+                }
+            }
+        )+
+        };
     }
-}
 
-impl<T> BorshDeserialize for LinkedList<T>
-where
-    T: BorshDeserialize,
-{
-    #[inline]
-    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
-        let vec = <Vec<T>>::deserialize_reader(reader)?;
-        Ok(vec.into_iter().collect::<LinkedList<T>>())
-    }
+    impl_for_uint!(U128: 16, U256: 32, U512: 64);
 }
 
-/// Module is available if borsh is built with `features = ["std"]` or `features = ["hashbrown"]`.
+/// Module is available if borsh is built with `features = ["ethnum"]`.
 ///
 /// Module defines [BorshDeserialize] implementation for
-/// [HashMap](std::collections::HashMap)/[HashSet](std::collections::HashSet).
-#[cfg(hash_collections)]
-pub mod hashes {
-    use core::hash::{BuildHasher, Hash};
-
-    use crate::__private::maybestd::collections::{HashMap, HashSet};
-    use crate::__private::maybestd::vec::Vec;
+/// 256-bit integers from [ethnum](::ethnum) crate.
+#[cfg(feature = "ethnum")]
+pub mod ethnum {
+    use super::unexpected_eof_to_unexpected_length_of_input;
     use crate::io::{Read, Result};
     use crate::BorshDeserialize;
 
-    #[cfg(feature = "de_strict_order")]
-    const ERROR_WRONG_ORDER_OF_KEYS: &str = "keys were not serialized in ascending order";
-    use crate::error::check_zst;
-    #[cfg(feature = "de_strict_order")]
-    use crate::io::{Error, ErrorKind};
+    impl BorshDeserialize for ::ethnum::U256 {
+        fixed_serialized_size!(32);
 
-    impl<T, H> BorshDeserialize for HashSet<T, H>
-    where
-        T: BorshDeserialize + Eq + Hash + Ord,
-        H: BuildHasher + Default,
-    {
         #[inline]
         fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
-            // NOTE: deserialize-as-you-go approach as once was in HashSet is better in the sense
-            // that it allows to fail early, and not allocate memory for all the elements
-            // which may fail `cmp()` checks
-            // NOTE: deserialize first to `Vec<T>` is faster
-            let vec = <Vec<T>>::deserialize_reader(reader)?;
+            let mut buf = [0u8; 32];
+            reader
+                .read_exact(&mut buf)
+                .map_err(unexpected_eof_to_unexpected_length_of_input)?;
+            Ok(::ethnum::U256::from_le_bytes(buf))
+        }
+    }
 
-            #[cfg(feature = "de_strict_order")]
-            // TODO: replace with `is_sorted` api when stabilizes https://github.com/rust-lang/rust/issues/53485
-            // TODO: first replace with `array_windows` api when stabilizes https://github.com/rust-lang/rust/issues/75027
-            for pair in vec.windows(2) {
-                let [a, b] = pair else {
-                    unreachable!("`windows` always return a slice of length 2 or nothing");
-                };
-                let cmp_result = a.cmp(b).is_lt();
-                if !cmp_result {
-                    return Err(Error::new(
-                        ErrorKind::InvalidData,
-                        ERROR_WRONG_ORDER_OF_KEYS,
-                    ));
-                }
+    impl BorshDeserialize for ::ethnum::I256 {
+        fixed_serialized_size!(32);
+
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let mut buf = [0u8; 32];
+            reader
+                .read_exact(&mut buf)
+                .map_err(unexpected_eof_to_unexpected_length_of_input)?;
+            Ok(::ethnum::I256::from_le_bytes(buf))
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["num-bigint"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// [BigUint](::num_bigint::BigUint) and [BigInt](::num_bigint::BigInt).
+///
+/// Only the canonical encoding is accepted: the magnitude must not have leading
+/// (most significant) zero bytes, and the sign must be `NoSign` if and only if
+/// the magnitude is zero.
+#[cfg(feature = "num-bigint")]
+pub mod num_bigint {
+    use crate::__private::maybestd::{format, vec::Vec};
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::num_bigint::{BigInt, BigUint, Sign};
+
+    const ERROR_LEADING_ZERO_BYTES: &str = "BigUint magnitude has leading zero bytes";
+    const ERROR_SIGN_MISMATCH: &str = "BigInt sign doesn't match its magnitude";
+
+    impl BorshDeserialize for BigUint {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let bytes = Vec::<u8>::deserialize_reader(reader)?;
+            if bytes.last() == Some(&0) {
+                return Err(Error::new(ErrorKind::InvalidData, ERROR_LEADING_ZERO_BYTES));
+            }
+            Ok(BigUint::from_bytes_le(&bytes))
+        }
+    }
+
+    impl BorshDeserialize for Sign {
+        fixed_serialized_size!(1);
+
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let kind = u8::deserialize_reader(reader)?;
+            match kind {
+                0 => Ok(Sign::Minus),
+                1 => Ok(Sign::NoSign),
+                2 => Ok(Sign::Plus),
+                value => Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Invalid Sign variant: {}", value),
+                )),
+            }
+        }
+    }
+
+    impl BorshDeserialize for BigInt {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let sign = Sign::deserialize_reader(reader)?;
+            let magnitude = BigUint::deserialize_reader(reader)?;
+            if (sign == Sign::NoSign) != (magnitude.bits() == 0) {
+                return Err(Error::new(ErrorKind::InvalidData, ERROR_SIGN_MISMATCH));
+            }
+            Ok(BigInt::from_biguint(sign, magnitude))
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["smallvec"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// [SmallVec](::smallvec::SmallVec).
+#[cfg(feature = "smallvec")]
+pub mod smallvec {
+    use super::hint;
+    use crate::error::check_zst;
+    use crate::io::{Read, Result};
+    use crate::BorshDeserialize;
+    use ::smallvec::{Array, SmallVec};
+
+    impl<A> BorshDeserialize for SmallVec<A>
+    where
+        A: Array,
+        A::Item: BorshDeserialize,
+    {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            check_zst::<A::Item>()?;
+
+            let len = u32::deserialize_reader(reader)?;
+            let mut result = SmallVec::with_capacity(hint::cautious::<A::Item>(len));
+            for _ in 0..len {
+                result.push(A::Item::deserialize_reader(reader)?);
+            }
+            Ok(result)
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["arrayvec"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// [ArrayVec](::arrayvec::ArrayVec) and [ArrayString](::arrayvec::ArrayString).
+///
+/// Input with length, which exceeds the capacity, is rejected.
+#[cfg(feature = "arrayvec")]
+pub mod arrayvec {
+    use super::{read_bounded_len, unexpected_eof_to_unexpected_length_of_input};
+    use crate::__private::maybestd::string::ToString;
+    use crate::error::check_zst;
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::arrayvec::{ArrayString, ArrayVec};
+
+    impl<T: BorshDeserialize, const CAP: usize> BorshDeserialize for ArrayVec<T, CAP> {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            check_zst::<T>()?;
+
+            let len = read_bounded_len(reader, CAP)?;
+            let mut result = ArrayVec::new();
+            for _ in 0..len {
+                result.push(T::deserialize_reader(reader)?);
+            }
+            Ok(result)
+        }
+    }
+
+    impl<const CAP: usize> BorshDeserialize for ArrayString<CAP> {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let len = read_bounded_len(reader, CAP)?;
+            let mut buf = [0u8; CAP];
+            reader
+                .read_exact(&mut buf[..len])
+                .map_err(unexpected_eof_to_unexpected_length_of_input)?;
+            let s = core::str::from_utf8(&buf[..len])
+                .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+            Ok(ArrayString::from(s).expect("length is checked to be within capacity"))
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["tinyvec"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// [ArrayVec](::tinyvec::ArrayVec) and [TinyVec](::tinyvec::TinyVec).
+///
+/// Input with length, which exceeds the capacity of `ArrayVec`, is rejected.
+#[cfg(feature = "tinyvec")]
+pub mod tinyvec {
+    use super::{hint, read_bounded_len};
+    use crate::error::check_zst;
+    use crate::io::{Read, Result};
+    use crate::BorshDeserialize;
+    use ::tinyvec::{Array, ArrayVec, TinyVec};
+
+    impl<A> BorshDeserialize for ArrayVec<A>
+    where
+        A: Array,
+        A::Item: BorshDeserialize,
+    {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            check_zst::<A::Item>()?;
+
+            let len = read_bounded_len(reader, A::CAPACITY)?;
+            let mut result = ArrayVec::new();
+            for _ in 0..len {
+                result.push(A::Item::deserialize_reader(reader)?);
+            }
+            Ok(result)
+        }
+    }
+
+    impl<A> BorshDeserialize for TinyVec<A>
+    where
+        A: Array,
+        A::Item: BorshDeserialize,
+    {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            check_zst::<A::Item>()?;
+
+            let len = u32::deserialize_reader(reader)?;
+            let mut result = TinyVec::with_capacity(hint::cautious::<A::Item>(len));
+            for _ in 0..len {
+                result.push(A::Item::deserialize_reader(reader)?);
+            }
+            Ok(result)
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["heapless"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// [Vec](::heapless::Vec) and [String](::heapless::String) from [heapless](::heapless) crate.
+///
+/// Input with length, which exceeds the capacity, is rejected.
+#[cfg(feature = "heapless")]
+pub mod heapless {
+    use super::{read_bounded_len, unexpected_eof_to_unexpected_length_of_input};
+    use crate::__private::maybestd::string::ToString;
+    use crate::error::check_zst;
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::heapless::{String, Vec};
+
+    impl<T: BorshDeserialize, const N: usize> BorshDeserialize for Vec<T, N> {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            check_zst::<T>()?;
+
+            let len = read_bounded_len(reader, N)?;
+            let mut result = Vec::new();
+            for _ in 0..len {
+                if result.push(T::deserialize_reader(reader)?).is_err() {
+                    unreachable!("length is checked to be within capacity");
+                }
+            }
+            Ok(result)
+        }
+    }
+
+    impl<const N: usize> BorshDeserialize for String<N> {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let len = read_bounded_len(reader, N)?;
+            let mut bytes = Vec::<u8, N>::new();
+            bytes
+                .resize_default(len)
+                .expect("length is checked to be within capacity");
+            reader
+                .read_exact(&mut bytes)
+                .map_err(unexpected_eof_to_unexpected_length_of_input)?;
+            String::from_utf8(bytes)
+                .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))
+        }
+    }
+}
+
+/// Reads `u32` length prefix of a collection with fixed `capacity`,
+/// and checks that the length doesn't exceed the capacity.
+#[cfg(any(feature = "arrayvec", feature = "tinyvec", feature = "heapless"))]
+fn read_bounded_len<R: Read>(reader: &mut R, capacity: usize) -> Result<usize> {
+    let len = u32::deserialize_reader(reader)?;
+    match usize::try_from(len) {
+        Ok(len) if len <= capacity => Ok(len),
+        _ => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Length {} exceeds the capacity of the collection: {}",
+                len, capacity
+            ),
+        )),
+    }
+}
+
+/// Module is available if borsh is built with `features = ["serde_json"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// [Value](::serde_json::Value), [Number](::serde_json::Number) and [Map](::serde_json::Map).
+///
+/// Nesting of arrays and objects is limited by [MAX_DEPTH](serde_json::MAX_DEPTH),
+/// deeper input is rejected instead of overflowing the stack.
+/// `NaN` and infinite `f64` numbers are rejected, as they are not valid JSON numbers.
+#[cfg(feature = "serde_json")]
+pub mod serde_json {
+    use super::hint;
+    use crate::__private::maybestd::{format, string::String, vec::Vec};
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::serde_json::{Map, Number, Value};
+
+    #[cfg(feature = "de_strict_order")]
+    use super::ERROR_WRONG_ORDER_OF_KEYS;
+
+    /// Maximum nesting depth of arrays and objects, accepted on deserialization.
+    pub const MAX_DEPTH: usize = 128;
+
+    impl BorshDeserialize for Value {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            deserialize_value(reader, 0)
+        }
+    }
+
+    impl BorshDeserialize for Number {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let tag = u8::deserialize_reader(reader)?;
+            match tag {
+                0 => Ok(u64::deserialize_reader(reader)?.into()),
+                1 => Ok(i64::deserialize_reader(reader)?.into()),
+                2 => {
+                    let f = f64::deserialize_reader(reader)?;
+                    Number::from_f64(f).ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::InvalidData,
+                            format!("Invalid JSON number: {}", f),
+                        )
+                    })
+                }
+                _ => Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Invalid JSON number representation: {}", tag),
+                )),
+            }
+        }
+    }
+
+    impl BorshDeserialize for Map<String, Value> {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            deserialize_map(reader, 0)
+        }
+    }
+
+    /// Checks, that one more level of nesting is allowed at `depth`,
+    /// and returns the depth of the nested values.
+    fn enter(depth: usize) -> Result<usize> {
+        if depth >= MAX_DEPTH {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "JSON value exceeds the maximum nesting depth: {}",
+                    MAX_DEPTH
+                ),
+            ));
+        }
+        Ok(depth + 1)
+    }
+
+    fn deserialize_value<R: Read>(reader: &mut R, depth: usize) -> Result<Value> {
+        let tag = u8::deserialize_reader(reader)?;
+        match tag {
+            0 => Ok(Value::Null),
+            1 => Ok(Value::Bool(bool::deserialize_reader(reader)?)),
+            2 => Ok(Value::Number(Number::deserialize_reader(reader)?)),
+            3 => Ok(Value::String(String::deserialize_reader(reader)?)),
+            4 => Ok(Value::Array(deserialize_array(reader, depth)?)),
+            5 => Ok(Value::Object(deserialize_map(reader, depth)?)),
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid JSON value representation: {}", tag),
+            )),
+        }
+    }
+
+    fn deserialize_array<R: Read>(reader: &mut R, depth: usize) -> Result<Vec<Value>> {
+        let depth = enter(depth)?;
+        let len = u32::deserialize_reader(reader)?;
+        let mut result = Vec::with_capacity(hint::cautious::<Value>(len));
+        for _ in 0..len {
+            result.push(deserialize_value(reader, depth)?);
+        }
+        Ok(result)
+    }
+
+    fn deserialize_map<R: Read>(reader: &mut R, depth: usize) -> Result<Map<String, Value>> {
+        let depth = enter(depth)?;
+        let len = u32::deserialize_reader(reader)?;
+        let mut result = Map::new();
+        for _ in 0..len {
+            let key = String::deserialize_reader(reader)?;
+            #[cfg(feature = "de_strict_order")]
+            if result.keys().next_back().is_some_and(|last| last >= &key) {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    ERROR_WRONG_ORDER_OF_KEYS,
+                ));
+            }
+            let value = deserialize_value(reader, depth)?;
+            result.insert(key, value);
+        }
+        Ok(result)
+    }
+}
+
+/// Module is available if borsh is built with `features = ["rust_decimal"]`.
+///
+/// Module defines [BorshDeserialize] implementation for [Decimal](::rust_decimal::Decimal).
+///
+/// Input with unused bits of `flags` set or with scale above 28 is rejected, as `Decimal` can't
+/// represent it. Values, which differ only in scale, e.g. `1.0` and `1.00`, or in the sign of zero,
+/// are distinct, and so are their encodings.
+#[cfg(feature = "rust_decimal")]
+pub mod rust_decimal {
+    use super::unexpected_eof_to_unexpected_length_of_input;
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::rust_decimal::Decimal;
+
+    /// sign bit and scale bits of `flags`
+    const FLAGS_MASK: u32 = 0x801F_0000;
+    const MAX_SCALE: u32 = 28;
+
+    impl BorshDeserialize for Decimal {
+        fixed_serialized_size!(16);
+
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let mut bytes = [0u8; 16];
+            reader
+                .read_exact(&mut bytes)
+                .map_err(unexpected_eof_to_unexpected_length_of_input)?;
+            let flags = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            let scale = (flags >> 16) & 0xFF;
+            if flags & !FLAGS_MASK != 0 || scale > MAX_SCALE {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "Invalid Decimal representation",
+                ));
+            }
+            Ok(Decimal::deserialize(bytes))
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["ordered-float"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// [OrderedFloat](::ordered_float::OrderedFloat) and [NotNan](::ordered_float::NotNan).
+///
+/// NaNs are rejected the same way as for `f32`/`f64`.
+#[cfg(feature = "ordered-float")]
+pub mod ordered_float {
+    use super::ERROR_FLOAT_NAN;
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::ordered_float::{FloatCore, NotNan, OrderedFloat};
+
+    impl<T: BorshDeserialize> BorshDeserialize for OrderedFloat<T> {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            T::deserialize_reader(reader).map(OrderedFloat)
+        }
+
+        #[inline]
+        #[doc(hidden)]
+        fn fixed_serialized_size() -> Option<usize> {
+            T::fixed_serialized_size()
+        }
+    }
+
+    impl<T: FloatCore + BorshDeserialize> BorshDeserialize for NotNan<T> {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            NotNan::new(T::deserialize_reader(reader)?)
+                .map_err(|_| Error::new(ErrorKind::InvalidData, ERROR_FLOAT_NAN))
+        }
+
+        #[inline]
+        #[doc(hidden)]
+        fn fixed_serialized_size() -> Option<usize> {
+            T::fixed_serialized_size()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["half"]`.
+///
+/// Module defines [BorshDeserialize] implementation for [f16](::half::f16) and [bf16](::half::bf16).
+///
+/// NaNs are rejected the same way as for `f32`/`f64`.
+#[cfg(feature = "half")]
+pub mod half {
+    use super::{unexpected_eof_to_unexpected_length_of_input, ERROR_FLOAT_NAN};
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+
+    macro_rules! impl_for_half_float {
+        ($type: ident) => {
+            impl BorshDeserialize for ::half::$type {
+                fixed_serialized_size!(2);
+
+                #[inline]
+                fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                    let mut buf = [0u8; 2];
+                    reader
+                        .read_exact(&mut buf)
+                        .map_err(unexpected_eof_to_unexpected_length_of_input)?;
+                    let res = ::half::$type::from_bits(u16::from_le_bytes(buf));
+                    if res.is_nan() {
+                        return Err(Error::new(ErrorKind::InvalidData, ERROR_FLOAT_NAN));
+                    }
+                    Ok(res)
+                }
             }
+        };
+    }
+
+    impl_for_half_float!(f16);
+    impl_for_half_float!(bf16);
+}
+
+/// Module is available if borsh is built with `features = ["bitflags"]`.
+///
+/// Module defines [deserialize](bitflags::deserialize) and [deserialize_strict](bitflags::deserialize_strict)
+/// functions for any [Flags](::bitflags::Flags) type, serialized as its underlying integer.
+/// They are meant to be used with `#[borsh(deserialize_with = "...")]` field attribute,
+/// as [BorshDeserialize] cannot be implemented for all `Flags` types at once.
+#[cfg(feature = "bitflags")]
+pub mod bitflags {
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::bitflags::Flags;
+
+    /// Deserializes flags from the underlying integer, retaining any bits,
+    /// which don't correspond to a defined flag.
+    pub fn deserialize<R, F>(reader: &mut R) -> Result<F>
+    where
+        R: Read,
+        F: Flags,
+        F::Bits: BorshDeserialize,
+    {
+        F::Bits::deserialize_reader(reader).map(F::from_bits_retain)
+    }
+
+    /// Deserializes flags from the underlying integer, rejecting any bits,
+    /// which don't correspond to a defined flag.
+    pub fn deserialize_strict<R, F>(reader: &mut R) -> Result<F>
+    where
+        R: Read,
+        F: Flags,
+        F::Bits: BorshDeserialize,
+    {
+        F::from_bits(F::Bits::deserialize_reader(reader)?)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "Flags contain unknown bits"))
+    }
+}
+
+/// Module is available if borsh is built with `features = ["bitvec"]`.
+///
+/// Module defines [BorshDeserialize] implementation for [BitVec](::bitvec::vec::BitVec).
+///
+/// Input with non-zero unused bits of the last byte is rejected, as these bits are always zero
+/// in serialized output.
+#[cfg(feature = "bitvec")]
+pub mod bitvec {
+    use core::convert::TryFrom;
+
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::bitvec::{order::BitOrder, store::BitStore, vec::BitVec};
+
+    impl<T: BitStore, O: BitOrder> BorshDeserialize for BitVec<T, O> {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let len = u32::deserialize_reader(reader)?;
+            let bytes = u8::vec_from_reader(len.div_ceil(8), reader)?
+                .expect("`u8` always reads a vector of bytes");
+            let len = usize::try_from(len).map_err(|_| ErrorKind::InvalidData)?;
+            let unused = bytes.len() * 8 - len;
+            if unused > 0 && bytes[bytes.len() - 1] >> (8 - unused) != 0 {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "Unused bits of BitVec must be zero",
+                ));
+            }
+            let mut result = BitVec::with_capacity(len);
+            result.extend((0..len).map(|i| (bytes[i / 8] >> (i % 8)) & 1 == 1));
+            Ok(result)
+        }
+    }
+}
+
+/// Checks, that keys of deserialized `entries` are in strictly ascending order.
+#[cfg(all(feature = "de_strict_order", any(feature = "im", feature = "rpds")))]
+fn check_order_of_keys<K: Ord, V>(entries: &[(K, V)]) -> Result<()> {
+    for pair in entries.windows(2) {
+        let [(a_k, _a_v), (b_k, _b_v)] = pair else {
+            unreachable!("`windows` always return a slice of length 2 or nothing");
+        };
+        if !a_k.cmp(b_k).is_lt() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                ERROR_WRONG_ORDER_OF_KEYS,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Module is available if borsh is built with `features = ["im"]`.
+///
+/// Module defines [BorshDeserialize] implementation for [HashMap](::im::HashMap),
+/// [OrdMap](::im::OrdMap) and [Vector](::im::Vector) from [im](::im) crate.
+#[cfg(feature = "im")]
+pub mod im {
+    use core::hash::{BuildHasher, Hash};
+
+    use crate::__private::maybestd::vec::Vec;
+    use crate::error::check_zst;
+    use crate::io::{Read, Result};
+    use crate::BorshDeserialize;
+    use ::im::{HashMap, OrdMap, Vector};
+
+    #[cfg(feature = "de_strict_order")]
+    use super::check_order_of_keys;
+
+    impl<K, V, S> BorshDeserialize for HashMap<K, V, S>
+    where
+        K: BorshDeserialize + Eq + Hash + Ord + Clone,
+        V: BorshDeserialize + Clone,
+        S: BuildHasher + Default,
+    {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            check_zst::<K>()?;
+            let vec = <Vec<(K, V)>>::deserialize_reader(reader)?;
+            #[cfg(feature = "de_strict_order")]
+            check_order_of_keys(&vec)?;
+            Ok(vec.into_iter().collect())
+        }
+    }
 
-            Ok(vec.into_iter().collect::<HashSet<T, H>>())
+    impl<K, V> BorshDeserialize for OrdMap<K, V>
+    where
+        K: BorshDeserialize + Ord + Clone,
+        V: BorshDeserialize + Clone,
+    {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            check_zst::<K>()?;
+            let vec = <Vec<(K, V)>>::deserialize_reader(reader)?;
+            #[cfg(feature = "de_strict_order")]
+            check_order_of_keys(&vec)?;
+            Ok(vec.into_iter().collect())
         }
     }
 
-    impl<K, V, H> BorshDeserialize for HashMap<K, V, H>
+    impl<T> BorshDeserialize for Vector<T>
+    where
+        T: BorshDeserialize + Clone,
+    {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            <Vec<T>>::deserialize_reader(reader).map(Vector::from)
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["rpds"]`.
+///
+/// Module defines [BorshDeserialize] implementation for [HashTrieMap](::rpds::HashTrieMap),
+/// [RedBlackTreeMap](::rpds::RedBlackTreeMap) and [Vector](::rpds::Vector) from [rpds](::rpds) crate.
+#[cfg(feature = "rpds")]
+pub mod rpds {
+    use core::hash::{BuildHasher, Hash};
+
+    use crate::__private::maybestd::vec::Vec;
+    use crate::error::check_zst;
+    use crate::io::{Read, Result};
+    use crate::BorshDeserialize;
+    use ::archery::SharedPointerKind;
+    use ::rpds::{HashTrieMap, RedBlackTreeMap, Vector};
+
+    #[cfg(feature = "de_strict_order")]
+    use super::check_order_of_keys;
+
+    impl<K, V, P, H> BorshDeserialize for HashTrieMap<K, V, P, H>
     where
         K: BorshDeserialize + Eq + Hash + Ord,
         V: BorshDeserialize,
-        H: BuildHasher + Default,
+        P: SharedPointerKind,
+        H: BuildHasher + Clone + Default,
+    {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            check_zst::<K>()?;
+            let vec = <Vec<(K, V)>>::deserialize_reader(reader)?;
+            #[cfg(feature = "de_strict_order")]
+            check_order_of_keys(&vec)?;
+            Ok(vec.into_iter().collect())
+        }
+    }
+
+    impl<K, V, P> BorshDeserialize for RedBlackTreeMap<K, V, P>
+    where
+        K: BorshDeserialize + Ord,
+        V: BorshDeserialize,
+        P: SharedPointerKind,
     {
         #[inline]
         fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
             check_zst::<K>()?;
-            // NOTE: deserialize-as-you-go approach as once was in HashSet is better in the sense
-            // that it allows to fail early, and not allocate memory for all the entries
-            // which may fail `cmp()` checks
-            // NOTE: deserialize first to `Vec<(K, V)>` is faster
             let vec = <Vec<(K, V)>>::deserialize_reader(reader)?;
+            #[cfg(feature = "de_strict_order")]
+            check_order_of_keys(&vec)?;
+            Ok(vec.into_iter().collect())
+        }
+    }
+
+    impl<T, P> BorshDeserialize for Vector<T, P>
+    where
+        T: BorshDeserialize,
+        P: SharedPointerKind,
+    {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let vec = <Vec<T>>::deserialize_reader(reader)?;
+            Ok(vec.into_iter().collect())
+        }
+    }
+}
+
+/// Reads a value, serialized the same way as `String`.
+///
+/// Strings of at most `N` bytes are read into a stack buffer and passed to `inline`,
+/// so that compact string types can store them without a heap allocation.
+/// Reading of longer strings is left to `heap`, which is passed their length.
+#[cfg(any(feature = "smol_str", feature = "compact_str"))]
+fn read_compact_str<R: Read, T, const N: usize>(
+    reader: &mut R,
+    inline: impl FnOnce(&str) -> T,
+    heap: impl FnOnce(u32, &mut R) -> Result<T>,
+) -> Result<T> {
+    let len = u32::deserialize_reader(reader)?;
+    match usize::try_from(len) {
+        Ok(len) if len <= N => {
+            let mut buf = [0u8; N];
+            let buf = &mut buf[..len];
+            reader
+                .read_exact(buf)
+                .map_err(unexpected_eof_to_unexpected_length_of_input)?;
+            let text = core::str::from_utf8(buf)
+                .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+            Ok(inline(text))
+        }
+        _ => heap(len, reader),
+    }
+}
+
+/// Reads a `String` of `len` bytes, which follow its length prefix.
+#[cfg(any(feature = "smol_str", feature = "compact_str"))]
+fn read_string_of_len<R: Read>(len: u32, reader: &mut R) -> Result<String> {
+    let Some(bytes) = u8::vec_from_reader(len, reader)? else {
+        unreachable!("`u8` always reads bytes into a `Vec`");
+    };
+    String::from_utf8(bytes).map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))
+}
+
+/// Module is available if borsh is built with `features = ["smol_str"]`.
+///
+/// Module defines [BorshDeserialize] implementation for [SmolStr](::smol_str::SmolStr).
+/// Strings, short enough to be stored inline, are decoded without a heap allocation,
+/// longer ones are read straight into the memory of the shared string.
+#[cfg(feature = "smol_str")]
+pub mod smol_str {
+    use core::convert::TryFrom;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::sync::Arc;
+    #[cfg(feature = "std")]
+    use std::sync::Arc;
+
+    use crate::__private::maybestd::string::ToString;
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::smol_str::SmolStr;
+
+    use super::{
+        read_compact_str, read_string_of_len, unexpected_eof_to_unexpected_length_of_input,
+    };
+
+    /// Maximal length of a string, which [SmolStr] stores inline.
+    const INLINE_CAP: usize = 23;
+
+    /// Maximal length of a string, which is allocated before its bytes are read.
+    ///
+    /// This protects from forcing a large allocation with a forged length prefix.
+    const MAX_PREALLOC: usize = 1024 * 1024;
+
+    /// Reads a string of `len` bytes into an `Arc<str>`, allocated only once.
+    ///
+    /// Strings longer than [MAX_PREALLOC] are read into a `String` first.
+    fn read_arc_str<R: Read>(len: u32, reader: &mut R) -> Result<Arc<str>> {
+        let len = match usize::try_from(len) {
+            Ok(len) if len <= MAX_PREALLOC => len,
+            _ => return read_string_of_len(len, reader).map(Arc::from),
+        };
+        let mut bytes: Arc<[u8]> = core::iter::repeat(0).take(len).collect();
+        let buf = Arc::get_mut(&mut bytes).expect("a new `Arc` is not shared");
+        reader
+            .read_exact(buf)
+            .map_err(unexpected_eof_to_unexpected_length_of_input)?;
+        core::str::from_utf8(&bytes)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+        // SAFETY: the bytes are checked to be valid UTF-8, and `str` has the same layout as `[u8]`.
+        Ok(unsafe { Arc::from_raw(Arc::into_raw(bytes) as *const str) })
+    }
+
+    impl BorshDeserialize for SmolStr {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            read_compact_str::<_, _, INLINE_CAP>(
+                reader,
+                |text| SmolStr::new(text),
+                |len, reader| read_arc_str(len, reader).map(SmolStr::from),
+            )
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["compact_str"]`.
+///
+/// Module defines [BorshDeserialize] implementation for [CompactString](::compact_str::CompactString).
+/// Strings, short enough to be stored inline, are decoded without a heap allocation,
+/// longer ones reuse the allocation of the decoded `String`.
+#[cfg(feature = "compact_str")]
+pub mod compact_str {
+    use crate::io::{Read, Result};
+    use crate::BorshDeserialize;
+    use ::compact_str::CompactString;
+
+    use super::{read_compact_str, read_string_of_len};
+
+    /// Maximal length of a string, which [CompactString] stores inline.
+    const INLINE_CAP: usize = core::mem::size_of::<CompactString>();
+
+    impl BorshDeserialize for CompactString {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            read_compact_str::<_, _, INLINE_CAP>(
+                reader,
+                |text| CompactString::new(text),
+                |len, reader| read_string_of_len(len, reader).map(CompactString::from),
+            )
+        }
+    }
+}
+
+impl<T> BorshDeserialize for Cow<'_, T>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: BorshDeserialize,
+{
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Ok(Cow::Owned(BorshDeserialize::deserialize_reader(reader)?))
+    }
+}
+
+impl<T> BorshDeserialize for VecDeque<T>
+where
+    T: BorshDeserialize,
+{
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let vec = <Vec<T>>::deserialize_reader(reader)?;
+        Ok(vec.into())
+    }
+}
+
+impl<T> BorshDeserialize for LinkedList<T>
+where
+    T: BorshDeserialize,
+{
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let vec = <Vec<T>>::deserialize_reader(reader)?;
+        Ok(vec.into_iter().collect::<LinkedList<T>>())
+    }
+}
+
+/// Defines [BorshDeserialize] implementation for `HashMap`/`HashSet` types, which are
+/// expected to be in scope of the invoking module under the given names.
+#[cfg(hash_collections)]
+macro_rules! impl_for_hash_collections {
+    ($map: ident, $set: ident) => {
+        use core::hash::{BuildHasher, Hash};
+
+        use crate::__private::maybestd::vec::Vec;
+        use crate::io::{Read, Result};
+        use crate::BorshDeserialize;
+
+        #[cfg(feature = "de_strict_order")]
+        use super::ERROR_WRONG_ORDER_OF_KEYS;
+        use crate::error::check_zst;
+        #[cfg(feature = "de_strict_order")]
+        use crate::io::{Error, ErrorKind};
+
+        impl<T, H> BorshDeserialize for $set<T, H>
+        where
+            T: BorshDeserialize + Eq + Hash + Ord,
+            H: BuildHasher + Default,
+        {
+            #[inline]
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                // NOTE: deserialize-as-you-go approach as once was in HashSet is better in the sense
+                // that it allows to fail early, and not allocate memory for all the elements
+                // which may fail `cmp()` checks
+                // NOTE: deserialize first to `Vec<T>` is faster
+                let vec = <Vec<T>>::deserialize_reader(reader)?;
+
+                #[cfg(feature = "de_strict_order")]
+                // TODO: replace with `is_sorted` api when stabilizes https://github.com/rust-lang/rust/issues/53485
+                // TODO: first replace with `array_windows` api when stabilizes https://github.com/rust-lang/rust/issues/75027
+                for pair in vec.windows(2) {
+                    let [a, b] = pair else {
+                        unreachable!("`windows` always return a slice of length 2 or nothing");
+                    };
+                    let cmp_result = a.cmp(b).is_lt();
+                    if !cmp_result {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            ERROR_WRONG_ORDER_OF_KEYS,
+                        ));
+                    }
+                }
+
+                Ok(vec.into_iter().collect::<$set<T, H>>())
+            }
+        }
+
+        impl<K, V, H> BorshDeserialize for $map<K, V, H>
+        where
+            K: BorshDeserialize + Eq + Hash + Ord,
+            V: BorshDeserialize,
+            H: BuildHasher + Default,
+        {
+            #[inline]
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                check_zst::<K>()?;
+                // NOTE: deserialize-as-you-go approach as once was in HashSet is better in the sense
+                // that it allows to fail early, and not allocate memory for all the entries
+                // which may fail `cmp()` checks
+                // NOTE: deserialize first to `Vec<(K, V)>` is faster
+                let vec = <Vec<(K, V)>>::deserialize_reader(reader)?;
+
+                #[cfg(feature = "de_strict_order")]
+                // TODO: replace with `is_sorted` api when stabilizes https://github.com/rust-lang/rust/issues/53485
+                // TODO: first replace with `array_windows` api when stabilizes https://github.com/rust-lang/rust/issues/75027
+                for pair in vec.windows(2) {
+                    let [(a_k, _a_v), (b_k, _b_v)] = pair else {
+                        unreachable!("`windows` always return a slice of length 2 or nothing");
+                    };
+                    let cmp_result = a_k.cmp(b_k).is_lt();
+                    if !cmp_result {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            ERROR_WRONG_ORDER_OF_KEYS,
+                        ));
+                    }
+                }
+
+                Ok(vec.into_iter().collect::<$map<K, V, H>>())
+            }
+        }
+    };
+}
+
+/// Module is available if borsh is built with `features = ["std"]` or `features = ["hashbrown"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// [HashMap](std::collections::HashMap)/[HashSet](std::collections::HashSet).
+#[cfg(hash_collections)]
+pub mod hashes {
+    use crate::__private::maybestd::collections::{HashMap, HashSet};
+
+    impl_for_hash_collections!(HashMap, HashSet);
+}
+
+/// Module is available if borsh is built with `features = ["std", "hashbrown"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// [HashMap](::hashbrown::HashMap)/[HashSet](::hashbrown::HashSet) from [hashbrown](::hashbrown) crate.
+/// Without `std` these are the types, covered by [hashes] module.
+#[cfg(all(feature = "std", feature = "hashbrown"))]
+pub mod hashbrown {
+    use ::hashbrown::{HashMap, HashSet};
+
+    impl_for_hash_collections!(HashMap, HashSet);
+}
 
-            #[cfg(feature = "de_strict_order")]
-            // TODO: replace with `is_sorted` api when stabilizes https://github.com/rust-lang/rust/issues/53485
-            // TODO: first replace with `array_windows` api when stabilizes https://github.com/rust-lang/rust/issues/75027
-            for pair in vec.windows(2) {
-                let [(a_k, _a_v), (b_k, _b_v)] = pair else {
-                    unreachable!("`windows` always return a slice of length 2 or nothing");
-                };
-                let cmp_result = a_k.cmp(b_k).is_lt();
-                if !cmp_result {
-                    return Err(Error::new(
-                        ErrorKind::InvalidData,
-                        ERROR_WRONG_ORDER_OF_KEYS,
-                    ));
-                }
-            }
+impl<T> BorshDeserialize for BinaryHeap<T>
+where
+    T: BorshDeserialize + Ord,
+{
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let vec = <Vec<T>>::deserialize_reader(reader)?;
 
-            Ok(vec.into_iter().collect::<HashMap<K, V, H>>())
+        #[cfg(feature = "de_strict_order")]
+        // NOTE: unlike sets, heaps may contain equal elements, so the order isn't strict
+        for pair in vec.windows(2) {
+            let [a, b] = pair else {
+                unreachable!("`windows` always return a slice of length 2 or nothing");
+            };
+            if a.cmp(b).is_gt() {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    ERROR_WRONG_ORDER_OF_KEYS,
+                ));
+            }
         }
+        Ok(BinaryHeap::from(vec))
     }
 }
 
@@ -724,6 +2086,8 @@ impl BorshDeserialize for core::net::IpAddr {
 }
 
 impl BorshDeserialize for core::net::SocketAddrV4 {
+    fixed_serialized_size!(4 + 2);
+
     #[inline]
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
         let ip = core::net::Ipv4Addr::deserialize_reader(reader)?;
@@ -733,6 +2097,8 @@ impl BorshDeserialize for core::net::SocketAddrV4 {
 }
 
 impl BorshDeserialize for core::net::SocketAddrV6 {
+    fixed_serialized_size!(16 + 2);
+
     #[inline]
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
         let ip = core::net::Ipv6Addr::deserialize_reader(reader)?;
@@ -742,6 +2108,8 @@ impl BorshDeserialize for core::net::SocketAddrV6 {
 }
 
 impl BorshDeserialize for core::net::Ipv4Addr {
+    fixed_serialized_size!(4);
+
     #[inline]
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
         let mut buf = [0u8; 4];
@@ -753,6 +2121,8 @@ impl BorshDeserialize for core::net::Ipv4Addr {
 }
 
 impl BorshDeserialize for core::net::Ipv6Addr {
+    fixed_serialized_size!(16);
+
     #[inline]
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
         let mut buf = [0u8; 16];
@@ -772,6 +2142,73 @@ where
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
         Ok(T::Owned::deserialize_reader(reader)?.into())
     }
+
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+        Ok(T::Owned::deserialize_from(reader)?.into())
+    }
+
+    #[inline]
+    fn skip<B: BorshRead>(reader: &mut B) -> Result<()> {
+        T::Owned::skip(reader)
+    }
+
+    #[inline]
+    #[doc(hidden)]
+    fn fixed_serialized_size() -> Option<usize> {
+        T::Owned::fixed_serialized_size()
+    }
+}
+
+struct ArrayDropGuard<T, const N: usize> {
+    buffer: [MaybeUninit<T>; N],
+    init_count: usize,
+}
+
+impl<T, const N: usize> Drop for ArrayDropGuard<T, N> {
+    fn drop(&mut self) {
+        let init_range = &mut self.buffer[..self.init_count];
+        // SAFETY: Elements up to self.init_count have been initialized. Assumes this value
+        //         is only incremented in `fill_buffer`, which writes the element before
+        //         increasing the init_count.
+        unsafe {
+            core::ptr::drop_in_place(init_range as *mut _ as *mut [T]);
+        };
+    }
+}
+
+impl<T, const N: usize> ArrayDropGuard<T, N> {
+    unsafe fn transmute_to_array(mut self) -> [T; N] {
+        debug_assert_eq!(self.init_count, N);
+        // Set init_count to 0 so that the values do not get dropped twice.
+        self.init_count = 0;
+        // SAFETY: This cast is required because `mem::transmute` does not work with
+        //         const generics https://github.com/rust-lang/rust/issues/61956. This
+        //         array is guaranteed to be initialized by this point.
+        core::ptr::read(&self.buffer as *const _ as *const [T; N])
+    }
+    fn fill_buffer(&mut self, mut f: impl FnMut() -> Result<T>) -> Result<()> {
+        // TODO: replace with `core::array::try_from_fn` when stabilized to avoid manually
+        // dropping uninitialized values through the guard drop.
+        for elem in self.buffer.iter_mut() {
+            elem.write(f()?);
+            self.init_count += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Builds an array from elements, returned by `f`, dropping already built ones on error.
+#[inline]
+fn array_try_from_fn<T, const N: usize>(f: impl FnMut() -> Result<T>) -> Result<[T; N]> {
+    let mut result = ArrayDropGuard {
+        buffer: unsafe { MaybeUninit::uninit().assume_init() },
+        init_count: 0,
+    };
+
+    result.fill_buffer(f)?;
+
+    // SAFETY: The elements up to `i` have been initialized in `fill_buffer`.
+    Ok(unsafe { result.transmute_to_array() })
 }
 
 impl<T, const N: usize> BorshDeserialize for [T; N]
@@ -780,55 +2217,31 @@ where
 {
     #[inline]
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
-        struct ArrayDropGuard<T, const N: usize> {
-            buffer: [MaybeUninit<T>; N],
-            init_count: usize,
-        }
-        impl<T, const N: usize> Drop for ArrayDropGuard<T, N> {
-            fn drop(&mut self) {
-                let init_range = &mut self.buffer[..self.init_count];
-                // SAFETY: Elements up to self.init_count have been initialized. Assumes this value
-                //         is only incremented in `fill_buffer`, which writes the element before
-                //         increasing the init_count.
-                unsafe {
-                    core::ptr::drop_in_place(init_range as *mut _ as *mut [T]);
-                };
-            }
-        }
-        impl<T, const N: usize> ArrayDropGuard<T, N> {
-            unsafe fn transmute_to_array(mut self) -> [T; N] {
-                debug_assert_eq!(self.init_count, N);
-                // Set init_count to 0 so that the values do not get dropped twice.
-                self.init_count = 0;
-                // SAFETY: This cast is required because `mem::transmute` does not work with
-                //         const generics https://github.com/rust-lang/rust/issues/61956. This
-                //         array is guaranteed to be initialized by this point.
-                core::ptr::read(&self.buffer as *const _ as *const [T; N])
-            }
-            fn fill_buffer(&mut self, mut f: impl FnMut() -> Result<T>) -> Result<()> {
-                // TODO: replace with `core::array::try_from_fn` when stabilized to avoid manually
-                // dropping uninitialized values through the guard drop.
-                for elem in self.buffer.iter_mut() {
-                    elem.write(f()?);
-                    self.init_count += 1;
-                }
-                Ok(())
-            }
+        if let Some(arr) = T::array_from_reader(reader)? {
+            Ok(arr)
+        } else {
+            array_try_from_fn(|| T::deserialize_reader(reader))
         }
+    }
 
+    #[inline]
+    fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
         if let Some(arr) = T::array_from_reader(reader)? {
             Ok(arr)
         } else {
-            let mut result = ArrayDropGuard {
-                buffer: unsafe { MaybeUninit::uninit().assume_init() },
-                init_count: 0,
-            };
+            array_try_from_fn(|| T::deserialize_from(reader))
+        }
+    }
 
-            result.fill_buffer(|| T::deserialize_reader(reader))?;
+    #[inline]
+    fn skip<B: BorshRead>(reader: &mut B) -> Result<()> {
+        skip_items::<T, _>(N, reader)
+    }
 
-            // SAFETY: The elements up to `i` have been initialized in `fill_buffer`.
-            Ok(unsafe { result.transmute_to_array() })
-        }
+    #[inline]
+    #[doc(hidden)]
+    fn fixed_serialized_size() -> Option<usize> {
+        T::fixed_serialized_size()?.checked_mul(N)
     }
 }
 
@@ -880,6 +2293,8 @@ fn array_deserialization_doesnt_leak() {
 macro_rules! impl_tuple {
     (@unit $name:ty) => {
         impl BorshDeserialize for $name {
+            fixed_serialized_size!(0);
+
             #[inline]
             fn deserialize_reader<R: Read>(_reader: &mut R) -> Result<Self> {
                 Ok(<$name>::default())
@@ -896,6 +2311,23 @@ macro_rules! impl_tuple {
 
             Ok(($($name::deserialize_reader(reader)?,)+))
         }
+
+        #[inline]
+        fn deserialize_from<B: BorshRead>(reader: &mut B) -> Result<Self> {
+            Ok(($($name::deserialize_from(reader)?,)+))
+        }
+
+        #[inline]
+        fn skip<B: BorshRead>(reader: &mut B) -> Result<()> {
+            $($name::skip(reader)?;)+
+            Ok(())
+        }
+
+        #[inline]
+        #[doc(hidden)]
+        fn fixed_serialized_size() -> Option<usize> {
+            Some(0 $(+ $name::fixed_serialized_size()?)+)
+        }
       }
     };
 }
@@ -982,6 +2414,8 @@ pub mod rc {
 }
 
 impl<T: ?Sized> BorshDeserialize for PhantomData<T> {
+    fixed_serialized_size!(0);
+
     fn deserialize_reader<R: Read>(_: &mut R) -> Result<Self> {
         Ok(PhantomData)
     }
@@ -994,6 +2428,12 @@ where
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
         <T as BorshDeserialize>::deserialize_reader(reader).map(core::cell::Cell::new)
     }
+
+    #[inline]
+    #[doc(hidden)]
+    fn fixed_serialized_size() -> Option<usize> {
+        T::fixed_serialized_size()
+    }
 }
 
 impl<T> BorshDeserialize for core::cell::RefCell<T>
@@ -1005,6 +2445,370 @@ where
     }
 }
 
+impl BorshDeserialize for core::time::Duration {
+    fixed_serialized_size!(8 + 4);
+
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let secs = u64::deserialize_reader(reader)?;
+        let nanos = u32::deserialize_reader(reader)?;
+        if nanos >= 1_000_000_000 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                ERROR_INVALID_NANOSECONDS,
+            ));
+        }
+        Ok(core::time::Duration::new(secs, nanos))
+    }
+}
+
+#[cfg(feature = "std")]
+impl BorshDeserialize for std::time::SystemTime {
+    fixed_serialized_size!(8 + 4);
+
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let secs = i64::deserialize_reader(reader)?;
+        let nanos = u32::deserialize_reader(reader)?;
+        if nanos >= 1_000_000_000 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                ERROR_INVALID_NANOSECONDS,
+            ));
+        }
+        let time = if secs >= 0 {
+            std::time::UNIX_EPOCH.checked_add(core::time::Duration::new(secs as u64, nanos))
+        } else {
+            std::time::UNIX_EPOCH
+                .checked_sub(core::time::Duration::from_secs(secs.unsigned_abs()))
+                .and_then(|time| time.checked_add(core::time::Duration::from_nanos(nanos.into())))
+        };
+        time.ok_or_else(|| Error::new(ErrorKind::InvalidData, ERROR_SYSTEM_TIME_OUT_OF_RANGE))
+    }
+}
+
+macro_rules! impl_for_transparent_wrapper {
+    ($($module:ident::$type:ident),+) => {
+    $(
+        impl<T: BorshDeserialize> BorshDeserialize for core::$module::$type<T> {
+            #[inline]
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                T::deserialize_reader(reader).map(core::$module::$type)
+            }
+
+            #[inline]
+            #[doc(hidden)]
+            fn fixed_serialized_size() -> Option<usize> {
+                T::fixed_serialized_size()
+            }
+        }
+    )+
+    };
+}
+
+impl_for_transparent_wrapper!(num::Wrapping, num::Saturating, cmp::Reverse);
+
+impl BorshDeserialize for CString {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        CString::new(Vec::<u8>::deserialize_reader(reader)?).map_err(|err| {
+            let msg = err.to_string();
+            Error::new(ErrorKind::InvalidData, msg)
+        })
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for core::ops::Bound<T> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let kind = u8::deserialize_reader(reader)?;
+        match kind {
+            0 => T::deserialize_reader(reader).map(core::ops::Bound::Included),
+            1 => T::deserialize_reader(reader).map(core::ops::Bound::Excluded),
+            2 => Ok(core::ops::Bound::Unbounded),
+            value => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid Bound variant: {}", value),
+            )),
+        }
+    }
+}
+
+impl BorshDeserialize for core::cmp::Ordering {
+    fixed_serialized_size!(1);
+
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let kind = u8::deserialize_reader(reader)?;
+        match kind {
+            0 => Ok(core::cmp::Ordering::Less),
+            1 => Ok(core::cmp::Ordering::Equal),
+            2 => Ok(core::cmp::Ordering::Greater),
+            value => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid Ordering variant: {}", value),
+            )),
+        }
+    }
+}
+
+macro_rules! impl_for_atomic {
+    ($($type:ident($inner:ty) : $width:literal),+) => {
+    $(
+        #[cfg(target_has_atomic = $width)]
+        impl BorshDeserialize for core::sync::atomic::$type {
+            #[inline]
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                <$inner>::deserialize_reader(reader).map(core::sync::atomic::$type::new)
+            }
+        }
+    )+
+    };
+}
+
+impl_for_atomic!(
+    AtomicBool(bool): "8",
+    AtomicI8(i8): "8",
+    AtomicI16(i16): "16",
+    AtomicI32(i32): "32",
+    AtomicI64(i64): "64",
+    AtomicIsize(isize): "ptr",
+    AtomicU8(u8): "8",
+    AtomicU16(u16): "16",
+    AtomicU32(u32): "32",
+    AtomicU64(u64): "64",
+    AtomicUsize(usize): "ptr"
+);
+
+/// Module is available if borsh is built with `features = ["chrono"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// some types from [chrono](::chrono) crate.
+#[cfg(feature = "chrono")]
+pub mod chrono {
+    use super::{ERROR_DATE_OUT_OF_RANGE, ERROR_TIMESTAMP_OUT_OF_RANGE};
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+    /// number of days from 0001-01-01 to 1970-01-01
+    const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+    impl BorshDeserialize for DateTime<Utc> {
+        fixed_serialized_size!(8 + 4);
+
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let secs = i64::deserialize_reader(reader)?;
+            let nanos = u32::deserialize_reader(reader)?;
+            DateTime::from_timestamp(secs, nanos)
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, ERROR_TIMESTAMP_OUT_OF_RANGE))
+        }
+    }
+
+    impl BorshDeserialize for NaiveDateTime {
+        fixed_serialized_size!(8 + 4);
+
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            DateTime::<Utc>::deserialize_reader(reader).map(|datetime| datetime.naive_utc())
+        }
+    }
+
+    impl BorshDeserialize for NaiveDate {
+        fixed_serialized_size!(4);
+
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let days = i32::deserialize_reader(reader)?;
+            days.checked_add(UNIX_EPOCH_DAYS_FROM_CE)
+                .and_then(NaiveDate::from_num_days_from_ce_opt)
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, ERROR_DATE_OUT_OF_RANGE))
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["time"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// some types from [time](::time) crate.
+///
+/// [OffsetDateTime](::time::OffsetDateTime) is deserialized with UTC offset.
+#[cfg(feature = "time")]
+pub mod time {
+    use super::{ERROR_DATE_OUT_OF_RANGE, ERROR_INVALID_NANOSECONDS, ERROR_TIMESTAMP_OUT_OF_RANGE};
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+    /// julian day of 1970-01-01
+    const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+    impl BorshDeserialize for OffsetDateTime {
+        fixed_serialized_size!(8 + 4);
+
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let secs = i64::deserialize_reader(reader)?;
+            let nanos = u32::deserialize_reader(reader)?;
+            if nanos >= 1_000_000_000 {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    ERROR_INVALID_NANOSECONDS,
+                ));
+            }
+            OffsetDateTime::from_unix_timestamp(secs)
+                .and_then(|datetime| datetime.replace_nanosecond(nanos))
+                .map_err(|_| Error::new(ErrorKind::InvalidData, ERROR_TIMESTAMP_OUT_OF_RANGE))
+        }
+    }
+
+    impl BorshDeserialize for PrimitiveDateTime {
+        fixed_serialized_size!(8 + 4);
+
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let datetime = OffsetDateTime::deserialize_reader(reader)?;
+            Ok(PrimitiveDateTime::new(datetime.date(), datetime.time()))
+        }
+    }
+
+    impl BorshDeserialize for Date {
+        fixed_serialized_size!(4);
+
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let days = i32::deserialize_reader(reader)?;
+            days.checked_add(UNIX_EPOCH_JULIAN_DAY)
+                .and_then(|julian_day| Date::from_julian_day(julian_day).ok())
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, ERROR_DATE_OUT_OF_RANGE))
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["jiff"]`.
+///
+/// Module defines [BorshDeserialize] implementation for
+/// some types from [jiff](::jiff) crate.
+#[cfg(feature = "jiff")]
+pub mod jiff {
+    use super::{ERROR_DATE_OUT_OF_RANGE, ERROR_INVALID_NANOSECONDS, ERROR_TIMESTAMP_OUT_OF_RANGE};
+    use crate::io::{Error, ErrorKind, Read, Result};
+    use crate::BorshDeserialize;
+    use ::jiff::civil::{self, Date};
+    use ::jiff::{Span, Timestamp};
+
+    impl BorshDeserialize for Timestamp {
+        fixed_serialized_size!(8 + 4);
+
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let secs = i64::deserialize_reader(reader)?;
+            let nanos = u32::deserialize_reader(reader)?;
+            if nanos >= 1_000_000_000 {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    ERROR_INVALID_NANOSECONDS,
+                ));
+            }
+            Timestamp::new(secs, nanos as i32)
+                .map_err(|_| Error::new(ErrorKind::InvalidData, ERROR_TIMESTAMP_OUT_OF_RANGE))
+        }
+    }
+
+    impl BorshDeserialize for Date {
+        fixed_serialized_size!(4);
+
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let days = i32::deserialize_reader(reader)?;
+            Span::new()
+                .try_days(days)
+                .and_then(|span| civil::date(1970, 1, 1).checked_add(span))
+                .map_err(|_| Error::new(ErrorKind::InvalidData, ERROR_DATE_OUT_OF_RANGE))
+        }
+    }
+}
+
+/// Reads all of the bytes remaining in `reader`.
+///
+/// This is used by `#[derive(BorshDeserialize)]` to capture the payload of an enum variant,
+/// annotated with `#[borsh(unknown)]`.
+#[doc(hidden)]
+pub fn read_remaining<R: Read>(reader: &mut R) -> Result<Vec<u8>> {
+    let mut result = Vec::new();
+    let mut buf = [0u8; 1024];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return Ok(result),
+            Ok(read) => result.extend_from_slice(&buf[..read]),
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Deserializes a field, annotated with `#[borsh(trailing_default)]`, with `f`.
+///
+/// If `reader` is cleanly exhausted before the field, i.e. not a single byte of it is present,
+/// the field takes its default value instead.
+#[doc(hidden)]
+pub fn deserialize_trailing_default<'a, R, T, F>(reader: &'a mut R, f: F) -> Result<T>
+where
+    R: Read,
+    T: Default,
+    F: FnOnce(&mut TrailingReader<'a, R>) -> Result<T>,
+{
+    let mut first = [0u8; 1];
+    loop {
+        match reader.read(&mut first) {
+            Ok(0) => return Ok(T::default()),
+            Ok(_) => break,
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    f(&mut TrailingReader {
+        first: Some(first[0]),
+        reader,
+    })
+}
+
+/// Same as [deserialize_trailing_default], but for an in-memory [BorshRead] source,
+/// which reports the end of input without consuming anything.
+#[doc(hidden)]
+pub fn deserialize_trailing_default_from<B, T, F>(reader: &mut B, f: F) -> Result<T>
+where
+    B: BorshRead,
+    T: Default,
+    F: FnOnce(&mut B) -> Result<T>,
+{
+    if reader.remaining() == 0 {
+        return Ok(T::default());
+    }
+    f(reader)
+}
+
+/// Reader, which yields the byte, consumed by [deserialize_trailing_default] to check for the end of
+/// input, followed by the rest of the underlying reader.
+#[doc(hidden)]
+pub struct TrailingReader<'a, R> {
+    first: Option<u8>,
+    reader: &'a mut R,
+}
+
+impl<R: Read> Read for TrailingReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        match (self.first, buf.split_first_mut()) {
+            (Some(first), Some((head, _))) => {
+                *head = first;
+                self.first = None;
+                Ok(1)
+            }
+            _ => self.reader.read(buf),
+        }
+    }
+}
+
 /// Deserializes an object from a slice of bytes.
 /// # Example
 /// ```
@@ -1045,6 +2849,32 @@ pub fn from_slice<T: BorshDeserialize>(v: &[u8]) -> Result<T> {
     Ok(object)
 }
 
+/// Deserializes an object from a [bytes::Buf], such as `Bytes` or a chain of buffers.
+///
+/// `Bytes` values are decoded as views sharing the allocation of `buf`, if it's backed by
+/// `Bytes` or `BytesMut`, instead of being copied out of it.
+/// # Example
+/// ```
+/// use bytes::{Buf, Bytes};
+///
+/// let encoded = borsh::to_vec(&(7u32, Bytes::from_static(b"payload"))).unwrap();
+/// let (head, tail) = encoded.split_at(6);
+/// let chained = Bytes::copy_from_slice(head).chain(Bytes::copy_from_slice(tail));
+/// let (number, payload) = borsh::from_buf::<_, (u32, Bytes)>(chained).unwrap();
+/// assert_eq!((number, &payload[..]), (7, &b"payload"[..]));
+/// ```
+/// # Errors
+/// This function will return an error if the data is invalid or not fully read.
+#[cfg(feature = "bytes")]
+pub fn from_buf<B: bytes::Buf, T: BorshDeserialize>(buf: B) -> Result<T> {
+    let mut source = BufSource::new(buf);
+    let result = T::deserialize_from(&mut source)?;
+    if source.remaining() != 0 {
+        return Err(Error::new(ErrorKind::InvalidData, ERROR_NOT_ALL_BYTES_READ));
+    }
+    Ok(result)
+}
+
 /// Deserializes an object from a reader.
 /// # Example
 /// ```
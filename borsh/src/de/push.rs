@@ -0,0 +1,374 @@
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+
+use crate::__private::maybestd::{collections::BTreeMap, format, vec::Vec};
+use crate::io::{Error, ErrorKind, Result};
+use crate::schema::{BorshSchemaContainer, Definition, Fields};
+use crate::{from_slice, BorshDeserialize, BorshSchema};
+
+const ERROR_LENGTH_OUT_OF_RANGE: &str = "Sequence length is out of range";
+const ERROR_SIZE_OVERFLOW: &str = "Size of the value overflows usize";
+
+/// Index of the node of the decoded type, which is resolved first.
+const ROOT: usize = 0;
+
+/// Result of feeding a chunk of bytes to a [PushDecoder].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Progress<T> {
+    /// The value isn't complete yet, at least this many more bytes are needed.
+    NeedMore(usize),
+    /// The value is complete.
+    Done(T),
+}
+
+/// Resumable decoder of `T`, for input which arrives in chunks and can't be blocked on.
+///
+/// The decoder buffers fed bytes and walks the [schema](crate::schema) of `T` to find where
+/// the value ends. The walk is resumed from where it stopped on each [PushDecoder::feed], so
+/// every byte is scanned once, and `T` is deserialized once, when all of its bytes are buffered.
+/// Fixed-size parts, such as `Vec<u8>` payloads, are skipped in one step.
+///
+/// Bytes following a completed value are kept for the next one, which can be decoded by
+/// feeding an empty chunk. Validation of the contents, e.g. of utf-8 or `bool` values,
+/// happens in [BorshDeserialize], so errors about those are returned once the value is complete.
+///
+/// ```
+/// use borsh::de::{Progress, PushDecoder};
+///
+/// let bytes = borsh::to_vec(&(7u8, String::from("borsh"))).unwrap();
+/// let mut decoder = PushDecoder::<(u8, String)>::new().unwrap();
+/// assert_eq!(decoder.feed(&bytes[..3]).unwrap(), Progress::NeedMore(2));
+/// assert_eq!(decoder.feed(&bytes[3..6]).unwrap(), Progress::NeedMore(4));
+/// assert_eq!(
+///     decoder.feed(&bytes[6..]).unwrap(),
+///     Progress::Done((7, String::from("borsh")))
+/// );
+/// ```
+pub struct PushDecoder<T> {
+    nodes: Vec<Node>,
+    buf: Vec<u8>,
+    /// position in `buf` the scan has reached
+    cursor: usize,
+    tasks: Vec<Task>,
+    _value: PhantomData<fn() -> T>,
+}
+
+/// Definition of a type, resolved against the schema.
+enum Node {
+    /// a type of known size
+    Fixed(usize),
+    /// a sequence prefixed by its `length_width`-byte-wide length
+    Sequence {
+        length_width: u8,
+        length_range: core::ops::RangeInclusive<u64>,
+        elements: usize,
+    },
+    /// a sequence of known length, such as an array
+    Array { elements: usize, length: u64 },
+    /// fields of a struct or elements of a tuple, one after another
+    Product(Vec<usize>),
+    /// a union prefixed by its `tag_width`-byte-wide tag
+    Enum {
+        tag_width: u8,
+        variants: Vec<(u64, usize)>,
+    },
+}
+
+/// Pending step of the scan.
+enum Task {
+    Node(usize),
+    Skip(usize),
+    Repeat { node: usize, count: u64 },
+}
+
+impl<T: BorshDeserialize + BorshSchema> PushDecoder<T> {
+    /// Creates a decoder for values of `T`.
+    ///
+    /// Fails with [ErrorKind::InvalidInput], if the schema of `T` is missing definitions or
+    /// uses untagged sequences or unions, as the end of such values can't be found by the schema alone.
+    pub fn new() -> Result<Self> {
+        let schema = BorshSchemaContainer::for_type::<T>();
+        let mut nodes = Vec::new();
+        let mut ids = BTreeMap::new();
+        resolve(schema.declaration(), &schema, &mut nodes, &mut ids)?;
+        let mut states = Vec::new();
+        states.resize(nodes.len(), SizeState::Unknown);
+        for id in 0..nodes.len() {
+            fixed_size(id, &mut nodes, &mut states);
+        }
+        Ok(Self {
+            nodes,
+            buf: Vec::new(),
+            cursor: 0,
+            tasks: Vec::from([Task::Node(ROOT)]),
+            _value: PhantomData,
+        })
+    }
+
+    /// Appends `chunk` to the buffered input and continues decoding.
+    ///
+    /// Returns [Progress::Done] with the value once all of its bytes were fed, after which
+    /// the decoder starts on the next value. On error the decoder should be discarded.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Progress<T>> {
+        self.buf.extend_from_slice(chunk);
+        while let Some(task) = self.tasks.pop() {
+            if let Some(needed) = self.step(task)? {
+                return Ok(Progress::NeedMore(needed));
+            }
+        }
+        let value = from_slice(&self.buf[..self.cursor]);
+        self.buf.drain(..self.cursor);
+        self.cursor = 0;
+        self.tasks.push(Task::Node(ROOT));
+        value.map(Progress::Done)
+    }
+
+    /// Returns the number of fed bytes which aren't yet part of a returned value.
+    pub fn buffered(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Makes progress on `task`, returning the number of bytes needed if it can't be completed.
+    fn step(&mut self, task: Task) -> Result<Option<usize>> {
+        let available = self.buf.len() - self.cursor;
+        match task {
+            Task::Skip(size) => {
+                if size > available {
+                    self.cursor = self.buf.len();
+                    self.tasks.push(Task::Skip(size - available));
+                    return Ok(Some(size - available));
+                }
+                self.cursor += size;
+            }
+            Task::Repeat { node, count } => {
+                if count > 0 {
+                    self.tasks.push(Task::Repeat {
+                        node,
+                        count: count - 1,
+                    });
+                    self.tasks.push(Task::Node(node));
+                }
+            }
+            Task::Node(id) => match &self.nodes[id] {
+                Node::Fixed(size) => self.tasks.push(Task::Skip(*size)),
+                Node::Product(elements) => {
+                    self.tasks
+                        .extend(elements.iter().rev().map(|element| Task::Node(*element)));
+                }
+                Node::Sequence {
+                    length_width,
+                    length_range,
+                    elements,
+                } => {
+                    let width = usize::from(*length_width);
+                    if width > available {
+                        self.tasks.push(task);
+                        return Ok(Some(width - available));
+                    }
+                    let length = read_le(&self.buf[self.cursor..], width);
+                    if !length_range.contains(&length) {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            ERROR_LENGTH_OUT_OF_RANGE,
+                        ));
+                    }
+                    let elements = *elements;
+                    self.cursor += width;
+                    self.push_elements(elements, length)?;
+                }
+                Node::Array { elements, length } => {
+                    let (elements, length) = (*elements, *length);
+                    self.push_elements(elements, length)?;
+                }
+                Node::Enum {
+                    tag_width,
+                    variants,
+                } => {
+                    let width = usize::from(*tag_width);
+                    if width > available {
+                        self.tasks.push(task);
+                        return Ok(Some(width - available));
+                    }
+                    let tag = read_le(&self.buf[self.cursor..], width);
+                    let variant = variants
+                        .iter()
+                        .find(|(discriminant, _)| *discriminant == tag)
+                        .map(|(_, variant)| *variant)
+                        .ok_or_else(|| {
+                            Error::new(
+                                ErrorKind::InvalidData,
+                                format!("Unexpected variant tag: {:?}", tag),
+                            )
+                        })?;
+                    self.cursor += width;
+                    self.tasks.push(Task::Node(variant));
+                }
+            },
+        }
+        Ok(None)
+    }
+
+    /// Schedules `length` values of `elements`, skipping them at once if they're of fixed size.
+    fn push_elements(&mut self, elements: usize, length: u64) -> Result<()> {
+        match &self.nodes[elements] {
+            Node::Fixed(size) => {
+                let size = usize::try_from(length)
+                    .ok()
+                    .and_then(|length| length.checked_mul(*size))
+                    .ok_or_else(|| Error::new(ErrorKind::InvalidData, ERROR_SIZE_OVERFLOW))?;
+                self.tasks.push(Task::Skip(size));
+            }
+            _ => self.tasks.push(Task::Repeat {
+                node: elements,
+                count: length,
+            }),
+        }
+        Ok(())
+    }
+}
+
+/// Reads a `width`-byte-wide little-endian number from the start of `bytes`.
+fn read_le(bytes: &[u8], width: usize) -> u64 {
+    let mut number = [0u8; 8];
+    number[..width].copy_from_slice(&bytes[..width]);
+    u64::from_le_bytes(number)
+}
+
+fn unsupported(declaration: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "Schema of {} doesn't determine the encoded length",
+            declaration
+        ),
+    )
+}
+
+/// Resolves `declaration` into `nodes`, returning its index.
+fn resolve(
+    declaration: &str,
+    schema: &BorshSchemaContainer,
+    nodes: &mut Vec<Node>,
+    ids: &mut BTreeMap<Vec<u8>, usize>,
+) -> Result<usize> {
+    if let Some(id) = ids.get(declaration.as_bytes()) {
+        return Ok(*id);
+    }
+    let id = nodes.len();
+    // placeholder, which lets recursive types refer to themselves
+    nodes.push(Node::Product(Vec::new()));
+    ids.insert(declaration.as_bytes().to_vec(), id);
+    let node = match schema.get_definition(declaration) {
+        None => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Schema is missing definition of {}", declaration),
+            ))
+        }
+        Some(Definition::Primitive(size)) => Node::Fixed(usize::from(*size)),
+        Some(Definition::Sequence {
+            length_width,
+            length_range,
+            elements,
+        }) => {
+            let elements = resolve(elements, schema, nodes, ids)?;
+            if *length_width > 8 {
+                return Err(unsupported(declaration));
+            }
+            if *length_width == Definition::ARRAY_LENGTH_WIDTH {
+                if length_range.start() != length_range.end() {
+                    return Err(unsupported(declaration));
+                }
+                Node::Array {
+                    elements,
+                    length: *length_range.start(),
+                }
+            } else {
+                Node::Sequence {
+                    length_width: *length_width,
+                    length_range: length_range.clone(),
+                    elements,
+                }
+            }
+        }
+        Some(Definition::Tuple { elements }) => Node::Product(
+            elements
+                .iter()
+                .map(|element| resolve(element, schema, nodes, ids))
+                .collect::<Result<_>>()?,
+        ),
+        Some(Definition::Struct { fields }) => {
+            let fields: Vec<&str> = match fields {
+                Fields::NamedFields(fields) => fields.iter().map(|(_, f)| f.as_str()).collect(),
+                Fields::UnnamedFields(fields) => fields.iter().map(|f| f.as_str()).collect(),
+                Fields::Empty => Vec::new(),
+            };
+            Node::Product(
+                fields
+                    .into_iter()
+                    .map(|field| resolve(field, schema, nodes, ids))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        Some(Definition::Enum {
+            tag_width,
+            variants,
+        }) => {
+            if *tag_width == 0 || *tag_width > 8 {
+                return Err(unsupported(declaration));
+            }
+            Node::Enum {
+                tag_width: *tag_width,
+                variants: variants
+                    .iter()
+                    .map(|(discriminant, _, variant)| {
+                        Ok((*discriminant as u64, resolve(variant, schema, nodes, ids)?))
+                    })
+                    .collect::<Result<_>>()?,
+            }
+        }
+    };
+    nodes[id] = node;
+    Ok(id)
+}
+
+#[derive(Clone, Copy)]
+enum SizeState {
+    Unknown,
+    Visiting,
+    Known(Option<usize>),
+}
+
+/// Returns the size of node `id` if it's fixed, replacing the node with [Node::Fixed] in that case.
+fn fixed_size(id: usize, nodes: &mut Vec<Node>, states: &mut Vec<SizeState>) -> Option<usize> {
+    match states[id] {
+        SizeState::Known(size) => return size,
+        // a recursive type can't be of fixed size
+        SizeState::Visiting => return None,
+        SizeState::Unknown => states[id] = SizeState::Visiting,
+    }
+    let size = match &nodes[id] {
+        Node::Fixed(size) => Some(*size),
+        Node::Sequence { .. } | Node::Enum { .. } => None,
+        Node::Array { elements, length } => {
+            let (elements, length) = (*elements, *length);
+            fixed_size(elements, nodes, states)
+                .and_then(|size| size.checked_mul(usize::try_from(length).ok()?))
+        }
+        Node::Product(elements) => {
+            let elements = elements.clone();
+            let mut sum = Some(0usize);
+            // every element is visited, so that its own size gets known
+            for element in elements {
+                let size = fixed_size(element, nodes, states);
+                sum = sum.and_then(|sum| sum.checked_add(size?));
+            }
+            sum
+        }
+    };
+    if let Some(size) = size {
+        nodes[id] = Node::Fixed(size);
+    }
+    states[id] = SizeState::Known(size);
+    size
+}
@@ -0,0 +1,180 @@
+#[cfg(feature = "bytes")]
+use crate::__private::maybestd::vec::Vec;
+use crate::io::{Error, ErrorKind, Read, Result};
+
+use super::ERROR_UNEXPECTED_LENGTH_OF_INPUT;
+
+/// In-memory source of bytes, which [BorshDeserialize](super::BorshDeserialize)
+/// implementations can read from without the overhead of [Read] calls.
+///
+/// Built-in and derived implementations read from it in
+/// [BorshDeserialize::deserialize_from](super::BorshDeserialize::deserialize_from),
+/// checking bounds once per primitive value. It's implemented for `&[u8]`, which is used by
+/// [from_slice](super::from_slice) and [BorshDeserialize::deserialize](super::BorshDeserialize::deserialize).
+///
+/// ```
+/// use borsh::de::BorshRead;
+///
+/// let mut input: &[u8] = &[1, 2, 3, 4, 5];
+/// assert_eq!(input.peek(), Some(1));
+/// assert_eq!(input.take(2).unwrap(), &[1, 2]);
+/// assert_eq!(input.take_array::<2>().unwrap(), [3, 4]);
+/// assert_eq!(input.remaining(), 1);
+/// assert!(input.take(2).is_err());
+/// ```
+pub trait BorshRead: Read {
+    /// Returns the next byte without consuming it, or `None` if the input is exhausted.
+    fn peek(&self) -> Option<u8>;
+
+    /// Consumes next `n` bytes and returns them.
+    ///
+    /// Fails with [ErrorKind::InvalidData], if fewer than `n` bytes are left,
+    /// in which case nothing is consumed.
+    fn take(&mut self, n: usize) -> Result<&[u8]>;
+
+    /// Returns the number of bytes left.
+    fn remaining(&self) -> usize;
+
+    /// Consumes next `n` bytes and returns them as [bytes::Bytes].
+    ///
+    /// Copies the bytes by default. The source used by [from_buf](super::from_buf) returns a
+    /// view sharing the allocation of the buffer, if it's backed by `Bytes` or `BytesMut`.
+    #[cfg(feature = "bytes")]
+    #[inline]
+    fn take_bytes(&mut self, n: usize) -> Result<bytes::Bytes> {
+        self.take(n).map(bytes::Bytes::copy_from_slice)
+    }
+
+    /// Consumes next `N` bytes and returns them as an array.
+    #[inline]
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+}
+
+impl BorshRead for &[u8] {
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        self.first().copied()
+    }
+
+    #[inline]
+    fn take(&mut self, n: usize) -> Result<&[u8]> {
+        if n > self.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                ERROR_UNEXPECTED_LENGTH_OF_INPUT,
+            ));
+        }
+        let (taken, rest) = self.split_at(n);
+        *self = rest;
+        Ok(taken)
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        match self.split_first_chunk::<N>() {
+            Some((taken, rest)) => {
+                *self = rest;
+                Ok(*taken)
+            }
+            None => Err(Error::new(
+                ErrorKind::InvalidData,
+                ERROR_UNEXPECTED_LENGTH_OF_INPUT,
+            )),
+        }
+    }
+}
+
+/// Source over a [bytes::Buf], which may consist of several chunks.
+///
+/// Bytes returned by [BorshRead::take] are borrowed from the current chunk, if they're all in it,
+/// and the buffer is advanced past them on the next call. Otherwise they're copied to `scratch`.
+#[cfg(feature = "bytes")]
+pub(crate) struct BufSource<B> {
+    buf: B,
+    /// bytes of the current chunk already returned, but not yet advanced past
+    consumed: usize,
+    scratch: Vec<u8>,
+}
+
+#[cfg(feature = "bytes")]
+impl<B: bytes::Buf> BufSource<B> {
+    pub(crate) fn new(buf: B) -> Self {
+        Self {
+            buf,
+            consumed: 0,
+            scratch: Vec::new(),
+        }
+    }
+
+    #[inline]
+    fn advance_consumed(&mut self) {
+        if self.consumed > 0 {
+            self.buf.advance(self.consumed);
+            self.consumed = 0;
+        }
+    }
+
+    #[inline]
+    fn check_remaining(&self, n: usize) -> Result<()> {
+        if n > self.remaining() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                ERROR_UNEXPECTED_LENGTH_OF_INPUT,
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<B: bytes::Buf> Read for BufSource<B> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.advance_consumed();
+        let len = buf.len().min(self.buf.remaining());
+        self.buf.copy_to_slice(&mut buf[..len]);
+        Ok(len)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<B: bytes::Buf> BorshRead for BufSource<B> {
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        // `take` leaves the current chunk only when the input is exhausted
+        self.buf.chunk().get(self.consumed).copied()
+    }
+
+    fn take(&mut self, n: usize) -> Result<&[u8]> {
+        self.advance_consumed();
+        self.check_remaining(n)?;
+        let chunk_len = self.buf.chunk().len();
+        // the current chunk can't be left, while `peek` needs to see the next one
+        if n < chunk_len || (n == chunk_len && n == self.buf.remaining()) {
+            self.consumed = n;
+            return Ok(&self.buf.chunk()[..n]);
+        }
+        self.scratch.resize(n, 0);
+        self.buf.copy_to_slice(&mut self.scratch);
+        Ok(&self.scratch)
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.buf.remaining() - self.consumed
+    }
+
+    fn take_bytes(&mut self, n: usize) -> Result<bytes::Bytes> {
+        self.advance_consumed();
+        self.check_remaining(n)?;
+        Ok(self.buf.copy_to_bytes(n))
+    }
+}
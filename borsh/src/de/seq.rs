@@ -0,0 +1,79 @@
+use core::marker::PhantomData;
+
+use crate::error::check_zst;
+use crate::io::{Read, Result};
+
+use super::BorshDeserialize;
+
+/// Lazy iterator over the elements of a sequence encoded as `Vec<T>`, which reads them
+/// one at a time from a reader.
+///
+/// It lets sequences too large to be kept in memory be processed element by element.
+/// The length prefix is read by [SeqReader::new]; after the last element, the reader is
+/// positioned past the sequence and can be recovered with [SeqReader::into_inner].
+/// The iteration stops after the first error.
+///
+/// ```
+/// use borsh::de::SeqReader;
+///
+/// let bytes = borsh::to_vec(&vec![1u64, 2, 3]).unwrap();
+/// let mut elements = SeqReader::<u64, _>::new(&bytes[..]).unwrap();
+/// assert_eq!(elements.len(), 3);
+/// assert_eq!(elements.next().unwrap().unwrap(), 1);
+/// let rest = elements.collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(rest, vec![2, 3]);
+/// ```
+pub struct SeqReader<T, R> {
+    reader: R,
+    remaining: u32,
+    _element: PhantomData<fn() -> T>,
+}
+
+impl<T: BorshDeserialize, R: Read> SeqReader<T, R> {
+    /// Reads the length of the sequence from `reader`.
+    pub fn new(mut reader: R) -> Result<Self> {
+        check_zst::<T>()?;
+        let remaining = u32::deserialize_reader(&mut reader)?;
+        Ok(Self {
+            reader,
+            remaining,
+            _element: PhantomData,
+        })
+    }
+
+    /// Returns the number of elements not yet read.
+    pub fn len(&self) -> usize {
+        self.remaining as usize
+    }
+
+    /// Returns `true` if all elements were read.
+    pub fn is_empty(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<T: BorshDeserialize, R: Read> Iterator for SeqReader<T, R> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let element = T::deserialize_reader(&mut self.reader);
+        self.remaining = match element {
+            Ok(_) => self.remaining - 1,
+            Err(_) => 0,
+        };
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // an error ends the iteration early
+        (0, Some(self.len()))
+    }
+}
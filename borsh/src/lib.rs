@@ -16,7 +16,16 @@ pub use borsh_derive::BorshDeserialize;
 #[cfg(feature = "derive")]
 pub use borsh_derive::BorshSerialize;
 
+#[doc = include_str!("../docs/rustdoc_include/borsh_pod.md")]
+#[cfg(feature = "derive")]
+pub use borsh_derive::BorshPod;
+
+#[doc = include_str!("../docs/rustdoc_include/borsh_view.md")]
+#[cfg(feature = "derive")]
+pub use borsh_derive::BorshView;
+
 pub mod de;
+pub mod pod;
 
 // See `hash_collections` alias definition in build.rs
 /// Module is available if borsh is built with `features = ["unstable__schema"]`.
@@ -26,6 +35,8 @@ pub mod schema;
 pub(crate) mod schema_helpers;
 pub mod ser;
 
+#[cfg(feature = "bytes")]
+pub use de::from_buf;
 pub use de::BorshDeserialize;
 pub use de::{from_reader, from_slice};
 #[cfg(feature = "unstable__schema")]
@@ -34,13 +45,12 @@ pub use schema::BorshSchema;
 pub use schema_helpers::{
     max_serialized_size, schema_container_of, try_from_slice_with_schema, try_to_vec_with_schema,
 };
-pub use ser::helpers::{object_length, to_vec, to_writer};
+#[cfg(feature = "bytes")]
+pub use ser::helpers::to_buf_mut;
+pub use ser::helpers::{object_length, to_array, to_slice, to_vec, to_writer};
 pub use ser::BorshSerialize;
 pub mod error;
 
-#[cfg(all(feature = "std", feature = "hashbrown"))]
-compile_error!("feature \"std\" and feature \"hashbrown\" don't make sense at the same time");
-
 #[cfg(feature = "std")]
 use std::io as io_impl;
 #[cfg(not(feature = "std"))]
@@ -65,20 +75,22 @@ pub mod __private {
     /// module.
     #[cfg(feature = "std")]
     pub mod maybestd {
-        pub use std::{borrow, boxed, collections, format, string, vec};
+        pub use std::{borrow, boxed, collections, ffi, format, string, vec};
 
         #[cfg(feature = "rc")]
         pub use std::{rc, sync};
     }
     #[cfg(not(feature = "std"))]
     pub mod maybestd {
-        pub use alloc::{borrow, boxed, format, string, vec};
+        pub use alloc::{borrow, boxed, ffi, format, string, vec};
 
         #[cfg(feature = "rc")]
         pub use alloc::{rc, sync};
 
         pub mod collections {
-            pub use alloc::collections::{btree_map, BTreeMap, BTreeSet, LinkedList, VecDeque};
+            pub use alloc::collections::{
+                btree_map, BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque,
+            };
             #[cfg(feature = "hashbrown")]
             pub use hashbrown::*;
         }
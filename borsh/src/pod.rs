@@ -0,0 +1,141 @@
+//! Zero-copy views of byte buffers as plain-old-data types.
+
+use core::mem::{align_of, size_of};
+
+use crate::__private::maybestd::format;
+use crate::io::{Error, ErrorKind, Result};
+
+/// Type, whose in-memory representation equals its borsh encoding, so that a byte buffer
+/// can be viewed as a value of the type without copying.
+///
+/// It's implemented for `u8`, `i8`, `bool`, arrays of such types and, on little-endian targets,
+/// other fixed-size integers. It's derived with `#[derive(BorshPod)]` for `#[repr(C)]` and
+/// `#[repr(transparent)]` structs of such types without padding, which is checked at compile time.
+///
+/// ```
+/// use borsh::pod::BorshPod;
+///
+/// let mut bytes = [1u8, 0, 2, 0];
+/// assert_eq!(<[u8; 4]>::from_bytes(&bytes).unwrap(), &[1, 0, 2, 0]);
+/// assert!(bool::from_bytes(&bytes[..1]).unwrap());
+/// assert!(bool::from_bytes(&bytes[2..3]).is_err());
+/// *<[u8; 2]>::from_bytes_mut(&mut bytes[2..]).unwrap() = [3, 4];
+/// assert_eq!(bytes, [1, 0, 3, 4]);
+/// ```
+///
+/// # Safety
+///
+/// Implementations must guarantee that the type has no padding and no interior mutability,
+/// that the borsh encoding of any of its values is its in-memory representation, and that
+/// any bytes accepted by [BorshPod::validate] are a valid value of the type.
+pub unsafe trait BorshPod: Copy + 'static {
+    /// Checks that `bytes`, which are exactly `size_of::<Self>()` long, are a valid value of the type.
+    #[doc(hidden)]
+    fn validate(bytes: &[u8]) -> Result<()>;
+
+    /// Views `bytes` as a value of the type.
+    ///
+    /// Fails with [ErrorKind::InvalidData], if `bytes` isn't exactly `size_of::<Self>()` long,
+    /// isn't aligned to `align_of::<Self>()` or isn't a valid value of the type.
+    /// [BorshPod::read_unaligned] may be used for unaligned buffers.
+    fn from_bytes(bytes: &[u8]) -> Result<&Self> {
+        check_len_and_alignment::<Self>(bytes)?;
+        Self::validate(bytes)?;
+        // SAFETY: length and alignment are checked above, and the bytes are a valid value
+        Ok(unsafe { &*bytes.as_ptr().cast::<Self>() })
+    }
+
+    /// Views `bytes` as a mutable value of the type, with the same requirements as [BorshPod::from_bytes].
+    ///
+    /// Any value assigned through the view keeps `bytes` a valid borsh encoding of the type.
+    fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self> {
+        check_len_and_alignment::<Self>(bytes)?;
+        Self::validate(bytes)?;
+        // SAFETY: length and alignment are checked above, and the bytes are a valid value
+        Ok(unsafe { &mut *bytes.as_mut_ptr().cast::<Self>() })
+    }
+
+    /// Copies a value of the type out of `bytes`, which don't have to be aligned.
+    ///
+    /// Fails with [ErrorKind::InvalidData], if `bytes` isn't exactly `size_of::<Self>()` long
+    /// or isn't a valid value of the type.
+    fn read_unaligned(bytes: &[u8]) -> Result<Self> {
+        check_len::<Self>(bytes)?;
+        Self::validate(bytes)?;
+        // SAFETY: length is checked above, and the bytes are a valid value
+        Ok(unsafe { bytes.as_ptr().cast::<Self>().read_unaligned() })
+    }
+
+    /// Returns the borsh encoding of the value, which is its in-memory representation.
+    fn as_bytes(&self) -> &[u8] {
+        // SAFETY: the type has no padding, so all of its bytes are initialized
+        unsafe {
+            core::slice::from_raw_parts((self as *const Self).cast::<u8>(), size_of::<Self>())
+        }
+    }
+}
+
+fn check_len<T>(bytes: &[u8]) -> Result<()> {
+    if bytes.len() != size_of::<T>() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Unexpected length of input: {} bytes, expected {}",
+                bytes.len(),
+                size_of::<T>()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn check_len_and_alignment<T>(bytes: &[u8]) -> Result<()> {
+    check_len::<T>(bytes)?;
+    if bytes.as_ptr() as usize % align_of::<T>() != 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("Input isn't aligned to {} bytes", align_of::<T>()),
+        ));
+    }
+    Ok(())
+}
+
+macro_rules! impl_for_primitives {
+    ($($type: ident)*) => {
+        $(
+            unsafe impl BorshPod for $type {
+                #[inline]
+                fn validate(_bytes: &[u8]) -> Result<()> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_for_primitives!(u8 i8);
+#[cfg(target_endian = "little")]
+impl_for_primitives!(u16 u32 u64 u128 i16 i32 i64 i128);
+
+unsafe impl BorshPod for bool {
+    #[inline]
+    fn validate(bytes: &[u8]) -> Result<()> {
+        match bytes[0] {
+            0 | 1 => Ok(()),
+            value => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid bool representation: {}", value),
+            )),
+        }
+    }
+}
+
+unsafe impl<T: BorshPod, const N: usize> BorshPod for [T; N] {
+    #[inline]
+    fn validate(bytes: &[u8]) -> Result<()> {
+        if size_of::<T>() == 0 {
+            return Ok(());
+        }
+        bytes.chunks_exact(size_of::<T>()).try_for_each(T::validate)
+    }
+}
@@ -16,7 +16,8 @@ use crate as borsh; // For `#[derive(BorshSerialize, BorshDeserialize)]`.
 use crate::__private::maybestd::{
     borrow,
     boxed::Box,
-    collections::{btree_map::Entry, BTreeMap, BTreeSet, LinkedList, VecDeque},
+    collections::{btree_map::Entry, BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    ffi::CString,
     format,
     string::{String, ToString},
     vec,
@@ -138,6 +139,13 @@ impl Definition {
     /// It equals `0..=u32::MAX`.  Can be used with
     /// `Definition::Sequence::length_range`.
     pub const DEFAULT_LENGTH_RANGE: core::ops::RangeInclusive<u64> = 0..=(u32::MAX as u64);
+
+    /// Discriminant value of a catch-all variant of `Definition::Enum`.
+    ///
+    /// Such a variant, derived from `#[borsh(unknown)]` attribute, is encoded with
+    /// any tag not claimed by the other variants, and its payload spans the rest
+    /// of the input (see [`UnknownVariantPayload`]).
+    pub const UNKNOWN_VARIANT_DISCRIMINANT: DiscriminantValue = -1;
 }
 
 /// The collection representing the fields of a struct.
@@ -242,6 +250,55 @@ where
     }
 }
 
+/// Payload of an enum variant, annotated with `#[borsh(unknown)]`.
+///
+/// The payload isn't prefixed with its length: it consists of all the bytes, which follow
+/// the tag, so the enum has to be either the last element of a length-delimited frame or
+/// wrapped into a length-prefixed envelope, e.g. `Vec<u8>`.
+pub struct UnknownVariantPayload;
+
+impl BorshSchema for UnknownVariantPayload {
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let definition = Definition::Sequence {
+            length_width: Definition::ARRAY_LENGTH_WIDTH,
+            length_range: Definition::DEFAULT_LENGTH_RANGE,
+            elements: u8::declaration(),
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        u8::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        "UnknownVariantPayload".into()
+    }
+}
+
+/// Schema marker of a struct field, annotated with `#[borsh(trailing_default)]`.
+///
+/// Such a field may be absent from the end of the input, in which case it takes its default
+/// value. It's described as an untagged [`Definition::Enum`] of two variants: `Absent`, declared
+/// as `()`, and `Present`, declared as `T`.
+pub struct TrailingDefault<T: ?Sized>(PhantomData<T>);
+
+impl<T: BorshSchema + ?Sized> BorshSchema for TrailingDefault<T> {
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let definition = Definition::Enum {
+            tag_width: 0,
+            variants: vec![
+                (0, "Absent".to_string(), <()>::declaration()),
+                (1, "Present".to_string(), T::declaration()),
+            ],
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        <()>::add_definitions_recursively(definitions);
+        T::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        format!(r#"TrailingDefault<{}>"#, T::declaration())
+    }
+}
+
 /// Helper method to add a single type definition to the map.
 pub fn add_definition(
     declaration: Declaration,
@@ -435,6 +492,7 @@ macro_rules! impl_for_primitives {
 
 impl_for_primitives!(bool => 1; f32 => 4; f64 => 8; i8 => 1; i16 => 2; i32 => 4; i64 => 8; i128 => 16);
 impl_for_primitives!(u8 => 1; u16 => 2; u32 => 4; u64 => 8; u128 => 16);
+impl_for_primitives!(char => 4);
 impl_for_renamed_primitives!(isize: i64 => 8);
 impl_for_renamed_primitives!(usize: u64 => 8);
 
@@ -532,250 +590,1007 @@ pub mod ascii {
     }
 }
 
-impl BorshSchema for core::ops::RangeFull {
-    #[inline]
-    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
-        let fields = Fields::Empty;
-        let def = Definition::Struct { fields };
-        add_definition(Self::declaration(), def, definitions);
-    }
-    #[inline]
-    fn declaration() -> Declaration {
-        "RangeFull".into()
-    }
-}
-
-macro_rules! impl_for_range {
-    ($type:ident, $($name:ident),*) => {
-        impl<T: BorshSchema> BorshSchema for core::ops::$type<T> {
-            fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
-                let decl = T::declaration();
-                let fields = Fields::NamedFields(vec![$(
-                    (FieldName::from(stringify!($name)), decl.clone())
-                ),*]);
-                let def = Definition::Struct { fields };
-                add_definition(Self::declaration(), def, definitions);
-                T::add_definitions_recursively(definitions);
-            }
-            fn declaration() -> Declaration {
-                format!("{}<{}>", stringify!($type), T::declaration())
-            }
-        }
-    };
-}
-
-impl_for_range!(Range, start, end);
-impl_for_range!(RangeInclusive, start, end);
-impl_for_range!(RangeFrom, start);
-impl_for_range!(RangeTo, end);
-impl_for_range!(RangeToInclusive, end);
+/// Module is available if borsh is built with `features = ["primitive-types"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// unsigned integers from [primitive_types](::primitive_types) crate.
+#[cfg(feature = "primitive-types")]
+pub mod primitive_types {
+    use crate::BorshSchema;
 
-impl<T, const N: usize> BorshSchema for [T; N]
-where
-    T: BorshSchema,
-{
-    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
-        use core::convert::TryFrom;
-        let length = u64::try_from(N).unwrap();
-        let definition = Definition::Sequence {
-            length_width: Definition::ARRAY_LENGTH_WIDTH,
-            length_range: length..=length,
-            elements: T::declaration(),
-        };
-        add_definition(Self::declaration(), definition, definitions);
-        T::add_definitions_recursively(definitions);
-    }
-    fn declaration() -> Declaration {
-        format!(r#"[{}; {}]"#, T::declaration(), N)
-    }
-}
+    use super::{add_definition, Declaration, Definition};
+    use crate::__private::maybestd::collections::BTreeMap;
 
-impl<T> BorshSchema for Option<T>
-where
-    T: BorshSchema,
-{
-    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
-        let definition = Definition::Enum {
-            tag_width: 1,
-            variants: vec![
-                (0u8 as i64, "None".to_string(), <()>::declaration()),
-                (1u8 as i64, "Some".to_string(), T::declaration()),
-            ],
+    macro_rules! impl_for_uint {
+        ($($type:ident : $size:expr),+) => {
+        $(
+            impl BorshSchema for ::primitive_types::$type {
+                #[inline]
+                fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+                    add_definition(Self::declaration(), Definition::Primitive($size), definitions);
+                }
+                #[inline]
+                fn declaration() -> Declaration {
+                    stringify!($type).into()
+                }
+            }
+        )+
         };
-        add_definition(Self::declaration(), definition, definitions);
-        T::add_definitions_recursively(definitions);
-        <()>::add_definitions_recursively(definitions);
     }
 
-    fn declaration() -> Declaration {
-        format!(r#"Option<{}>"#, T::declaration())
-    }
+    impl_for_uint!(U128: 16, U256: 32, U512: 64);
 }
 
-impl<T, E> BorshSchema for core::result::Result<T, E>
-where
-    T: BorshSchema,
-    E: BorshSchema,
-{
-    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
-        let definition = Definition::Enum {
-            tag_width: 1,
-            variants: vec![
-                (1u8 as i64, "Ok".to_string(), T::declaration()),
-                (0u8 as i64, "Err".to_string(), E::declaration()),
-            ],
-        };
-        add_definition(Self::declaration(), definition, definitions);
-        T::add_definitions_recursively(definitions);
-        E::add_definitions_recursively(definitions);
-    }
-
-    fn declaration() -> Declaration {
-        format!(r#"Result<{}, {}>"#, T::declaration(), E::declaration())
-    }
-}
+/// Module is available if borsh is built with `features = ["ethnum"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// 256-bit integers from [ethnum](::ethnum) crate.
+#[cfg(feature = "ethnum")]
+pub mod ethnum {
+    use crate::BorshSchema;
 
-macro_rules! impl_for_vec_like_collection {
-    ($type: ident) => {
-        impl<T> BorshSchema for $type<T>
-        where
-            T: BorshSchema,
-        {
-            fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
-                let definition = Definition::Sequence {
-                    length_width: Definition::DEFAULT_LENGTH_WIDTH,
-                    length_range: Definition::DEFAULT_LENGTH_RANGE,
-                    elements: T::declaration(),
-                };
-                add_definition(Self::declaration(), definition, definitions);
-                T::add_definitions_recursively(definitions);
-            }
+    use super::{add_definition, Declaration, Definition};
+    use crate::__private::maybestd::collections::BTreeMap;
 
-            fn declaration() -> Declaration {
-                format!(r#"{}<{}>"#, stringify!($type), T::declaration())
-            }
+    impl BorshSchema for ::ethnum::U256 {
+        #[inline]
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_definition(Self::declaration(), Definition::Primitive(32), definitions);
+        }
+        #[inline]
+        fn declaration() -> Declaration {
+            "U256".into()
         }
-    };
-}
-
-impl_for_vec_like_collection!(Vec);
-impl_for_vec_like_collection!(VecDeque);
-impl_for_vec_like_collection!(LinkedList);
-
-impl<T> BorshSchema for [T]
-where
-    T: BorshSchema,
-{
-    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
-        let definition = Definition::Sequence {
-            length_width: Definition::DEFAULT_LENGTH_WIDTH,
-            length_range: Definition::DEFAULT_LENGTH_RANGE,
-            elements: T::declaration(),
-        };
-        add_definition(Self::declaration(), definition, definitions);
-        T::add_definitions_recursively(definitions);
     }
 
-    fn declaration() -> Declaration {
-        format!(r#"Vec<{}>"#, T::declaration())
+    impl BorshSchema for ::ethnum::I256 {
+        #[inline]
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_definition(Self::declaration(), Definition::Primitive(32), definitions);
+        }
+        #[inline]
+        fn declaration() -> Declaration {
+            "I256".into()
+        }
     }
 }
 
-/// Module is available if borsh is built with `features = ["std"]` or `features = ["hashbrown"]`.
+/// Module is available if borsh is built with `features = ["num-bigint"]`.
 ///
 /// Module defines [BorshSchema] implementation for
-/// [HashMap](std::collections::HashMap)/[HashSet](std::collections::HashSet).
-#[cfg(hash_collections)]
-pub mod hashes {
+/// [BigUint](::num_bigint::BigUint) and [BigInt](::num_bigint::BigInt).
+#[cfg(feature = "num-bigint")]
+pub mod num_bigint {
     use crate::BorshSchema;
 
-    use super::{add_definition, Declaration, Definition};
-    use crate::__private::maybestd::collections::BTreeMap;
+    use super::{add_definition, Declaration, Definition, Fields};
+    use crate::__private::maybestd::{collections::BTreeMap, string::ToString, vec};
+    use ::num_bigint::{BigInt, BigUint, Sign};
 
-    use crate::__private::maybestd::collections::{HashMap, HashSet};
-    #[cfg(not(feature = "std"))]
-    use alloc::format;
-
-    // S is not serialized, so we ignore it in schema too
-    // forcing S to be BorshSchema forces to define Definition
-    // which must be empty, but if not - it will fail
-    // so better to ignore it
-    impl<K, V, S> BorshSchema for HashMap<K, V, S>
-    where
-        K: BorshSchema,
-        V: BorshSchema,
-    {
+    impl BorshSchema for BigUint {
         fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
             let definition = Definition::Sequence {
                 length_width: Definition::DEFAULT_LENGTH_WIDTH,
                 length_range: Definition::DEFAULT_LENGTH_RANGE,
-                elements: <(K, V)>::declaration(),
+                elements: u8::declaration(),
             };
             add_definition(Self::declaration(), definition, definitions);
-            <(K, V)>::add_definitions_recursively(definitions);
+            u8::add_definitions_recursively(definitions);
         }
 
         fn declaration() -> Declaration {
-            format!(r#"HashMap<{}, {}>"#, K::declaration(), V::declaration())
+            "BigUint".into()
         }
     }
 
-    impl<T, S> BorshSchema for HashSet<T, S>
-    where
-        T: BorshSchema,
-    {
+    impl BorshSchema for Sign {
         fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
-            let definition = Definition::Sequence {
-                length_width: Definition::DEFAULT_LENGTH_WIDTH,
-                length_range: Definition::DEFAULT_LENGTH_RANGE,
-                elements: <T>::declaration(),
+            let definition = Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (0u8 as i64, "Minus".to_string(), <()>::declaration()),
+                    (1u8 as i64, "NoSign".to_string(), <()>::declaration()),
+                    (2u8 as i64, "Plus".to_string(), <()>::declaration()),
+                ],
             };
             add_definition(Self::declaration(), definition, definitions);
-            <T>::add_definitions_recursively(definitions);
+            <()>::add_definitions_recursively(definitions);
         }
 
         fn declaration() -> Declaration {
-            format!(r#"HashSet<{}>"#, T::declaration())
+            "Sign".into()
         }
     }
-}
 
-impl<K, V> BorshSchema for BTreeMap<K, V>
-where
-    K: BorshSchema,
-    V: BorshSchema,
-{
-    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
-        let definition = Definition::Sequence {
-            length_width: Definition::DEFAULT_LENGTH_WIDTH,
-            length_range: Definition::DEFAULT_LENGTH_RANGE,
-            elements: <(K, V)>::declaration(),
-        };
-        add_definition(Self::declaration(), definition, definitions);
-        <(K, V)>::add_definitions_recursively(definitions);
-    }
+    impl BorshSchema for BigInt {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            let fields = Fields::NamedFields(vec![
+                ("sign".to_string(), Sign::declaration()),
+                ("magnitude".to_string(), BigUint::declaration()),
+            ]);
+            let definition = Definition::Struct { fields };
+            add_definition(Self::declaration(), definition, definitions);
+            Sign::add_definitions_recursively(definitions);
+            BigUint::add_definitions_recursively(definitions);
+        }
 
-    fn declaration() -> Declaration {
-        format!(r#"BTreeMap<{}, {}>"#, K::declaration(), V::declaration())
+        fn declaration() -> Declaration {
+            "BigInt".into()
+        }
     }
 }
 
-impl<T> BorshSchema for BTreeSet<T>
-where
-    T: BorshSchema,
-{
-    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
-        let definition = Definition::Sequence {
-            length_width: Definition::DEFAULT_LENGTH_WIDTH,
-            length_range: Definition::DEFAULT_LENGTH_RANGE,
-            elements: <T>::declaration(),
-        };
-        add_definition(Self::declaration(), definition, definitions);
-        <T>::add_definitions_recursively(definitions);
-    }
+/// Module is available if borsh is built with `features = ["smallvec"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// [SmallVec](::smallvec::SmallVec), which is described the same way as `Vec<T>`.
+#[cfg(feature = "smallvec")]
+pub mod smallvec {
+    use crate::BorshSchema;
 
-    fn declaration() -> Declaration {
-        format!(r#"BTreeSet<{}>"#, T::declaration())
+    use super::{Declaration, Definition};
+    use crate::__private::maybestd::collections::BTreeMap;
+
+    impl<A> BorshSchema for ::smallvec::SmallVec<A>
+    where
+        A: ::smallvec::Array,
+        A::Item: BorshSchema,
+    {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            <[A::Item]>::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            <[A::Item]>::declaration()
+        }
+    }
+}
+
+/// Adds definition of a sequence with fixed `capacity`.
+#[cfg(any(feature = "arrayvec", feature = "tinyvec", feature = "heapless"))]
+fn add_bounded_sequence_definition(
+    declaration: Declaration,
+    capacity: usize,
+    elements: Declaration,
+    definitions: &mut BTreeMap<Declaration, Definition>,
+) {
+    use core::convert::TryFrom;
+    let definition = Definition::Sequence {
+        length_width: Definition::DEFAULT_LENGTH_WIDTH,
+        length_range: 0..=u64::try_from(capacity).unwrap(),
+        elements,
+    };
+    add_definition(declaration, definition, definitions);
+}
+
+/// Module is available if borsh is built with `features = ["arrayvec"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// [ArrayVec](::arrayvec::ArrayVec) and [ArrayString](::arrayvec::ArrayString).
+#[cfg(feature = "arrayvec")]
+pub mod arrayvec {
+    use crate::BorshSchema;
+
+    use super::{add_bounded_sequence_definition, Declaration, Definition};
+    use crate::__private::maybestd::{collections::BTreeMap, format};
+
+    impl<T: BorshSchema, const CAP: usize> BorshSchema for ::arrayvec::ArrayVec<T, CAP> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_bounded_sequence_definition(
+                Self::declaration(),
+                CAP,
+                T::declaration(),
+                definitions,
+            );
+            T::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            format!(r#"ArrayVec<{}, {}>"#, T::declaration(), CAP)
+        }
+    }
+
+    impl<const CAP: usize> BorshSchema for ::arrayvec::ArrayString<CAP> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_bounded_sequence_definition(
+                Self::declaration(),
+                CAP,
+                u8::declaration(),
+                definitions,
+            );
+            u8::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            format!(r#"ArrayString<{}>"#, CAP)
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["tinyvec"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// [ArrayVec](::tinyvec::ArrayVec) and [TinyVec](::tinyvec::TinyVec).
+#[cfg(feature = "tinyvec")]
+pub mod tinyvec {
+    use crate::BorshSchema;
+
+    use super::{add_bounded_sequence_definition, Declaration, Definition};
+    use crate::__private::maybestd::{collections::BTreeMap, format};
+
+    impl<A> BorshSchema for ::tinyvec::ArrayVec<A>
+    where
+        A: ::tinyvec::Array,
+        A::Item: BorshSchema,
+    {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_bounded_sequence_definition(
+                Self::declaration(),
+                A::CAPACITY,
+                A::Item::declaration(),
+                definitions,
+            );
+            A::Item::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            format!(r#"ArrayVec<{}, {}>"#, A::Item::declaration(), A::CAPACITY)
+        }
+    }
+
+    impl<A> BorshSchema for ::tinyvec::TinyVec<A>
+    where
+        A: ::tinyvec::Array,
+        A::Item: BorshSchema,
+    {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            <[A::Item]>::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            <[A::Item]>::declaration()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["heapless"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// [Vec](::heapless::Vec) and [String](::heapless::String) from [heapless](::heapless) crate.
+#[cfg(feature = "heapless")]
+pub mod heapless {
+    use crate::BorshSchema;
+
+    use super::{add_bounded_sequence_definition, Declaration, Definition};
+    use crate::__private::maybestd::{collections::BTreeMap, format};
+
+    impl<T: BorshSchema, const N: usize> BorshSchema for ::heapless::Vec<T, N> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_bounded_sequence_definition(Self::declaration(), N, T::declaration(), definitions);
+            T::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            format!(r#"Vec<{}, {}>"#, T::declaration(), N)
+        }
+    }
+
+    impl<const N: usize> BorshSchema for ::heapless::String<N> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_bounded_sequence_definition(Self::declaration(), N, u8::declaration(), definitions);
+            u8::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            format!(r#"String<{}>"#, N)
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["serde_json"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// [Value](::serde_json::Value), [Number](::serde_json::Number) and [Map](::serde_json::Map).
+#[cfg(feature = "serde_json")]
+pub mod serde_json {
+    use crate::BorshSchema;
+
+    use super::{add_definition, Declaration, Definition};
+    use crate::__private::maybestd::{
+        collections::BTreeMap,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use ::serde_json::{Map, Number, Value};
+
+    impl BorshSchema for Value {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            // NOTE: `Value` is a recursive type, so its definitions are added only once
+            if definitions.contains_key(&Self::declaration()) {
+                return;
+            }
+            let definition = Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (0u8 as i64, "Null".to_string(), <()>::declaration()),
+                    (1u8 as i64, "Bool".to_string(), bool::declaration()),
+                    (2u8 as i64, "Number".to_string(), Number::declaration()),
+                    (3u8 as i64, "String".to_string(), String::declaration()),
+                    (4u8 as i64, "Array".to_string(), <Vec<Value>>::declaration()),
+                    (
+                        5u8 as i64,
+                        "Object".to_string(),
+                        <Map<String, Value>>::declaration(),
+                    ),
+                ],
+            };
+            add_definition(Self::declaration(), definition, definitions);
+            <()>::add_definitions_recursively(definitions);
+            bool::add_definitions_recursively(definitions);
+            Number::add_definitions_recursively(definitions);
+            String::add_definitions_recursively(definitions);
+            <Vec<Value>>::add_definitions_recursively(definitions);
+            <Map<String, Value>>::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            "JsonValue".into()
+        }
+    }
+
+    impl BorshSchema for Number {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            let definition = Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (0u8 as i64, "U64".to_string(), u64::declaration()),
+                    (1u8 as i64, "I64".to_string(), i64::declaration()),
+                    (2u8 as i64, "F64".to_string(), f64::declaration()),
+                ],
+            };
+            add_definition(Self::declaration(), definition, definitions);
+            u64::add_definitions_recursively(definitions);
+            i64::add_definitions_recursively(definitions);
+            f64::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            "JsonNumber".into()
+        }
+    }
+
+    impl BorshSchema for Map<String, Value> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            let definition = Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: <(String, Value)>::declaration(),
+            };
+            add_definition(Self::declaration(), definition, definitions);
+            <(String, Value)>::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            "JsonMap".into()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["rust_decimal"]`.
+///
+/// Module defines [BorshSchema] implementation for [Decimal](::rust_decimal::Decimal).
+#[cfg(feature = "rust_decimal")]
+pub mod rust_decimal {
+    use crate::BorshSchema;
+
+    use super::{add_definition, Declaration, Definition, Fields};
+    use crate::__private::maybestd::{collections::BTreeMap, string::ToString, vec};
+    use ::rust_decimal::Decimal;
+
+    impl BorshSchema for Decimal {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            let fields = Fields::NamedFields(vec![
+                ("flags".to_string(), u32::declaration()),
+                ("lo".to_string(), u32::declaration()),
+                ("mid".to_string(), u32::declaration()),
+                ("hi".to_string(), u32::declaration()),
+            ]);
+            let definition = Definition::Struct { fields };
+            add_definition(Self::declaration(), definition, definitions);
+            u32::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            "Decimal".into()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["ordered-float"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// [OrderedFloat](::ordered_float::OrderedFloat) and [NotNan](::ordered_float::NotNan),
+/// which are described the same way as the wrapped float.
+#[cfg(feature = "ordered-float")]
+pub mod ordered_float {
+    use crate::BorshSchema;
+
+    use super::{Declaration, Definition};
+    use crate::__private::maybestd::collections::BTreeMap;
+    use ::ordered_float::{NotNan, OrderedFloat};
+
+    impl<T: BorshSchema> BorshSchema for OrderedFloat<T> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            T::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            T::declaration()
+        }
+    }
+
+    impl<T: BorshSchema> BorshSchema for NotNan<T> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            T::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            T::declaration()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["half"]`.
+///
+/// Module defines [BorshSchema] implementation for [f16](::half::f16) and [bf16](::half::bf16).
+#[cfg(feature = "half")]
+pub mod half {
+    use crate::BorshSchema;
+
+    use super::{add_definition, Declaration, Definition};
+    use crate::__private::maybestd::collections::BTreeMap;
+
+    impl BorshSchema for ::half::f16 {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_definition(Self::declaration(), Definition::Primitive(2), definitions);
+        }
+
+        fn declaration() -> Declaration {
+            "f16".into()
+        }
+    }
+
+    impl BorshSchema for ::half::bf16 {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_definition(Self::declaration(), Definition::Primitive(2), definitions);
+        }
+
+        fn declaration() -> Declaration {
+            "bf16".into()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["bitflags"]`.
+///
+/// Module defines [declaration](bitflags::declaration) and
+/// [add_definitions_recursively](bitflags::add_definitions_recursively) functions for any
+/// [Flags](::bitflags::Flags) type, which is described the same way as its underlying integer.
+/// They are meant to be used with `#[borsh(schema(with_funcs(...)))]` field attribute.
+#[cfg(feature = "bitflags")]
+pub mod bitflags {
+    use crate::BorshSchema;
+
+    use super::{Declaration, Definition};
+    use crate::__private::maybestd::collections::BTreeMap;
+    use ::bitflags::Flags;
+
+    /// Returns declaration of the underlying integer of `F`.
+    pub fn declaration<F>() -> Declaration
+    where
+        F: Flags,
+        F::Bits: BorshSchema,
+    {
+        F::Bits::declaration()
+    }
+
+    /// Adds definitions of the underlying integer of `F`.
+    pub fn add_definitions_recursively<F>(definitions: &mut BTreeMap<Declaration, Definition>)
+    where
+        F: Flags,
+        F::Bits: BorshSchema,
+    {
+        F::Bits::add_definitions_recursively(definitions);
+    }
+}
+
+/// Module is available if borsh is built with `features = ["bitvec"]`.
+///
+/// Module defines [BorshSchema] implementation for [BitVec](::bitvec::vec::BitVec).
+///
+/// `BitVec` is described as a struct of its `u32` bit length and the packed bits. As the
+/// number of bytes is derived from the number of bits, the bits are described as an untagged
+/// sequence of bytes, `BitVecBytes`.
+#[cfg(feature = "bitvec")]
+pub mod bitvec {
+    use crate::BorshSchema;
+
+    use super::{add_definition, Declaration, Definition, Fields};
+    use crate::__private::maybestd::{collections::BTreeMap, vec};
+    use ::bitvec::{order::BitOrder, store::BitStore, vec::BitVec};
+
+    impl<T: BitStore, O: BitOrder> BorshSchema for BitVec<T, O> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            let bytes_declaration: Declaration = "BitVecBytes".into();
+            let bytes_definition = Definition::Sequence {
+                length_width: 0,
+                length_range: 0..=u64::from(u32::MAX).div_ceil(8),
+                elements: u8::declaration(),
+            };
+            add_definition(bytes_declaration.clone(), bytes_definition, definitions);
+            let fields = Fields::NamedFields(vec![
+                ("len".into(), u32::declaration()),
+                ("bytes".into(), bytes_declaration),
+            ]);
+            add_definition(
+                Self::declaration(),
+                Definition::Struct { fields },
+                definitions,
+            );
+            u32::add_definitions_recursively(definitions);
+            u8::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            "BitVec".into()
+        }
+    }
+}
+
+/// Adds definition of a map, which is serialized the same way as `BTreeMap<K, V>`.
+#[cfg(any(feature = "im", feature = "rpds"))]
+fn add_map_definitions<K: BorshSchema, V: BorshSchema>(
+    declaration: Declaration,
+    definitions: &mut BTreeMap<Declaration, Definition>,
+) {
+    let definition = Definition::Sequence {
+        length_width: Definition::DEFAULT_LENGTH_WIDTH,
+        length_range: Definition::DEFAULT_LENGTH_RANGE,
+        elements: <(K, V)>::declaration(),
+    };
+    add_definition(declaration, definition, definitions);
+    <(K, V)>::add_definitions_recursively(definitions);
+}
+
+/// Module is available if borsh is built with `features = ["im"]`.
+///
+/// Module defines [BorshSchema] implementation for [HashMap](::im::HashMap),
+/// [OrdMap](::im::OrdMap) and [Vector](::im::Vector) from [im](::im) crate.
+/// `Vector<T>` is described the same way as `Vec<T>`.
+#[cfg(feature = "im")]
+pub mod im {
+    use crate::BorshSchema;
+
+    use super::{add_map_definitions, Declaration, Definition};
+    use crate::__private::maybestd::{collections::BTreeMap, format};
+    use ::im::{HashMap, OrdMap, Vector};
+
+    impl<K: BorshSchema, V: BorshSchema, S> BorshSchema for HashMap<K, V, S> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_map_definitions::<K, V>(Self::declaration(), definitions);
+        }
+
+        fn declaration() -> Declaration {
+            format!(r#"im::HashMap<{}, {}>"#, K::declaration(), V::declaration())
+        }
+    }
+
+    impl<K: BorshSchema, V: BorshSchema> BorshSchema for OrdMap<K, V> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_map_definitions::<K, V>(Self::declaration(), definitions);
+        }
+
+        fn declaration() -> Declaration {
+            format!(r#"im::OrdMap<{}, {}>"#, K::declaration(), V::declaration())
+        }
+    }
+
+    impl<T: BorshSchema + Clone> BorshSchema for Vector<T> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            <[T]>::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            <[T]>::declaration()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["rpds"]`.
+///
+/// Module defines [BorshSchema] implementation for [HashTrieMap](::rpds::HashTrieMap),
+/// [RedBlackTreeMap](::rpds::RedBlackTreeMap) and [Vector](::rpds::Vector) from [rpds](::rpds) crate.
+/// `Vector<T>` is described the same way as `Vec<T>`.
+#[cfg(feature = "rpds")]
+pub mod rpds {
+    use core::hash::BuildHasher;
+
+    use crate::BorshSchema;
+
+    use super::{add_map_definitions, Declaration, Definition};
+    use crate::__private::maybestd::{collections::BTreeMap, format};
+    use ::archery::SharedPointerKind;
+    use ::rpds::{HashTrieMap, RedBlackTreeMap, Vector};
+
+    impl<K, V, P, H> BorshSchema for HashTrieMap<K, V, P, H>
+    where
+        K: BorshSchema,
+        V: BorshSchema,
+        P: SharedPointerKind,
+        H: BuildHasher,
+    {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_map_definitions::<K, V>(Self::declaration(), definitions);
+        }
+
+        fn declaration() -> Declaration {
+            format!(
+                r#"rpds::HashTrieMap<{}, {}>"#,
+                K::declaration(),
+                V::declaration()
+            )
+        }
+    }
+
+    impl<K, V, P> BorshSchema for RedBlackTreeMap<K, V, P>
+    where
+        K: BorshSchema,
+        V: BorshSchema,
+        P: SharedPointerKind,
+    {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            add_map_definitions::<K, V>(Self::declaration(), definitions);
+        }
+
+        fn declaration() -> Declaration {
+            format!(
+                r#"rpds::RedBlackTreeMap<{}, {}>"#,
+                K::declaration(),
+                V::declaration()
+            )
+        }
+    }
+
+    impl<T: BorshSchema, P: SharedPointerKind> BorshSchema for Vector<T, P> {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            <[T]>::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            <[T]>::declaration()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["smol_str"]`.
+///
+/// Module defines [BorshSchema] implementation for [SmolStr](::smol_str::SmolStr),
+/// which is described the same way as `String`.
+#[cfg(feature = "smol_str")]
+pub mod smol_str {
+    use crate::BorshSchema;
+
+    use super::{Declaration, Definition};
+    use crate::__private::maybestd::{collections::BTreeMap, string::String};
+    use ::smol_str::SmolStr;
+
+    impl BorshSchema for SmolStr {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            String::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            String::declaration()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["compact_str"]`.
+///
+/// Module defines [BorshSchema] implementation for [CompactString](::compact_str::CompactString),
+/// which is described the same way as `String`.
+#[cfg(feature = "compact_str")]
+pub mod compact_str {
+    use crate::BorshSchema;
+
+    use super::{Declaration, Definition};
+    use crate::__private::maybestd::{collections::BTreeMap, string::String};
+    use ::compact_str::CompactString;
+
+    impl BorshSchema for CompactString {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            String::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            String::declaration()
+        }
+    }
+}
+
+impl BorshSchema for core::ops::RangeFull {
+    #[inline]
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let fields = Fields::Empty;
+        let def = Definition::Struct { fields };
+        add_definition(Self::declaration(), def, definitions);
+    }
+    #[inline]
+    fn declaration() -> Declaration {
+        "RangeFull".into()
+    }
+}
+
+macro_rules! impl_for_range {
+    ($type:ident, $($name:ident),*) => {
+        impl<T: BorshSchema> BorshSchema for core::ops::$type<T> {
+            fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+                let decl = T::declaration();
+                let fields = Fields::NamedFields(vec![$(
+                    (FieldName::from(stringify!($name)), decl.clone())
+                ),*]);
+                let def = Definition::Struct { fields };
+                add_definition(Self::declaration(), def, definitions);
+                T::add_definitions_recursively(definitions);
+            }
+            fn declaration() -> Declaration {
+                format!("{}<{}>", stringify!($type), T::declaration())
+            }
+        }
+    };
+}
+
+impl_for_range!(Range, start, end);
+impl_for_range!(RangeInclusive, start, end);
+impl_for_range!(RangeFrom, start);
+impl_for_range!(RangeTo, end);
+impl_for_range!(RangeToInclusive, end);
+
+impl<T, const N: usize> BorshSchema for [T; N]
+where
+    T: BorshSchema,
+{
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        use core::convert::TryFrom;
+        let length = u64::try_from(N).unwrap();
+        let definition = Definition::Sequence {
+            length_width: Definition::ARRAY_LENGTH_WIDTH,
+            length_range: length..=length,
+            elements: T::declaration(),
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        T::add_definitions_recursively(definitions);
+    }
+    fn declaration() -> Declaration {
+        format!(r#"[{}; {}]"#, T::declaration(), N)
+    }
+}
+
+impl<T> BorshSchema for Option<T>
+where
+    T: BorshSchema,
+{
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let definition = Definition::Enum {
+            tag_width: 1,
+            variants: vec![
+                (0u8 as i64, "None".to_string(), <()>::declaration()),
+                (1u8 as i64, "Some".to_string(), T::declaration()),
+            ],
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        T::add_definitions_recursively(definitions);
+        <()>::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        format!(r#"Option<{}>"#, T::declaration())
+    }
+}
+
+impl<T, E> BorshSchema for core::result::Result<T, E>
+where
+    T: BorshSchema,
+    E: BorshSchema,
+{
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let definition = Definition::Enum {
+            tag_width: 1,
+            variants: vec![
+                (1u8 as i64, "Ok".to_string(), T::declaration()),
+                (0u8 as i64, "Err".to_string(), E::declaration()),
+            ],
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        T::add_definitions_recursively(definitions);
+        E::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        format!(r#"Result<{}, {}>"#, T::declaration(), E::declaration())
+    }
+}
+
+macro_rules! impl_for_vec_like_collection {
+    ($type: ident) => {
+        impl<T> BorshSchema for $type<T>
+        where
+            T: BorshSchema,
+        {
+            fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+                let definition = Definition::Sequence {
+                    length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                    length_range: Definition::DEFAULT_LENGTH_RANGE,
+                    elements: T::declaration(),
+                };
+                add_definition(Self::declaration(), definition, definitions);
+                T::add_definitions_recursively(definitions);
+            }
+
+            fn declaration() -> Declaration {
+                format!(r#"{}<{}>"#, stringify!($type), T::declaration())
+            }
+        }
+    };
+}
+
+impl_for_vec_like_collection!(Vec);
+impl_for_vec_like_collection!(VecDeque);
+impl_for_vec_like_collection!(LinkedList);
+impl_for_vec_like_collection!(BinaryHeap);
+
+impl<T> BorshSchema for [T]
+where
+    T: BorshSchema,
+{
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let definition = Definition::Sequence {
+            length_width: Definition::DEFAULT_LENGTH_WIDTH,
+            length_range: Definition::DEFAULT_LENGTH_RANGE,
+            elements: T::declaration(),
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        T::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        format!(r#"Vec<{}>"#, T::declaration())
+    }
+}
+
+/// Defines [BorshSchema] implementation for `HashMap`/`HashSet` types, which are
+/// expected to be in scope of the invoking module under the given names,
+/// with given names of the types in declarations.
+#[cfg(hash_collections)]
+macro_rules! impl_for_hash_collections {
+    ($map: ident => $map_name: literal, $set: ident => $set_name: literal) => {
+        use crate::BorshSchema;
+
+        use super::{add_definition, Declaration, Definition};
+        use crate::__private::maybestd::{collections::BTreeMap, format};
+
+        // S is not serialized, so we ignore it in schema too
+        // forcing S to be BorshSchema forces to define Definition
+        // which must be empty, but if not - it will fail
+        // so better to ignore it
+        impl<K, V, S> BorshSchema for $map<K, V, S>
+        where
+            K: BorshSchema,
+            V: BorshSchema,
+        {
+            fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+                let definition = Definition::Sequence {
+                    length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                    length_range: Definition::DEFAULT_LENGTH_RANGE,
+                    elements: <(K, V)>::declaration(),
+                };
+                add_definition(Self::declaration(), definition, definitions);
+                <(K, V)>::add_definitions_recursively(definitions);
+            }
+
+            fn declaration() -> Declaration {
+                format!(
+                    r#"{}<{}, {}>"#,
+                    $map_name,
+                    K::declaration(),
+                    V::declaration()
+                )
+            }
+        }
+
+        impl<T, S> BorshSchema for $set<T, S>
+        where
+            T: BorshSchema,
+        {
+            fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+                let definition = Definition::Sequence {
+                    length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                    length_range: Definition::DEFAULT_LENGTH_RANGE,
+                    elements: <T>::declaration(),
+                };
+                add_definition(Self::declaration(), definition, definitions);
+                <T>::add_definitions_recursively(definitions);
+            }
+
+            fn declaration() -> Declaration {
+                format!(r#"{}<{}>"#, $set_name, T::declaration())
+            }
+        }
+    };
+}
+
+/// Module is available if borsh is built with `features = ["std"]` or `features = ["hashbrown"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// [HashMap](std::collections::HashMap)/[HashSet](std::collections::HashSet).
+#[cfg(hash_collections)]
+pub mod hashes {
+    use crate::__private::maybestd::collections::{HashMap, HashSet};
+
+    impl_for_hash_collections!(HashMap => "HashMap", HashSet => "HashSet");
+}
+
+/// Module is available if borsh is built with `features = ["std", "hashbrown"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// [HashMap](::hashbrown::HashMap)/[HashSet](::hashbrown::HashSet) from [hashbrown](::hashbrown) crate.
+/// Their declarations (`hashbrown::HashMap<K, V>`/`hashbrown::HashSet<T>`) are distinct from
+/// the ones of `std` types, which are declared as `HashMap<K, V>`/`HashSet<T>`.
+/// Without `std` hashbrown types are covered by [hashes] module and are declared the same way as `std` ones.
+#[cfg(all(feature = "std", feature = "hashbrown"))]
+pub mod hashbrown {
+    use ::hashbrown::{HashMap, HashSet};
+
+    impl_for_hash_collections!(HashMap => "hashbrown::HashMap", HashSet => "hashbrown::HashSet");
+}
+
+impl<K, V> BorshSchema for BTreeMap<K, V>
+where
+    K: BorshSchema,
+    V: BorshSchema,
+{
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let definition = Definition::Sequence {
+            length_width: Definition::DEFAULT_LENGTH_WIDTH,
+            length_range: Definition::DEFAULT_LENGTH_RANGE,
+            elements: <(K, V)>::declaration(),
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        <(K, V)>::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        format!(r#"BTreeMap<{}, {}>"#, K::declaration(), V::declaration())
+    }
+}
+
+impl<T> BorshSchema for BTreeSet<T>
+where
+    T: BorshSchema,
+{
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let definition = Definition::Sequence {
+            length_width: Definition::DEFAULT_LENGTH_WIDTH,
+            length_range: Definition::DEFAULT_LENGTH_RANGE,
+            elements: <T>::declaration(),
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        <T>::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        format!(r#"BTreeSet<{}>"#, T::declaration())
     }
 }
 
@@ -903,3 +1718,246 @@ impl BorshSchema for core::net::IpAddr {
         ip_addr_std_derive_impl::IpAddr::declaration()
     }
 }
+
+impl BorshSchema for core::time::Duration {
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let fields = Fields::NamedFields(vec![
+            ("secs".to_string(), u64::declaration()),
+            ("nanos".to_string(), u32::declaration()),
+        ]);
+        let definition = Definition::Struct { fields };
+        add_definition(Self::declaration(), definition, definitions);
+        u64::add_definitions_recursively(definitions);
+        u32::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        "Duration".into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl BorshSchema for std::time::SystemTime {
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let fields = Fields::NamedFields(vec![
+            ("secs".to_string(), i64::declaration()),
+            ("nanos".to_string(), u32::declaration()),
+        ]);
+        let definition = Definition::Struct { fields };
+        add_definition(Self::declaration(), definition, definitions);
+        i64::add_definitions_recursively(definitions);
+        u32::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        "SystemTime".into()
+    }
+}
+
+macro_rules! impl_for_transparent_wrapper {
+    ($($module:ident::$type:ident),+) => {
+    $(
+        impl<T: BorshSchema> BorshSchema for core::$module::$type<T> {
+            fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+                T::add_definitions_recursively(definitions);
+            }
+
+            fn declaration() -> Declaration {
+                T::declaration()
+            }
+        }
+    )+
+    };
+}
+
+impl_for_transparent_wrapper!(num::Wrapping, num::Saturating, cmp::Reverse);
+
+impl BorshSchema for CString {
+    #[inline]
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        core::ffi::CStr::add_definitions_recursively(definitions);
+    }
+    #[inline]
+    fn declaration() -> Declaration {
+        core::ffi::CStr::declaration()
+    }
+}
+
+impl BorshSchema for core::ffi::CStr {
+    #[inline]
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let definition = Definition::Sequence {
+            length_width: Definition::DEFAULT_LENGTH_WIDTH,
+            length_range: Definition::DEFAULT_LENGTH_RANGE,
+            elements: u8::declaration(),
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        u8::add_definitions_recursively(definitions);
+    }
+    #[inline]
+    fn declaration() -> Declaration {
+        "CString".into()
+    }
+}
+
+impl<T: BorshSchema> BorshSchema for core::ops::Bound<T> {
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let definition = Definition::Enum {
+            tag_width: 1,
+            variants: vec![
+                (0u8 as i64, "Included".to_string(), T::declaration()),
+                (1u8 as i64, "Excluded".to_string(), T::declaration()),
+                (2u8 as i64, "Unbounded".to_string(), <()>::declaration()),
+            ],
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        T::add_definitions_recursively(definitions);
+        <()>::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        format!(r#"Bound<{}>"#, T::declaration())
+    }
+}
+
+impl BorshSchema for core::cmp::Ordering {
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let definition = Definition::Enum {
+            tag_width: 1,
+            variants: vec![
+                (0u8 as i64, "Less".to_string(), <()>::declaration()),
+                (1u8 as i64, "Equal".to_string(), <()>::declaration()),
+                (2u8 as i64, "Greater".to_string(), <()>::declaration()),
+            ],
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        <()>::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        "Ordering".into()
+    }
+}
+
+macro_rules! impl_for_atomic {
+    ($($type:ident($inner:ty) : $width:literal),+) => {
+    $(
+        #[cfg(target_has_atomic = $width)]
+        impl BorshSchema for core::sync::atomic::$type {
+            fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+                <$inner>::add_definitions_recursively(definitions);
+            }
+
+            fn declaration() -> Declaration {
+                <$inner>::declaration()
+            }
+        }
+    )+
+    };
+}
+
+impl_for_atomic!(
+    AtomicBool(bool): "8",
+    AtomicI8(i8): "8",
+    AtomicI16(i16): "16",
+    AtomicI32(i32): "32",
+    AtomicI64(i64): "64",
+    AtomicIsize(isize): "ptr",
+    AtomicU8(u8): "8",
+    AtomicU16(u16): "16",
+    AtomicU32(u32): "32",
+    AtomicU64(u64): "64",
+    AtomicUsize(usize): "ptr"
+);
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+macro_rules! impl_for_unix_timestamp {
+    ($($type:ty => $name:expr),+) => {
+    $(
+        impl BorshSchema for $type {
+            fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+                let fields = Fields::NamedFields(vec![
+                    ("secs".to_string(), i64::declaration()),
+                    ("nanos".to_string(), u32::declaration()),
+                ]);
+                let definition = Definition::Struct { fields };
+                add_definition(Self::declaration(), definition, definitions);
+                i64::add_definitions_recursively(definitions);
+                u32::add_definitions_recursively(definitions);
+            }
+
+            fn declaration() -> Declaration {
+                $name.into()
+            }
+        }
+    )+
+    };
+}
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+macro_rules! impl_for_unix_date {
+    ($($type:ty => $name:expr),+) => {
+    $(
+        impl BorshSchema for $type {
+            fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+                let fields = Fields::NamedFields(vec![("days".to_string(), i32::declaration())]);
+                let definition = Definition::Struct { fields };
+                add_definition(Self::declaration(), definition, definitions);
+                i32::add_definitions_recursively(definitions);
+            }
+
+            fn declaration() -> Declaration {
+                $name.into()
+            }
+        }
+    )+
+    };
+}
+
+/// Module is available if borsh is built with `features = ["chrono"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// some types from [chrono](::chrono) crate.
+#[cfg(feature = "chrono")]
+pub mod chrono {
+    use super::{add_definition, Declaration, Definition, Fields};
+    use crate::__private::maybestd::{collections::BTreeMap, string::ToString, vec};
+    use crate::BorshSchema;
+    use ::chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+    impl_for_unix_timestamp!(DateTime<Utc> => "DateTime<Utc>", NaiveDateTime => "NaiveDateTime");
+    impl_for_unix_date!(NaiveDate => "NaiveDate");
+}
+
+/// Module is available if borsh is built with `features = ["time"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// some types from [time](::time) crate.
+#[cfg(feature = "time")]
+pub mod time {
+    use super::{add_definition, Declaration, Definition, Fields};
+    use crate::__private::maybestd::{collections::BTreeMap, string::ToString, vec};
+    use crate::BorshSchema;
+    use ::time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+    impl_for_unix_timestamp!(
+        OffsetDateTime => "OffsetDateTime",
+        PrimitiveDateTime => "PrimitiveDateTime"
+    );
+    impl_for_unix_date!(Date => "Date");
+}
+
+/// Module is available if borsh is built with `features = ["jiff"]`.
+///
+/// Module defines [BorshSchema] implementation for
+/// some types from [jiff](::jiff) crate.
+#[cfg(feature = "jiff")]
+pub mod jiff {
+    use super::{add_definition, Declaration, Definition, Fields};
+    use crate::__private::maybestd::{collections::BTreeMap, string::ToString, vec};
+    use crate::BorshSchema;
+    use ::jiff::{civil::Date, Timestamp};
+
+    impl_for_unix_timestamp!(Timestamp => "Timestamp");
+    impl_for_unix_date!(Date => "Date");
+}
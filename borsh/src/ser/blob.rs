@@ -0,0 +1,43 @@
+use core::convert::TryFrom;
+
+use crate::io::{Error, ErrorKind, Read, Result, Write};
+
+const ERROR_BLOB_SOURCE_TOO_SHORT: &str = "Blob source ended before the declared length";
+
+/// Size of the stack buffer bytes are copied through.
+const COPY_BUFFER_SIZE: usize = 8 * 1024;
+
+/// Writes `len` bytes read from `source` to `writer` as a byte blob, encoded as `Vec<u8>`,
+/// without collecting them in memory.
+///
+/// Fails with [ErrorKind::InvalidData], if `len` doesn't fit `u32` or `source` ends early.
+/// It may be used in functions passed to `#[borsh(serialize_with = ...)]`.
+///
+/// ```
+/// let mut bytes = Vec::new();
+/// borsh::ser::write_blob(&mut &[1u8, 2, 3][..], 3, &mut bytes).unwrap();
+/// assert_eq!(bytes, borsh::to_vec(&vec![1u8, 2, 3]).unwrap());
+/// ```
+pub fn write_blob<R: Read + ?Sized, W: Write + ?Sized>(
+    source: &mut R,
+    len: usize,
+    writer: &mut W,
+) -> Result<()> {
+    let len = u32::try_from(len).map_err(|_| ErrorKind::InvalidData)?;
+    writer.write_all(&len.to_le_bytes())?;
+    let mut buf = [0u8; COPY_BUFFER_SIZE];
+    let mut remaining = len as usize;
+    while remaining > 0 {
+        let chunk = remaining.min(COPY_BUFFER_SIZE);
+        source.read_exact(&mut buf[..chunk]).map_err(|err| {
+            if err.kind() == ErrorKind::UnexpectedEof {
+                Error::new(ErrorKind::InvalidData, ERROR_BLOB_SOURCE_TOO_SHORT)
+            } else {
+                err
+            }
+        })?;
+        writer.write_all(&buf[..chunk])?;
+        remaining -= chunk;
+    }
+    Ok(())
+}
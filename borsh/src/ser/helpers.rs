@@ -1,10 +1,17 @@
+#[cfg(feature = "bytes")]
+use crate::__private::maybestd::format;
 use crate::__private::maybestd::vec::Vec;
+#[cfg(feature = "bytes")]
+use crate::io::Error;
 use crate::io::{ErrorKind, Result, Write};
 use crate::BorshSerialize;
 
 pub(super) const DEFAULT_SERIALIZER_CAPACITY: usize = 1024;
 
 /// Serialize an object into a vector of bytes.
+///
+/// The vector is allocated with capacity of [BorshSerialize::serialized_size_hint] of the object,
+/// if there is one, so that it isn't reallocated.
 /// # Example
 ///
 /// ```
@@ -14,7 +21,10 @@ pub fn to_vec<T>(value: &T) -> Result<Vec<u8>>
 where
     T: BorshSerialize + ?Sized,
 {
-    let mut result = Vec::with_capacity(DEFAULT_SERIALIZER_CAPACITY);
+    let capacity = value
+        .serialized_size_hint()
+        .unwrap_or(DEFAULT_SERIALIZER_CAPACITY);
+    let mut result = Vec::with_capacity(capacity);
     value.serialize(&mut result)?;
     Ok(result)
 }
@@ -35,6 +45,150 @@ where
     value.serialize(&mut writer)
 }
 
+/// Serializes an object into the beginning of `buf` without allocation,
+/// and returns the number of bytes written.
+///
+/// If `buf` is too small, fails with [ErrorKind::WriteZero] error, leaving contents of `buf`
+/// unspecified. The error carries no message, so that it isn't allocated either, and the required
+/// size can be obtained with [BorshSerialize::serialized_size].
+/// # Example
+///
+/// ```
+/// use borsh::BorshSerialize;
+///
+/// let mut buf = [0u8; 16];
+/// let len = borsh::to_slice(&(1u8, 2u32), &mut buf).unwrap();
+/// assert_eq!(&buf[..len], &[1, 2, 0, 0, 0]);
+///
+/// let err = borsh::to_slice(&u64::MAX, &mut buf[..4]).unwrap_err();
+/// assert_eq!(err.kind(), borsh::io::ErrorKind::WriteZero);
+/// assert_eq!(u64::MAX.serialized_size().unwrap(), 8);
+/// ```
+pub fn to_slice<T>(value: &T, buf: &mut [u8]) -> Result<usize>
+where
+    T: BorshSerialize + ?Sized,
+{
+    let available = buf.len();
+    let mut writer = SliceWriter {
+        buf,
+        overflowed: false,
+    };
+    match value.serialize(&mut writer) {
+        Ok(()) => Ok(available - writer.buf.len()),
+        Err(_) if writer.overflowed => Err(ErrorKind::WriteZero.into()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Serializes an object into an array of `N` bytes without allocation.
+///
+/// Fails, if the object isn't serialized into exactly `N` bytes: with [ErrorKind::WriteZero]
+/// error, if it takes more, as [to_slice] does, and with [ErrorKind::InvalidData] error, if it
+/// takes less. Neither error carries a message, so that it isn't allocated.
+/// # Example
+///
+/// ```
+/// assert_eq!(borsh::to_array::<4>(&7u32).unwrap(), [7, 0, 0, 0]);
+/// assert!(borsh::to_array::<8>(&7u32).is_err());
+/// assert!(borsh::to_array::<2>(&7u32).is_err());
+/// ```
+pub fn to_array<const N: usize>(value: &(impl BorshSerialize + ?Sized)) -> Result<[u8; N]> {
+    let mut array = [0u8; N];
+    let len = to_slice(value, &mut array)?;
+    if len != N {
+        return Err(ErrorKind::InvalidData.into());
+    }
+    Ok(array)
+}
+
+/// Serializes an object into a [bytes::BufMut], such as `BytesMut` or a chain of buffers.
+///
+/// If `buf` has less than [BorshSerialize::serialized_size_hint] bytes of capacity left, fails with
+/// [ErrorKind::WriteZero] error without writing anything. An object without a size hint
+/// is written until `buf` runs out of capacity, failing with the same error kind then.
+/// # Example
+///
+/// ```
+/// use bytes::BytesMut;
+///
+/// let mut buf = BytesMut::new();
+/// borsh::to_buf_mut(&(1u8, String::from("borsh")), &mut buf).unwrap();
+/// assert_eq!(&buf[..], &borsh::to_vec(&(1u8, String::from("borsh"))).unwrap()[..]);
+///
+/// let mut array = [0u8; 4];
+/// let err = borsh::to_buf_mut(&u64::MAX, &mut array[..]).unwrap_err();
+/// assert_eq!(err.to_string(), "Buffer too small: 8 bytes required, 4 available");
+/// ```
+#[cfg(feature = "bytes")]
+pub fn to_buf_mut<T, B>(value: &T, buf: B) -> Result<()>
+where
+    T: BorshSerialize + ?Sized,
+    B: bytes::BufMut,
+{
+    if let Some(required) = value.serialized_size_hint() {
+        let available = buf.remaining_mut();
+        if required > available {
+            return Err(Error::new(
+                ErrorKind::WriteZero,
+                format!(
+                    "Buffer too small: {} bytes required, {} available",
+                    required, available
+                ),
+            ));
+        }
+    }
+    value.serialize(&mut BufMutWriter(buf))
+}
+
+/// Writer into a [bytes::BufMut], which doesn't write past its capacity.
+#[cfg(feature = "bytes")]
+struct BufMutWriter<B>(B);
+
+#[cfg(feature = "bytes")]
+impl<B: bytes::BufMut> Write for BufMutWriter<B> {
+    #[inline]
+    fn write(&mut self, data: &[u8]) -> Result<usize> {
+        let len = data.len().min(self.0.remaining_mut());
+        self.0.put_slice(&data[..len]);
+        Ok(len)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Writer into a slice, which remembers whether it ran out of space.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    overflowed: bool,
+}
+
+impl Write for SliceWriter<'_> {
+    #[inline]
+    fn write(&mut self, data: &[u8]) -> Result<usize> {
+        if data.len() > self.buf.len() {
+            self.overflowed = true;
+            return Err(ErrorKind::WriteZero.into());
+        }
+        let (written, rest) = core::mem::take(&mut self.buf).split_at_mut(data.len());
+        written.copy_from_slice(data);
+        self.buf = rest;
+        Ok(data.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, data: &[u8]) -> Result<()> {
+        self.write(data).map(|_| ())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
 /// Serializes an object without allocation to compute and return its length
 /// # Example
 ///
@@ -60,29 +214,33 @@ pub fn object_length<T>(value: &T) -> Result<usize>
 where
     T: BorshSerialize + ?Sized,
 {
-    // copy-paste of solution provided by @matklad
-    // in https://github.com/near/borsh-rs/issues/23#issuecomment-816633365
-    struct LengthWriter {
-        len: usize,
-    }
-    impl Write for LengthWriter {
-        #[inline]
-        fn write(&mut self, buf: &[u8]) -> Result<usize> {
-            let res = self.len.checked_add(buf.len());
-            self.len = match res {
-                Some(res) => res,
-                None => {
-                    return Err(ErrorKind::OutOfMemory.into());
-                }
-            };
-            Ok(buf.len())
-        }
-        #[inline]
-        fn flush(&mut self) -> Result<()> {
-            Ok(())
-        }
-    }
     let mut w = LengthWriter { len: 0 };
     value.serialize(&mut w)?;
     Ok(w.len)
 }
+
+/// Writer, which only counts bytes written into it.
+// copy-paste of solution provided by @matklad
+// in https://github.com/near/borsh-rs/issues/23#issuecomment-816633365
+#[doc(hidden)]
+pub struct LengthWriter {
+    len: usize,
+}
+
+impl Write for LengthWriter {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let res = self.len.checked_add(buf.len());
+        self.len = match res {
+            Some(res) => res,
+            None => {
+                return Err(ErrorKind::OutOfMemory.into());
+            }
+        };
+        Ok(buf.len())
+    }
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -4,14 +4,20 @@ use core::marker::PhantomData;
 use crate::__private::maybestd::{
     borrow::{Cow, ToOwned},
     boxed::Box,
-    collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    ffi::CString,
     string::String,
     vec::Vec,
 };
 use crate::error::check_zst;
 use crate::io::{Error, ErrorKind, Result, Write};
 
+mod blob;
 pub(crate) mod helpers;
+mod seq;
+
+pub use blob::write_blob;
+pub use seq::SeqWriter;
 
 const FLOAT_NAN_ERR: &str = "For portability reasons we do not allow to serialize NaNs.";
 
@@ -51,6 +57,53 @@ const FLOAT_NAN_ERR: &str = "For portability reasons we do not allow to serializ
 pub trait BorshSerialize {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()>;
 
+    /// Returns the number of bytes, which [serialize](BorshSerialize::serialize) writes for `self`,
+    /// if it's computed without serializing the value.
+    ///
+    /// [to_vec](crate::to_vec) uses it to allocate the output buffer once, with exact capacity.
+    /// Derived and built-in implementations compute it in O(1) for values of fixed size
+    /// and for collections of such values, and return `None`, if some nested value has no hint,
+    /// e.g. a field with `#[borsh(serialize_with = ...)]` attribute.
+    /// Defaults to `None`, so manual implementations should override it, if the size is cheap to compute.
+    ///
+    /// ```
+    /// use borsh::BorshSerialize;
+    ///
+    /// assert_eq!(vec![1u64, 2, 3].serialized_size_hint(), Some(4 + 3 * 8));
+    /// assert_eq!("hello".serialized_size_hint(), Some(4 + 5));
+    /// ```
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the number of bytes, which [serialize](BorshSerialize::serialize) writes for `self`.
+    ///
+    /// Returns [BorshSerialize::serialized_size_hint], if there is one, and otherwise serializes `self`
+    /// into a writer, which only counts bytes, failing if `self` fails to serialize.
+    ///
+    /// ```
+    /// use borsh::BorshSerialize;
+    ///
+    /// assert_eq!(vec![1u64, 2, 3].serialized_size().unwrap(), 4 + 3 * 8);
+    /// ```
+    fn serialized_size(&self) -> Result<usize> {
+        match self.serialized_size_hint() {
+            Some(size) => Ok(size),
+            None => helpers::object_length(self),
+        }
+    }
+
+    /// Returns the serialized size, which is the same for all values of the type, if there is one.
+    #[inline]
+    #[doc(hidden)]
+    fn fixed_serialized_size() -> Option<usize>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
     #[inline]
     #[doc(hidden)]
     fn u8_slice(slice: &[Self]) -> Option<&[u8]>
@@ -62,18 +115,67 @@ pub trait BorshSerialize {
     }
 }
 
+/// Size of `u32` length prefix of sequences, strings and maps.
+const LENGTH_PREFIX_SIZE: usize = core::mem::size_of::<u32>();
+
+/// Implements [BorshSerialize::serialized_size_hint] and [BorshSerialize::fixed_serialized_size]
+/// for a type, all values of which are serialized into `$size` bytes.
+macro_rules! fixed_serialized_size {
+    ($size: expr) => {
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            Some($size)
+        }
+
+        #[inline]
+        fn fixed_serialized_size() -> Option<usize> {
+            Some($size)
+        }
+    };
+}
+
+/// Returns the total serialized size of `len` elements, which `items` yields,
+/// without iterating them if elements are of fixed size, or `None`, if some element has no hint
+/// or the total overflows `usize`.
+#[inline]
+fn serialized_size_of_items<'a, T, I>(len: usize, items: I) -> Option<usize>
+where
+    T: BorshSerialize + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    match T::fixed_serialized_size() {
+        Some(size) => len.checked_mul(size),
+        None => items.into_iter().try_fold(0usize, |total, item| {
+            total.checked_add(item.serialized_size_hint()?)
+        }),
+    }
+}
+
 impl BorshSerialize for u8 {
     #[inline]
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         writer.write_all(core::slice::from_ref(self))
     }
 
+    fixed_serialized_size!(1);
+
     #[inline]
     fn u8_slice(slice: &[Self]) -> Option<&[u8]> {
         Some(slice)
     }
 }
 
+/// Returns in-memory representation of a slice of primitive numbers.
+///
+/// # Safety
+///
+/// `T` must not have padding bytes.
+#[cfg(target_endian = "little")]
+#[inline]
+unsafe fn primitives_as_bytes<T: Copy>(slice: &[T]) -> &[u8] {
+    core::slice::from_raw_parts(slice.as_ptr().cast(), core::mem::size_of_val(slice))
+}
+
 macro_rules! impl_for_integer {
     ($type: ident) => {
         impl BorshSerialize for $type {
@@ -82,6 +184,16 @@ macro_rules! impl_for_integer {
                 let bytes = self.to_le_bytes();
                 writer.write_all(&bytes)
             }
+
+            fixed_serialized_size!(core::mem::size_of::<$type>());
+
+            #[inline]
+            #[cfg(target_endian = "little")]
+            fn u8_slice(slice: &[Self]) -> Option<&[u8]> {
+                // SAFETY: integers don't have padding bytes, and are serialized
+                // as their in-memory representation on little-endian targets.
+                Some(unsafe { primitives_as_bytes(slice) })
+            }
         }
     };
 }
@@ -103,6 +215,8 @@ macro_rules! impl_for_nonzero_integer {
             fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
                 BorshSerialize::serialize(&self.get(), writer)
             }
+
+            fixed_serialized_size!(core::mem::size_of::<$type>());
         }
     };
 }
@@ -123,12 +237,16 @@ impl BorshSerialize for isize {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         BorshSerialize::serialize(&(*self as i64), writer)
     }
+
+    fixed_serialized_size!(core::mem::size_of::<i64>());
 }
 
 impl BorshSerialize for usize {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         BorshSerialize::serialize(&(*self as u64), writer)
     }
+
+    fixed_serialized_size!(core::mem::size_of::<u64>());
 }
 
 // Note NaNs have a portability issue. Specifically, signalling NaNs on MIPS are quiet NaNs on x86,
@@ -143,6 +261,21 @@ macro_rules! impl_for_float {
                 }
                 writer.write_all(&self.to_bits().to_le_bytes())
             }
+
+            fixed_serialized_size!(core::mem::size_of::<$type>());
+
+            #[inline]
+            #[cfg(target_endian = "little")]
+            fn u8_slice(slice: &[Self]) -> Option<&[u8]> {
+                // If there is a NaN, the slice is serialized element by element,
+                // which reports the error. The check doesn't short-circuit, so that it's vectorized.
+                if slice.iter().fold(false, |nan, x| nan | x.is_nan()) {
+                    return None;
+                }
+                // SAFETY: floats don't have padding bytes, and are serialized
+                // as their in-memory representation on little-endian targets.
+                Some(unsafe { primitives_as_bytes(slice) })
+            }
         }
     };
 }
@@ -155,6 +288,17 @@ impl BorshSerialize for bool {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         (u8::from(*self)).serialize(writer)
     }
+
+    fixed_serialized_size!(1);
+}
+
+impl BorshSerialize for char {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        u32::from(*self).serialize(writer)
+    }
+
+    fixed_serialized_size!(core::mem::size_of::<u32>());
 }
 
 impl<T> BorshSerialize for Option<T>
@@ -171,6 +315,11 @@ where
             }
         }
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        Some(1 + self.as_ref().map_or(Some(0), T::serialized_size_hint)?)
+    }
 }
 
 impl<T, E> BorshSerialize for core::result::Result<T, E>
@@ -191,6 +340,16 @@ where
             }
         }
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        Some(
+            1 + match self {
+                Err(e) => e.serialized_size_hint()?,
+                Ok(v) => v.serialized_size_hint()?,
+            },
+        )
+    }
 }
 
 impl BorshSerialize for str {
@@ -198,6 +357,11 @@ impl BorshSerialize for str {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         self.as_bytes().serialize(writer)
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        Some(LENGTH_PREFIX_SIZE + self.len())
+    }
 }
 
 impl BorshSerialize for String {
@@ -205,6 +369,11 @@ impl BorshSerialize for String {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         self.as_bytes().serialize(writer)
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        Some(LENGTH_PREFIX_SIZE + self.len())
+    }
 }
 
 /// Module is available if borsh is built with `features = ["ascii"]`.
@@ -221,6 +390,8 @@ pub mod ascii {
         fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
             self.as_byte().serialize(writer)
         }
+
+        fixed_serialized_size!(1);
     }
 
     impl BorshSerialize for ascii::AsciiStr {
@@ -228,6 +399,11 @@ pub mod ascii {
         fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
             self.as_bytes().serialize(writer)
         }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            Some(super::LENGTH_PREFIX_SIZE + self.len())
+        }
     }
 
     impl BorshSerialize for ascii::AsciiString {
@@ -235,6 +411,11 @@ pub mod ascii {
         fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
             self.as_bytes().serialize(writer)
         }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            Some(super::LENGTH_PREFIX_SIZE + self.len())
+        }
     }
 }
 
@@ -262,6 +443,11 @@ where
         )?;
         serialize_slice(self, writer)
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        LENGTH_PREFIX_SIZE.checked_add(serialized_size_of_items(self.len(), self)?)
+    }
 }
 
 impl<T: BorshSerialize + ?Sized> BorshSerialize for &T {
@@ -269,6 +455,11 @@ impl<T: BorshSerialize + ?Sized> BorshSerialize for &T {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         (*self).serialize(writer)
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        (*self).serialized_size_hint()
+    }
 }
 
 impl<T> BorshSerialize for Cow<'_, T>
@@ -279,6 +470,11 @@ where
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         self.as_ref().serialize(writer)
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        self.as_ref().serialized_size_hint()
+    }
 }
 
 impl<T> BorshSerialize for Vec<T>
@@ -291,6 +487,11 @@ where
 
         self.as_slice().serialize(writer)
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        self.as_slice().serialized_size_hint()
+    }
 }
 
 #[cfg(feature = "bytes")]
@@ -299,6 +500,11 @@ impl BorshSerialize for bytes::Bytes {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         self.as_ref().serialize(writer)
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        Some(LENGTH_PREFIX_SIZE + self.len())
+    }
 }
 
 #[cfg(feature = "bytes")]
@@ -307,6 +513,11 @@ impl BorshSerialize for bytes::BytesMut {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         self.as_ref().serialize(writer)
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        Some(LENGTH_PREFIX_SIZE + self.len())
+    }
 }
 
 #[cfg(feature = "bson")]
@@ -315,6 +526,8 @@ impl BorshSerialize for bson::oid::ObjectId {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         self.bytes().serialize(writer)
     }
+
+    fixed_serialized_size!(12);
 }
 
 #[cfg(feature = "indexmap")]
@@ -340,6 +553,11 @@ where
 
         Ok(())
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        LENGTH_PREFIX_SIZE.checked_add(serialized_size_of_items(self.len(), self)?)
+    }
 }
 
 #[cfg(feature = "indexmap")]
@@ -367,6 +585,13 @@ where
 
         Ok(())
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        LENGTH_PREFIX_SIZE
+            .checked_add(serialized_size_of_items(self.len(), self.keys())?)?
+            .checked_add(serialized_size_of_items(self.len(), self.values())?)
+    }
 }
 
 #[cfg(feature = "uuid")]
@@ -376,144 +601,993 @@ impl BorshSerialize for uuid::Uuid {
 
         Ok(())
     }
+
+    fixed_serialized_size!(16);
 }
 
-impl<T> BorshSerialize for VecDeque<T>
-where
-    T: BorshSerialize,
-{
-    #[inline]
-    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
-        check_zst::<T>()?;
+/// Module is available if borsh is built with `features = ["primitive-types"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// unsigned integers from [primitive_types](::primitive_types) crate.
+/// They are serialized as fixed-width little-endian byte arrays.
+#[cfg(feature = "primitive-types")]
+pub mod primitive_types {
+    use super::BorshSerialize;
+    use crate::io::{Result, Write};
 
-        writer.write_all(
-            &(u32::try_from(self.len()).map_err(|_| ErrorKind::InvalidData)?).to_le_bytes(),
-        )?;
-        let slices = self.as_slices();
-        serialize_slice(slices.0, writer)?;
-        serialize_slice(slices.1, writer)
+    macro_rules! impl_for_uint {
+        ($($type:ident),+) => {
+        $(
+            impl BorshSerialize for ::primitive_types::$type {
+                #[inline]
+                fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                    writer.write_all(&self.to_little_endian())
+                }
+
+                fixed_serialized_size!(core::mem::size_of::<Self>());
+            }
+        )+
+        };
     }
+
+    impl_for_uint!(U128, U256, U512);
 }
 
-impl<T> BorshSerialize for LinkedList<T>
-where
-    T: BorshSerialize,
-{
-    #[inline]
-    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
-        check_zst::<T>()?;
+/// Module is available if borsh is built with `features = ["ethnum"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// 256-bit integers from [ethnum](::ethnum) crate.
+/// They are serialized as fixed-width 32-byte little-endian arrays.
+#[cfg(feature = "ethnum")]
+pub mod ethnum {
+    use super::BorshSerialize;
+    use crate::io::{Result, Write};
 
-        writer.write_all(
-            &(u32::try_from(self.len()).map_err(|_| ErrorKind::InvalidData)?).to_le_bytes(),
-        )?;
-        for item in self {
-            item.serialize(writer)?;
+    impl BorshSerialize for ::ethnum::U256 {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            writer.write_all(&self.to_le_bytes())
         }
-        Ok(())
+
+        fixed_serialized_size!(32);
+    }
+
+    impl BorshSerialize for ::ethnum::I256 {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            writer.write_all(&self.to_le_bytes())
+        }
+
+        fixed_serialized_size!(32);
     }
 }
 
-/// Module is available if borsh is built with `features = ["std"]` or `features = ["hashbrown"]`.
+/// Module is available if borsh is built with `features = ["num-bigint"]`.
 ///
 /// Module defines [BorshSerialize] implementation for
-/// [HashMap](std::collections::HashMap)/[HashSet](std::collections::HashSet).
-#[cfg(hash_collections)]
-pub mod hashes {
-    use crate::__private::maybestd::vec::Vec;
-    use crate::error::check_zst;
-    use crate::{
-        __private::maybestd::collections::{HashMap, HashSet},
-        BorshSerialize,
-    };
+/// [BigUint](::num_bigint::BigUint) and [BigInt](::num_bigint::BigInt).
+///
+/// `BigUint` is serialized as a length-prefixed little-endian magnitude without
+/// leading (most significant) zero bytes, so zero is serialized as an empty magnitude.
+/// `BigInt` is serialized as a sign (`0` - `Minus`, `1` - `NoSign`, `2` - `Plus`),
+/// followed by its magnitude.
+#[cfg(feature = "num-bigint")]
+pub mod num_bigint {
     use core::convert::TryFrom;
-    use core::hash::BuildHasher;
 
-    use crate::io::{ErrorKind, Result, Write};
+    use super::{BorshSerialize, LENGTH_PREFIX_SIZE};
+    use crate::__private::maybestd::vec::Vec;
+    use crate::io::{Result, Write};
+    use ::num_bigint::{BigInt, BigUint, Sign};
 
-    impl<K, V, H> BorshSerialize for HashMap<K, V, H>
-    where
-        K: BorshSerialize + Ord,
-        V: BorshSerialize,
-        H: BuildHasher,
-    {
+    impl BorshSerialize for BigUint {
         #[inline]
         fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
-            check_zst::<K>()?;
+            // NOTE: `to_bytes_le` returns `[0]` for zero
+            let bytes = if self.bits() == 0 {
+                Vec::new()
+            } else {
+                self.to_bytes_le()
+            };
+            bytes.serialize(writer)
+        }
 
-            let mut vec = self.iter().collect::<Vec<_>>();
-            vec.sort_by_key(|(a, _)| *a);
-            u32::try_from(vec.len())
-                .map_err(|_| ErrorKind::InvalidData)?
-                .serialize(writer)?;
-            for kv in vec {
-                kv.serialize(writer)?;
-            }
-            Ok(())
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            Some(LENGTH_PREFIX_SIZE + usize::try_from(self.bits().div_ceil(8)).ok()?)
         }
     }
 
-    impl<T, H> BorshSerialize for HashSet<T, H>
-    where
-        T: BorshSerialize + Ord,
-        H: BuildHasher,
-    {
+    impl BorshSerialize for Sign {
         #[inline]
         fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
-            check_zst::<T>()?;
+            let tag: u8 = match self {
+                Sign::Minus => 0,
+                Sign::NoSign => 1,
+                Sign::Plus => 2,
+            };
+            tag.serialize(writer)
+        }
 
-            let mut vec = self.iter().collect::<Vec<_>>();
-            vec.sort();
-            u32::try_from(vec.len())
-                .map_err(|_| ErrorKind::InvalidData)?
-                .serialize(writer)?;
-            for item in vec {
-                item.serialize(writer)?;
-            }
-            Ok(())
+        fixed_serialized_size!(1);
+    }
+
+    impl BorshSerialize for BigInt {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.sign().serialize(writer)?;
+            self.magnitude().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            Some(1 + self.magnitude().serialized_size_hint()?)
         }
     }
 }
 
-impl<K, V> BorshSerialize for BTreeMap<K, V>
-where
-    K: BorshSerialize,
-    V: BorshSerialize,
-{
-    #[inline]
-    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
-        check_zst::<K>()?;
-        // NOTE: BTreeMap iterates over the entries that are sorted by key, so the serialization
-        // result will be consistent without a need to sort the entries as we do for HashMap
-        // serialization.
-        u32::try_from(self.len())
-            .map_err(|_| ErrorKind::InvalidData)?
-            .serialize(writer)?;
-        for (key, value) in self {
-            key.serialize(writer)?;
-            value.serialize(writer)?;
+/// Module is available if borsh is built with `features = ["smallvec"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// [SmallVec](::smallvec::SmallVec), which is serialized the same way as `Vec<T>`.
+#[cfg(feature = "smallvec")]
+pub mod smallvec {
+    use super::BorshSerialize;
+    use crate::io::{Result, Write};
+
+    impl<A> BorshSerialize for ::smallvec::SmallVec<A>
+    where
+        A: ::smallvec::Array,
+        A::Item: BorshSerialize,
+    {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.as_slice().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.as_slice().serialized_size_hint()
         }
-        Ok(())
     }
 }
 
-impl<T> BorshSerialize for BTreeSet<T>
-where
-    T: BorshSerialize,
-{
-    #[inline]
-    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
-        check_zst::<T>()?;
-        // NOTE: BTreeSet iterates over the items that are sorted, so the serialization result will
-        // be consistent without a need to sort the entries as we do for HashSet serialization.
-        u32::try_from(self.len())
-            .map_err(|_| ErrorKind::InvalidData)?
-            .serialize(writer)?;
-        for item in self {
-            item.serialize(writer)?;
+/// Module is available if borsh is built with `features = ["arrayvec"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// [ArrayVec](::arrayvec::ArrayVec) and [ArrayString](::arrayvec::ArrayString),
+/// which are serialized the same way as `Vec<T>` and `String`.
+#[cfg(feature = "arrayvec")]
+pub mod arrayvec {
+    use super::BorshSerialize;
+    use crate::io::{Result, Write};
+
+    impl<T: BorshSerialize, const CAP: usize> BorshSerialize for ::arrayvec::ArrayVec<T, CAP> {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.as_slice().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.as_slice().serialized_size_hint()
         }
-        Ok(())
     }
-}
+
+    impl<const CAP: usize> BorshSerialize for ::arrayvec::ArrayString<CAP> {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.as_str().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.as_str().serialized_size_hint()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["tinyvec"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// [ArrayVec](::tinyvec::ArrayVec) and [TinyVec](::tinyvec::TinyVec),
+/// which are serialized the same way as `Vec<T>`.
+#[cfg(feature = "tinyvec")]
+pub mod tinyvec {
+    use super::BorshSerialize;
+    use crate::io::{Result, Write};
+
+    impl<A> BorshSerialize for ::tinyvec::ArrayVec<A>
+    where
+        A: ::tinyvec::Array,
+        A::Item: BorshSerialize,
+    {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.as_slice().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.as_slice().serialized_size_hint()
+        }
+    }
+
+    impl<A> BorshSerialize for ::tinyvec::TinyVec<A>
+    where
+        A: ::tinyvec::Array,
+        A::Item: BorshSerialize,
+    {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.as_slice().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.as_slice().serialized_size_hint()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["heapless"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// [Vec](::heapless::Vec) and [String](::heapless::String) from [heapless](::heapless) crate,
+/// which are serialized the same way as `Vec<T>` and `String`.
+#[cfg(feature = "heapless")]
+pub mod heapless {
+    use super::BorshSerialize;
+    use crate::io::{Result, Write};
+
+    impl<T: BorshSerialize, const N: usize> BorshSerialize for ::heapless::Vec<T, N> {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.as_slice().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.as_slice().serialized_size_hint()
+        }
+    }
+
+    impl<const N: usize> BorshSerialize for ::heapless::String<N> {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.as_str().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.as_str().serialized_size_hint()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["serde_json"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// [Value](::serde_json::Value), [Number](::serde_json::Number) and [Map](::serde_json::Map).
+///
+/// `Value` is serialized as a `u8` tag, followed by the payload of the variant:
+/// `0` - `Null`, `1` - `Bool`, `2` - `Number`, `3` - `String`, `4` - `Array`, `5` - `Object`.
+///
+/// `Number` is serialized as a `u8` tag, followed by the number itself:
+/// `0` - `u64`, `1` - `i64` (used only for negative integers), `2` - `f64`.
+///
+/// `Map` is serialized the same way as `BTreeMap<String, Value>`: entries are
+/// always written in ascending order of keys, regardless of `preserve_order` feature of `serde_json`.
+#[cfg(feature = "serde_json")]
+pub mod serde_json {
+    use core::convert::TryFrom;
+
+    use super::BorshSerialize;
+    use crate::__private::maybestd::{string::String, vec::Vec};
+    use crate::io::{Error, ErrorKind, Result, Write};
+    use ::serde_json::{Map, Number, Value};
+
+    impl BorshSerialize for Value {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            match self {
+                Value::Null => 0u8.serialize(writer),
+                Value::Bool(b) => {
+                    1u8.serialize(writer)?;
+                    b.serialize(writer)
+                }
+                Value::Number(n) => {
+                    2u8.serialize(writer)?;
+                    n.serialize(writer)
+                }
+                Value::String(s) => {
+                    3u8.serialize(writer)?;
+                    s.serialize(writer)
+                }
+                Value::Array(a) => {
+                    4u8.serialize(writer)?;
+                    a.serialize(writer)
+                }
+                Value::Object(o) => {
+                    5u8.serialize(writer)?;
+                    o.serialize(writer)
+                }
+            }
+        }
+    }
+
+    impl BorshSerialize for Number {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            if let Some(u) = self.as_u64() {
+                0u8.serialize(writer)?;
+                u.serialize(writer)
+            } else if let Some(i) = self.as_i64() {
+                1u8.serialize(writer)?;
+                i.serialize(writer)
+            } else if let Some(f) = self.as_f64() {
+                2u8.serialize(writer)?;
+                f.serialize(writer)
+            } else {
+                Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "JSON number can't be represented as u64, i64 or f64",
+                ))
+            }
+        }
+    }
+
+    impl BorshSerialize for Map<String, Value> {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            let mut vec = self.iter().collect::<Vec<_>>();
+            vec.sort_by_key(|&(key, _)| key);
+            u32::try_from(vec.len())
+                .map_err(|_| ErrorKind::InvalidData)?
+                .serialize(writer)?;
+            for (key, value) in vec {
+                key.serialize(writer)?;
+                value.serialize(writer)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["rust_decimal"]`.
+///
+/// Module defines [BorshSerialize] implementation for [Decimal](::rust_decimal::Decimal),
+/// which is serialized as its 16-byte fixed layout: `flags`, `lo`, `mid`, `hi` as little-endian `u32`s.
+#[cfg(feature = "rust_decimal")]
+pub mod rust_decimal {
+    use super::BorshSerialize;
+    use crate::io::{Result, Write};
+    use ::rust_decimal::Decimal;
+
+    impl BorshSerialize for Decimal {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            writer.write_all(&Decimal::serialize(self))
+        }
+
+        fixed_serialized_size!(16);
+    }
+}
+
+/// Module is available if borsh is built with `features = ["ordered-float"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// [OrderedFloat](::ordered_float::OrderedFloat) and [NotNan](::ordered_float::NotNan),
+/// which are serialized the same way as the wrapped float, so NaNs are rejected.
+#[cfg(feature = "ordered-float")]
+pub mod ordered_float {
+    use super::BorshSerialize;
+    use crate::io::{Result, Write};
+    use ::ordered_float::{FloatCore, NotNan, OrderedFloat};
+
+    impl<T: BorshSerialize> BorshSerialize for OrderedFloat<T> {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.0.serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.0.serialized_size_hint()
+        }
+
+        #[inline]
+        fn fixed_serialized_size() -> Option<usize> {
+            T::fixed_serialized_size()
+        }
+    }
+
+    impl<T: FloatCore + BorshSerialize> BorshSerialize for NotNan<T> {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.as_ref().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.as_ref().serialized_size_hint()
+        }
+
+        #[inline]
+        fn fixed_serialized_size() -> Option<usize> {
+            T::fixed_serialized_size()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["half"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// [f16](::half::f16) and [bf16](::half::bf16), which are serialized as
+/// little-endian `u16` bits. NaNs are rejected the same way as for `f32`/`f64`.
+#[cfg(feature = "half")]
+pub mod half {
+    use super::{BorshSerialize, FLOAT_NAN_ERR};
+    use crate::io::{Error, ErrorKind, Result, Write};
+
+    macro_rules! impl_for_half_float {
+        ($type: ident) => {
+            impl BorshSerialize for ::half::$type {
+                #[inline]
+                fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                    if self.is_nan() {
+                        return Err(Error::new(ErrorKind::InvalidData, FLOAT_NAN_ERR));
+                    }
+                    writer.write_all(&self.to_bits().to_le_bytes())
+                }
+
+                fixed_serialized_size!(2);
+            }
+        };
+    }
+
+    impl_for_half_float!(f16);
+    impl_for_half_float!(bf16);
+}
+
+/// Module is available if borsh is built with `features = ["bitflags"]`.
+///
+/// Module defines [serialize](bitflags::serialize) function, which serializes any
+/// [Flags](::bitflags::Flags) type as its underlying integer.
+/// It's meant to be used with `#[borsh(serialize_with = "borsh::ser::bitflags::serialize")]`
+/// field attribute, as [BorshSerialize] cannot be implemented for all `Flags` types at once.
+#[cfg(feature = "bitflags")]
+pub mod bitflags {
+    use super::BorshSerialize;
+    use crate::io::{Result, Write};
+    use ::bitflags::Flags;
+
+    /// Serializes `flags` as the underlying integer, including any bits,
+    /// which don't correspond to a defined flag.
+    pub fn serialize<F, W>(flags: &F, writer: &mut W) -> Result<()>
+    where
+        F: Flags,
+        F::Bits: BorshSerialize,
+        W: Write,
+    {
+        flags.bits().serialize(writer)
+    }
+}
+
+/// Module is available if borsh is built with `features = ["bitvec"]`.
+///
+/// Module defines [BorshSerialize] implementation for [BitSlice](::bitvec::slice::BitSlice)
+/// and [BitVec](::bitvec::vec::BitVec).
+///
+/// Bits are serialized as a `u32` number of bits, followed by `ceil(len / 8)` bytes, where
+/// bit `i` is stored in byte `i / 8` at position `i % 8`, counting from the least significant bit,
+/// regardless of the storage type and bit order of the slice. Unused bits of the last byte are zero.
+#[cfg(feature = "bitvec")]
+pub mod bitvec {
+    use core::convert::TryFrom;
+
+    use super::{BorshSerialize, LENGTH_PREFIX_SIZE};
+    use crate::io::{ErrorKind, Result, Write};
+    use ::bitvec::{order::BitOrder, slice::BitSlice, store::BitStore, vec::BitVec};
+
+    impl<T: BitStore, O: BitOrder> BorshSerialize for BitSlice<T, O> {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            u32::try_from(self.len())
+                .map_err(|_| ErrorKind::InvalidData)?
+                .serialize(writer)?;
+            for chunk in self.chunks(8) {
+                let byte = chunk
+                    .iter()
+                    .by_vals()
+                    .enumerate()
+                    .fold(0u8, |byte, (i, bit)| byte | (u8::from(bit) << i));
+                writer.write_all(&[byte])?;
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            Some(LENGTH_PREFIX_SIZE + self.len().div_ceil(8))
+        }
+    }
+
+    impl<T: BitStore, O: BitOrder> BorshSerialize for BitVec<T, O> {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.as_bitslice().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.as_bitslice().serialized_size_hint()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["im"]`.
+///
+/// Module defines [BorshSerialize] implementation for [HashMap](::im::HashMap),
+/// [OrdMap](::im::OrdMap) and [Vector](::im::Vector) from [im](::im) crate.
+///
+/// Maps are serialized the same way as `HashMap<K, V>`/`BTreeMap<K, V>`, i.e. with entries in
+/// ascending order of keys, and `Vector<T>` is serialized the same way as `Vec<T>`.
+#[cfg(feature = "im")]
+pub mod im {
+    use core::convert::TryFrom;
+    use core::hash::BuildHasher;
+
+    use super::{serialized_size_of_items, BorshSerialize, LENGTH_PREFIX_SIZE};
+    use crate::__private::maybestd::vec::Vec;
+    use crate::error::check_zst;
+    use crate::io::{ErrorKind, Result, Write};
+    use ::im::{HashMap, OrdMap, Vector};
+
+    impl<K, V, S> BorshSerialize for HashMap<K, V, S>
+    where
+        K: BorshSerialize + Ord,
+        V: BorshSerialize,
+        S: BuildHasher,
+    {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            check_zst::<K>()?;
+
+            let mut vec = self.iter().collect::<Vec<_>>();
+            vec.sort_by_key(|(a, _)| *a);
+            u32::try_from(vec.len())
+                .map_err(|_| ErrorKind::InvalidData)?
+                .serialize(writer)?;
+            for kv in vec {
+                kv.serialize(writer)?;
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            LENGTH_PREFIX_SIZE
+                .checked_add(serialized_size_of_items(self.len(), self.keys())?)?
+                .checked_add(serialized_size_of_items(self.len(), self.values())?)
+        }
+    }
+
+    impl<K, V> BorshSerialize for OrdMap<K, V>
+    where
+        K: BorshSerialize + Ord,
+        V: BorshSerialize,
+    {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            check_zst::<K>()?;
+            // NOTE: OrdMap iterates over the entries that are sorted by key
+            u32::try_from(self.len())
+                .map_err(|_| ErrorKind::InvalidData)?
+                .serialize(writer)?;
+            for kv in self {
+                kv.serialize(writer)?;
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            LENGTH_PREFIX_SIZE
+                .checked_add(serialized_size_of_items(self.len(), self.keys())?)?
+                .checked_add(serialized_size_of_items(self.len(), self.values())?)
+        }
+    }
+
+    impl<T> BorshSerialize for Vector<T>
+    where
+        T: BorshSerialize + Clone,
+    {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            check_zst::<T>()?;
+
+            u32::try_from(self.len())
+                .map_err(|_| ErrorKind::InvalidData)?
+                .serialize(writer)?;
+            for item in self {
+                item.serialize(writer)?;
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            LENGTH_PREFIX_SIZE.checked_add(serialized_size_of_items(self.len(), self)?)
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["rpds"]`.
+///
+/// Module defines [BorshSerialize] implementation for [HashTrieMap](::rpds::HashTrieMap),
+/// [RedBlackTreeMap](::rpds::RedBlackTreeMap) and [Vector](::rpds::Vector) from [rpds](::rpds) crate.
+///
+/// Maps are serialized the same way as `HashMap<K, V>`/`BTreeMap<K, V>`, i.e. with entries in
+/// ascending order of keys, and `Vector<T>` is serialized the same way as `Vec<T>`.
+#[cfg(feature = "rpds")]
+pub mod rpds {
+    use core::convert::TryFrom;
+    use core::hash::{BuildHasher, Hash};
+
+    use super::{serialized_size_of_items, BorshSerialize, LENGTH_PREFIX_SIZE};
+    use crate::__private::maybestd::vec::Vec;
+    use crate::error::check_zst;
+    use crate::io::{ErrorKind, Result, Write};
+    use ::archery::SharedPointerKind;
+    use ::rpds::{HashTrieMap, RedBlackTreeMap, Vector};
+
+    impl<K, V, P, H> BorshSerialize for HashTrieMap<K, V, P, H>
+    where
+        K: BorshSerialize + Eq + Hash + Ord,
+        V: BorshSerialize,
+        P: SharedPointerKind,
+        H: BuildHasher + Clone,
+    {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            check_zst::<K>()?;
+
+            let mut vec = self.iter().collect::<Vec<_>>();
+            vec.sort_by_key(|(a, _)| *a);
+            u32::try_from(vec.len())
+                .map_err(|_| ErrorKind::InvalidData)?
+                .serialize(writer)?;
+            for kv in vec {
+                kv.serialize(writer)?;
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            LENGTH_PREFIX_SIZE
+                .checked_add(serialized_size_of_items(self.size(), self.keys())?)?
+                .checked_add(serialized_size_of_items(self.size(), self.values())?)
+        }
+    }
+
+    impl<K, V, P> BorshSerialize for RedBlackTreeMap<K, V, P>
+    where
+        K: BorshSerialize + Ord,
+        V: BorshSerialize,
+        P: SharedPointerKind,
+    {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            check_zst::<K>()?;
+            // NOTE: RedBlackTreeMap iterates over the entries that are sorted by key
+            u32::try_from(self.size())
+                .map_err(|_| ErrorKind::InvalidData)?
+                .serialize(writer)?;
+            for kv in self {
+                kv.serialize(writer)?;
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            LENGTH_PREFIX_SIZE
+                .checked_add(serialized_size_of_items(self.size(), self.keys())?)?
+                .checked_add(serialized_size_of_items(self.size(), self.values())?)
+        }
+    }
+
+    impl<T, P> BorshSerialize for Vector<T, P>
+    where
+        T: BorshSerialize,
+        P: SharedPointerKind,
+    {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            check_zst::<T>()?;
+
+            u32::try_from(self.len())
+                .map_err(|_| ErrorKind::InvalidData)?
+                .serialize(writer)?;
+            for item in self {
+                item.serialize(writer)?;
+            }
+            Ok(())
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            LENGTH_PREFIX_SIZE.checked_add(serialized_size_of_items(self.len(), self)?)
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["smol_str"]`.
+///
+/// Module defines [BorshSerialize] implementation for [SmolStr](::smol_str::SmolStr),
+/// which is serialized the same way as `String`.
+#[cfg(feature = "smol_str")]
+pub mod smol_str {
+    use crate::io::{Result, Write};
+    use crate::BorshSerialize;
+    use ::smol_str::SmolStr;
+
+    impl BorshSerialize for SmolStr {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.as_str().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.as_str().serialized_size_hint()
+        }
+    }
+}
+
+/// Module is available if borsh is built with `features = ["compact_str"]`.
+///
+/// Module defines [BorshSerialize] implementation for [CompactString](::compact_str::CompactString),
+/// which is serialized the same way as `String`.
+#[cfg(feature = "compact_str")]
+pub mod compact_str {
+    use crate::io::{Result, Write};
+    use crate::BorshSerialize;
+    use ::compact_str::CompactString;
+
+    impl BorshSerialize for CompactString {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.as_str().serialize(writer)
+        }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            self.as_str().serialized_size_hint()
+        }
+    }
+}
+
+impl<T> BorshSerialize for VecDeque<T>
+where
+    T: BorshSerialize,
+{
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        check_zst::<T>()?;
+
+        writer.write_all(
+            &(u32::try_from(self.len()).map_err(|_| ErrorKind::InvalidData)?).to_le_bytes(),
+        )?;
+        let slices = self.as_slices();
+        serialize_slice(slices.0, writer)?;
+        serialize_slice(slices.1, writer)
+    }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        LENGTH_PREFIX_SIZE.checked_add(serialized_size_of_items(self.len(), self)?)
+    }
+}
+
+impl<T> BorshSerialize for LinkedList<T>
+where
+    T: BorshSerialize,
+{
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        check_zst::<T>()?;
+
+        writer.write_all(
+            &(u32::try_from(self.len()).map_err(|_| ErrorKind::InvalidData)?).to_le_bytes(),
+        )?;
+        for item in self {
+            item.serialize(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        LENGTH_PREFIX_SIZE.checked_add(serialized_size_of_items(self.len(), self)?)
+    }
+}
+
+/// Defines [BorshSerialize] implementation for `HashMap`/`HashSet` types, which are
+/// expected to be in scope of the invoking module under the given names.
+#[cfg(hash_collections)]
+macro_rules! impl_for_hash_collections {
+    ($map: ident, $set: ident) => {
+        use crate::__private::maybestd::vec::Vec;
+        use crate::error::check_zst;
+        use crate::BorshSerialize;
+        use core::convert::TryFrom;
+        use core::hash::BuildHasher;
+
+        use crate::io::{ErrorKind, Result, Write};
+        use crate::ser::{serialized_size_of_items, LENGTH_PREFIX_SIZE};
+
+        impl<K, V, H> BorshSerialize for $map<K, V, H>
+        where
+            K: BorshSerialize + Ord,
+            V: BorshSerialize,
+            H: BuildHasher,
+        {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                check_zst::<K>()?;
+
+                let mut vec = self.iter().collect::<Vec<_>>();
+                vec.sort_by_key(|(a, _)| *a);
+                u32::try_from(vec.len())
+                    .map_err(|_| ErrorKind::InvalidData)?
+                    .serialize(writer)?;
+                for kv in vec {
+                    kv.serialize(writer)?;
+                }
+                Ok(())
+            }
+
+            #[inline]
+            fn serialized_size_hint(&self) -> Option<usize> {
+                LENGTH_PREFIX_SIZE
+                    .checked_add(serialized_size_of_items(self.len(), self.keys())?)?
+                    .checked_add(serialized_size_of_items(self.len(), self.values())?)
+            }
+        }
+
+        impl<T, H> BorshSerialize for $set<T, H>
+        where
+            T: BorshSerialize + Ord,
+            H: BuildHasher,
+        {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                check_zst::<T>()?;
+
+                let mut vec = self.iter().collect::<Vec<_>>();
+                vec.sort();
+                u32::try_from(vec.len())
+                    .map_err(|_| ErrorKind::InvalidData)?
+                    .serialize(writer)?;
+                for item in vec {
+                    item.serialize(writer)?;
+                }
+                Ok(())
+            }
+
+            #[inline]
+            fn serialized_size_hint(&self) -> Option<usize> {
+                LENGTH_PREFIX_SIZE.checked_add(serialized_size_of_items(self.len(), self)?)
+            }
+        }
+    };
+}
+
+/// Module is available if borsh is built with `features = ["std"]` or `features = ["hashbrown"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// [HashMap](std::collections::HashMap)/[HashSet](std::collections::HashSet).
+#[cfg(hash_collections)]
+pub mod hashes {
+    use crate::__private::maybestd::collections::{HashMap, HashSet};
+
+    impl_for_hash_collections!(HashMap, HashSet);
+}
+
+/// Module is available if borsh is built with `features = ["std", "hashbrown"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// [HashMap](::hashbrown::HashMap)/[HashSet](::hashbrown::HashSet) from [hashbrown](::hashbrown) crate,
+/// which are serialized the same way as the ones from `std`.
+/// Without `std` these are the types, covered by [hashes] module.
+#[cfg(all(feature = "std", feature = "hashbrown"))]
+pub mod hashbrown {
+    use ::hashbrown::{HashMap, HashSet};
+
+    impl_for_hash_collections!(HashMap, HashSet);
+}
+
+impl<K, V> BorshSerialize for BTreeMap<K, V>
+where
+    K: BorshSerialize,
+    V: BorshSerialize,
+{
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        check_zst::<K>()?;
+        // NOTE: BTreeMap iterates over the entries that are sorted by key, so the serialization
+        // result will be consistent without a need to sort the entries as we do for HashMap
+        // serialization.
+        u32::try_from(self.len())
+            .map_err(|_| ErrorKind::InvalidData)?
+            .serialize(writer)?;
+        for (key, value) in self {
+            key.serialize(writer)?;
+            value.serialize(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        LENGTH_PREFIX_SIZE
+            .checked_add(serialized_size_of_items(self.len(), self.keys())?)?
+            .checked_add(serialized_size_of_items(self.len(), self.values())?)
+    }
+}
+
+impl<T> BorshSerialize for BinaryHeap<T>
+where
+    T: BorshSerialize + Ord,
+{
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        check_zst::<T>()?;
+        // NOTE: BinaryHeap iterates over the items in arbitrary order, so they are sorted in
+        // ascending order to make the serialization result consistent.
+        let mut vec = self.iter().collect::<Vec<_>>();
+        vec.sort();
+        u32::try_from(vec.len())
+            .map_err(|_| ErrorKind::InvalidData)?
+            .serialize(writer)?;
+        for item in vec {
+            item.serialize(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        LENGTH_PREFIX_SIZE.checked_add(serialized_size_of_items(self.len(), self)?)
+    }
+}
+
+impl<T> BorshSerialize for BTreeSet<T>
+where
+    T: BorshSerialize,
+{
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        check_zst::<T>()?;
+        // NOTE: BTreeSet iterates over the items that are sorted, so the serialization result will
+        // be consistent without a need to sort the entries as we do for HashSet serialization.
+        u32::try_from(self.len())
+            .map_err(|_| ErrorKind::InvalidData)?
+            .serialize(writer)?;
+        for item in self {
+            item.serialize(writer)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        LENGTH_PREFIX_SIZE.checked_add(serialized_size_of_items(self.len(), self)?)
+    }
+}
 
 impl BorshSerialize for core::net::SocketAddr {
     #[inline]
@@ -529,6 +1603,16 @@ impl BorshSerialize for core::net::SocketAddr {
             }
         }
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        Some(
+            1 + match self {
+                core::net::SocketAddr::V4(addr) => addr.serialized_size_hint()?,
+                core::net::SocketAddr::V6(addr) => addr.serialized_size_hint()?,
+            },
+        )
+    }
 }
 
 impl BorshSerialize for core::net::SocketAddrV4 {
@@ -537,6 +1621,8 @@ impl BorshSerialize for core::net::SocketAddrV4 {
         self.ip().serialize(writer)?;
         self.port().serialize(writer)
     }
+
+    fixed_serialized_size!(4 + 2);
 }
 
 impl BorshSerialize for core::net::SocketAddrV6 {
@@ -545,6 +1631,8 @@ impl BorshSerialize for core::net::SocketAddrV6 {
         self.ip().serialize(writer)?;
         self.port().serialize(writer)
     }
+
+    fixed_serialized_size!(16 + 2);
 }
 
 impl BorshSerialize for core::net::Ipv4Addr {
@@ -552,6 +1640,8 @@ impl BorshSerialize for core::net::Ipv4Addr {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         writer.write_all(&self.octets())
     }
+
+    fixed_serialized_size!(4);
 }
 
 impl BorshSerialize for core::net::Ipv6Addr {
@@ -559,6 +1649,8 @@ impl BorshSerialize for core::net::Ipv6Addr {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         writer.write_all(&self.octets())
     }
+
+    fixed_serialized_size!(16);
 }
 
 impl BorshSerialize for core::net::IpAddr {
@@ -575,11 +1667,26 @@ impl BorshSerialize for core::net::IpAddr {
             }
         }
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        Some(
+            1 + match self {
+                core::net::IpAddr::V4(ipv4) => ipv4.serialized_size_hint()?,
+                core::net::IpAddr::V6(ipv6) => ipv6.serialized_size_hint()?,
+            },
+        )
+    }
 }
 impl<T: BorshSerialize + ?Sized> BorshSerialize for Box<T> {
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         self.as_ref().serialize(writer)
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        self.as_ref().serialized_size_hint()
+    }
 }
 
 impl<T, const N: usize> BorshSerialize for [T; N]
@@ -599,6 +1706,16 @@ where
         }
         Ok(())
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        serialized_size_of_items(N, self)
+    }
+
+    #[inline]
+    fn fixed_serialized_size() -> Option<usize> {
+        T::fixed_serialized_size()?.checked_mul(N)
+    }
 }
 
 macro_rules! impl_tuple {
@@ -608,6 +1725,8 @@ macro_rules! impl_tuple {
             fn serialize<W: Write>(&self, _writer: &mut W) -> Result<()> {
                 Ok(())
             }
+
+            fixed_serialized_size!(0);
         }
     };
 
@@ -620,6 +1739,16 @@ macro_rules! impl_tuple {
             $(self.$idx.serialize(writer)?;)+
             Ok(())
         }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            Some(0 $(+ self.$idx.serialized_size_hint()?)+)
+        }
+
+        #[inline]
+        fn fixed_serialized_size() -> Option<usize> {
+            Some(0 $(+ $name::fixed_serialized_size()?)+)
+        }
       }
     };
 }
@@ -657,6 +1786,12 @@ macro_rules! impl_range {
                 $( $field.serialize(writer)?; )*
                 Ok(())
             }
+
+            #[inline]
+            fn serialized_size_hint(&self) -> Option<usize> {
+                let $this = self;
+                Some(0 $( + $field.serialized_size_hint()? )*)
+            }
         }
     };
 }
@@ -687,6 +1822,11 @@ pub mod rc {
         fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
             (**self).serialize(writer)
         }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            (**self).serialized_size_hint()
+        }
     }
 
     /// This impl requires the [`"rc"`] Cargo feature of borsh.
@@ -699,6 +1839,11 @@ pub mod rc {
         fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
             (**self).serialize(writer)
         }
+
+        #[inline]
+        fn serialized_size_hint(&self) -> Option<usize> {
+            (**self).serialized_size_hint()
+        }
     }
 }
 
@@ -706,6 +1851,8 @@ impl<T: ?Sized> BorshSerialize for PhantomData<T> {
     fn serialize<W: Write>(&self, _: &mut W) -> Result<()> {
         Ok(())
     }
+
+    fixed_serialized_size!(0);
 }
 
 impl<T> BorshSerialize for core::cell::Cell<T>
@@ -715,6 +1862,16 @@ where
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         <T as BorshSerialize>::serialize(&self.get(), writer)
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        self.get().serialized_size_hint()
+    }
+
+    #[inline]
+    fn fixed_serialized_size() -> Option<usize> {
+        T::fixed_serialized_size()
+    }
 }
 
 impl<T> BorshSerialize for core::cell::RefCell<T>
@@ -727,4 +1884,314 @@ where
             Err(_) => Err(Error::other("already mutably borrowed")),
         }
     }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        self.try_borrow().ok()?.serialized_size_hint()
+    }
+}
+
+impl BorshSerialize for core::time::Duration {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_secs().serialize(writer)?;
+        self.subsec_nanos().serialize(writer)
+    }
+
+    fixed_serialized_size!(8 + 4);
+}
+
+/// `SystemTime` is serialized as an offset from [`UNIX_EPOCH`](std::time::UNIX_EPOCH):
+/// whole seconds as `i64`, followed by the nanoseconds as `u32`, which are always
+/// non-negative, i.e. `UNIX_EPOCH - 1.5s` is serialized as `(-2, 500_000_000)`.
+#[cfg(feature = "std")]
+impl BorshSerialize for std::time::SystemTime {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let (secs, nanos) = match self.duration_since(std::time::UNIX_EPOCH) {
+            Ok(after) => (
+                i64::try_from(after.as_secs()).map_err(|_| ErrorKind::InvalidData)?,
+                after.subsec_nanos(),
+            ),
+            Err(err) => {
+                let before = err.duration();
+                let secs = i64::try_from(before.as_secs()).map_err(|_| ErrorKind::InvalidData)?;
+                match before.subsec_nanos() {
+                    0 => (-secs, 0),
+                    nanos => (-secs - 1, 1_000_000_000 - nanos),
+                }
+            }
+        };
+        secs.serialize(writer)?;
+        nanos.serialize(writer)
+    }
+
+    fixed_serialized_size!(8 + 4);
+}
+
+macro_rules! impl_for_transparent_wrapper {
+    ($($module:ident::$type:ident),+) => {
+    $(
+        impl<T: BorshSerialize> BorshSerialize for core::$module::$type<T> {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                self.0.serialize(writer)
+            }
+
+            #[inline]
+            fn serialized_size_hint(&self) -> Option<usize> {
+                self.0.serialized_size_hint()
+            }
+
+            #[inline]
+            fn fixed_serialized_size() -> Option<usize> {
+                T::fixed_serialized_size()
+            }
+        }
+    )+
+    };
+}
+
+impl_for_transparent_wrapper!(num::Wrapping, num::Saturating, cmp::Reverse);
+
+impl BorshSerialize for core::ffi::CStr {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        // NOTE: the nul terminator isn't serialized
+        self.to_bytes().serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        Some(LENGTH_PREFIX_SIZE + self.to_bytes().len())
+    }
+}
+
+impl BorshSerialize for CString {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_c_str().serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        self.as_c_str().serialized_size_hint()
+    }
+}
+
+impl<T: BorshSerialize> BorshSerialize for core::ops::Bound<T> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match self {
+            core::ops::Bound::Included(value) => {
+                0u8.serialize(writer)?;
+                value.serialize(writer)
+            }
+            core::ops::Bound::Excluded(value) => {
+                1u8.serialize(writer)?;
+                value.serialize(writer)
+            }
+            core::ops::Bound::Unbounded => 2u8.serialize(writer),
+        }
+    }
+
+    #[inline]
+    fn serialized_size_hint(&self) -> Option<usize> {
+        Some(
+            1 + match self {
+                core::ops::Bound::Included(value) | core::ops::Bound::Excluded(value) => {
+                    value.serialized_size_hint()?
+                }
+                core::ops::Bound::Unbounded => 0,
+            },
+        )
+    }
+}
+
+impl BorshSerialize for core::cmp::Ordering {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let tag: u8 = match self {
+            core::cmp::Ordering::Less => 0,
+            core::cmp::Ordering::Equal => 1,
+            core::cmp::Ordering::Greater => 2,
+        };
+        tag.serialize(writer)
+    }
+
+    fixed_serialized_size!(1);
+}
+
+// NOTE: atomics are serialized as a snapshot of their value, taken with `Ordering::Relaxed`,
+// same as the underlying primitive
+macro_rules! impl_for_atomic {
+    ($($type:ident : $width:literal),+) => {
+    $(
+        #[cfg(target_has_atomic = $width)]
+        impl BorshSerialize for core::sync::atomic::$type {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                self.load(core::sync::atomic::Ordering::Relaxed).serialize(writer)
+            }
+
+            #[inline]
+            fn serialized_size_hint(&self) -> Option<usize> {
+                self.load(core::sync::atomic::Ordering::Relaxed).serialized_size_hint()
+            }
+        }
+    )+
+    };
+}
+
+impl_for_atomic!(
+    AtomicBool: "8",
+    AtomicI8: "8",
+    AtomicI16: "16",
+    AtomicI32: "32",
+    AtomicI64: "64",
+    AtomicIsize: "ptr",
+    AtomicU8: "8",
+    AtomicU16: "16",
+    AtomicU32: "32",
+    AtomicU64: "64",
+    AtomicUsize: "ptr"
+);
+
+/// Module is available if borsh is built with `features = ["chrono"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// some types from [chrono](::chrono) crate.
+///
+/// Points in time are serialized as an offset from the Unix epoch: whole seconds as `i64`,
+/// followed by the non-negative nanoseconds as `u32`, same as [SystemTime](std::time::SystemTime).
+/// Leap seconds are represented the way `chrono` does: with nanoseconds exceeding one second.
+/// Dates are serialized as the number of days since the Unix epoch as `i32`.
+#[cfg(feature = "chrono")]
+pub mod chrono {
+    use super::BorshSerialize;
+    use crate::io::{Result, Write};
+    use ::chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Utc};
+
+    /// number of days from 0001-01-01 to 1970-01-01
+    const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+    impl BorshSerialize for DateTime<Utc> {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.timestamp().serialize(writer)?;
+            self.timestamp_subsec_nanos().serialize(writer)
+        }
+
+        fixed_serialized_size!(8 + 4);
+    }
+
+    impl BorshSerialize for NaiveDateTime {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.and_utc().serialize(writer)
+        }
+
+        fixed_serialized_size!(8 + 4);
+    }
+
+    impl BorshSerialize for NaiveDate {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            (self.num_days_from_ce() - UNIX_EPOCH_DAYS_FROM_CE).serialize(writer)
+        }
+
+        fixed_serialized_size!(4);
+    }
+}
+
+/// Module is available if borsh is built with `features = ["time"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// some types from [time](::time) crate.
+///
+/// Points in time are serialized as an offset from the Unix epoch: whole seconds as `i64`,
+/// followed by the non-negative nanoseconds as `u32`, same as [SystemTime](std::time::SystemTime).
+/// The UTC offset of [OffsetDateTime](::time::OffsetDateTime) isn't serialized.
+/// Dates are serialized as the number of days since the Unix epoch as `i32`.
+#[cfg(feature = "time")]
+pub mod time {
+    use super::BorshSerialize;
+    use crate::io::{Result, Write};
+    use ::time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+    /// julian day of 1970-01-01
+    const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+    impl BorshSerialize for OffsetDateTime {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.unix_timestamp().serialize(writer)?;
+            self.nanosecond().serialize(writer)
+        }
+
+        fixed_serialized_size!(8 + 4);
+    }
+
+    impl BorshSerialize for PrimitiveDateTime {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            self.assume_utc().serialize(writer)
+        }
+
+        fixed_serialized_size!(8 + 4);
+    }
+
+    impl BorshSerialize for Date {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            (self.to_julian_day() - UNIX_EPOCH_JULIAN_DAY).serialize(writer)
+        }
+
+        fixed_serialized_size!(4);
+    }
+}
+
+/// Module is available if borsh is built with `features = ["jiff"]`.
+///
+/// Module defines [BorshSerialize] implementation for
+/// some types from [jiff](::jiff) crate.
+///
+/// Points in time are serialized as an offset from the Unix epoch: whole seconds as `i64`,
+/// followed by the non-negative nanoseconds as `u32`, same as [SystemTime](std::time::SystemTime).
+/// Dates are serialized as the number of days since the Unix epoch as `i32`.
+#[cfg(feature = "jiff")]
+pub mod jiff {
+    use super::BorshSerialize;
+    use crate::__private::maybestd::string::ToString;
+    use crate::io::{Error, ErrorKind, Result, Write};
+    use ::jiff::civil::{self, Date};
+    use ::jiff::Timestamp;
+
+    impl BorshSerialize for Timestamp {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            let (secs, nanos) = (self.as_second(), self.subsec_nanosecond());
+            // NOTE: jiff returns negative nanoseconds for timestamps before the Unix epoch
+            let (secs, nanos) = if nanos < 0 {
+                (secs - 1, nanos + 1_000_000_000)
+            } else {
+                (secs, nanos)
+            };
+            secs.serialize(writer)?;
+            (nanos as u32).serialize(writer)
+        }
+
+        fixed_serialized_size!(8 + 4);
+    }
+
+    impl BorshSerialize for Date {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            let span = civil::date(1970, 1, 1)
+                .until(*self)
+                .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+            span.get_days().serialize(writer)
+        }
+
+        fixed_serialized_size!(4);
+    }
 }
@@ -0,0 +1,75 @@
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+
+use crate::error::check_zst;
+use crate::io::{Error, ErrorKind, Result, Write};
+
+use super::BorshSerialize;
+
+const ERROR_TOO_MANY_ELEMENTS: &str = "More elements written than the declared length";
+const ERROR_TOO_FEW_ELEMENTS: &str = "Fewer elements written than the declared length";
+
+/// Writer of a sequence encoded as `Vec<T>`, which writes the length up front and then
+/// streams the elements one at a time.
+///
+/// It lets sequences too large to be kept in memory be produced element by element, to be
+/// read back as `Vec<T>` or with [SeqReader](crate::de::SeqReader).
+///
+/// ```
+/// use borsh::ser::SeqWriter;
+///
+/// let mut writer = SeqWriter::<u64, _>::new(Vec::new(), 3).unwrap();
+/// for element in 1..=3u64 {
+///     writer.write(&element).unwrap();
+/// }
+/// let bytes = writer.finish().unwrap();
+/// assert_eq!(bytes, borsh::to_vec(&vec![1u64, 2, 3]).unwrap());
+/// ```
+pub struct SeqWriter<T, W> {
+    writer: W,
+    remaining: u32,
+    _element: PhantomData<fn(&T)>,
+}
+
+impl<T: BorshSerialize, W: Write> SeqWriter<T, W> {
+    /// Writes the length of the sequence, `len`, to `writer`.
+    ///
+    /// Fails with [ErrorKind::InvalidData], if `len` doesn't fit `u32`.
+    pub fn new(mut writer: W, len: usize) -> Result<Self> {
+        check_zst::<T>()?;
+        let remaining = u32::try_from(len).map_err(|_| ErrorKind::InvalidData)?;
+        remaining.serialize(&mut writer)?;
+        Ok(Self {
+            writer,
+            remaining,
+            _element: PhantomData,
+        })
+    }
+
+    /// Writes the next element.
+    ///
+    /// Fails with [ErrorKind::InvalidData], if all of the declared elements were already written.
+    pub fn write(&mut self, element: &T) -> Result<()> {
+        if self.remaining == 0 {
+            return Err(Error::new(ErrorKind::InvalidData, ERROR_TOO_MANY_ELEMENTS));
+        }
+        element.serialize(&mut self.writer)?;
+        self.remaining -= 1;
+        Ok(())
+    }
+
+    /// Returns the number of elements not yet written.
+    pub fn remaining(&self) -> usize {
+        self.remaining as usize
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// Fails with [ErrorKind::InvalidData], if fewer elements than declared were written.
+    pub fn finish(self) -> Result<W> {
+        if self.remaining != 0 {
+            return Err(Error::new(ErrorKind::InvalidData, ERROR_TOO_FEW_ELEMENTS));
+        }
+        Ok(self.writer)
+    }
+}
@@ -3,7 +3,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 #[allow(unused)]
 use alloc::{string::String, vec::Vec};
 
-#[cfg(feature = "hashbrown")]
+#[cfg(all(feature = "hashbrown", not(feature = "std")))]
 use hashbrown::HashMap;
 
 #[cfg(hash_collections)]
@@ -1,4 +1,4 @@
-#[cfg(feature = "hashbrown")]
+#[cfg(all(feature = "hashbrown", not(feature = "std")))]
 use hashbrown::HashMap;
 
 #[cfg(hash_collections)]
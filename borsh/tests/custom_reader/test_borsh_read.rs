@@ -0,0 +1,200 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::Debug;
+
+use borsh::{de::BorshRead, from_reader, from_slice, to_vec, BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+struct Record {
+    id: u64,
+    name: String,
+    scores: Vec<u32>,
+    weights: [f32; 3],
+    tag: Option<char>,
+    nested: Box<(i16, bool)>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+enum Event {
+    Created(Record),
+    Renamed { id: u64, name: String },
+    Deleted,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+struct AccountV1 {
+    owner: String,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+struct AccountV2 {
+    owner: String,
+    #[borsh(trailing_default)]
+    balance: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+#[borsh(version = 2, migrate_from(AccountV1 = 1))]
+struct Account {
+    owner: String,
+    balance: u64,
+}
+
+impl From<AccountV1> for Account {
+    fn from(value: AccountV1) -> Self {
+        Self {
+            owner: value.owner,
+            balance: 0,
+        }
+    }
+}
+
+/// checks that `from_slice`, which goes through [BorshRead], and `from_reader`,
+/// which goes through [borsh::io::Read], agree on both values and errors
+fn assert_paths_agree<T: BorshDeserialize + PartialEq + Debug>(bytes: &[u8]) -> Option<T> {
+    let from_slice_result = from_slice::<T>(bytes);
+    let from_reader_result = from_reader::<_, T>(&mut &bytes[..]);
+    match (from_slice_result, from_reader_result) {
+        (Ok(a), Ok(b)) => {
+            assert_eq!(a, b);
+            Some(a)
+        }
+        (Err(a), Err(b)) => {
+            assert_eq!(a.kind(), b.kind());
+            assert_eq!(a.to_string(), b.to_string());
+            None
+        }
+        (a, b) => panic!("paths disagree: {:?} vs {:?}", a, b),
+    }
+}
+
+fn record() -> Record {
+    Record {
+        id: 42,
+        name: "borsh".to_string(),
+        scores: vec![1, 2, 3, 5, 8],
+        weights: [0.5, 1.5, -2.0],
+        tag: Some('λ'),
+        nested: Box::new((-7, true)),
+    }
+}
+
+#[test]
+fn test_struct_paths_agree() {
+    let value = record();
+    let bytes = to_vec(&value).unwrap();
+    assert_eq!(assert_paths_agree::<Record>(&bytes), Some(value));
+}
+
+#[test]
+fn test_enum_paths_agree() {
+    for value in [
+        Event::Created(record()),
+        Event::Renamed {
+            id: 1,
+            name: "new".to_string(),
+        },
+        Event::Deleted,
+    ] {
+        let bytes = to_vec(&value).unwrap();
+        assert_eq!(assert_paths_agree::<Event>(&bytes), Some(value));
+    }
+    assert_eq!(assert_paths_agree::<Event>(&[3]), None);
+}
+
+#[test]
+fn test_truncated_input_paths_agree() {
+    let bytes = to_vec(&Event::Created(record())).unwrap();
+    for len in 0..bytes.len() {
+        assert_eq!(assert_paths_agree::<Event>(&bytes[..len]), None);
+    }
+}
+
+#[test]
+fn test_invalid_input_paths_agree() {
+    // invalid utf-8
+    assert_eq!(
+        assert_paths_agree::<String>(&[2, 0, 0, 0, 0xff, 0xfe]),
+        None
+    );
+    // invalid bool
+    assert_eq!(assert_paths_agree::<bool>(&[2]), None);
+    // invalid option flag
+    assert_eq!(assert_paths_agree::<Option<u8>>(&[2, 0]), None);
+    // invalid char
+    assert_eq!(assert_paths_agree::<char>(&[0x00, 0xd8, 0, 0]), None);
+    // NaN in bulk-decoded floats
+    let mut bytes = to_vec(&vec![1.0f64, 2.0]).unwrap();
+    bytes.extend_from_slice(&f64::NAN.to_le_bytes());
+    bytes[0] = 3;
+    assert_eq!(assert_paths_agree::<Vec<f64>>(&bytes), None);
+    // trailing bytes
+    let mut bytes = to_vec(&record()).unwrap();
+    bytes.push(0);
+    assert_eq!(assert_paths_agree::<Record>(&bytes), None);
+}
+
+#[test]
+fn test_trailing_default_paths_agree() {
+    let old = to_vec(&AccountV1 {
+        owner: "alice".to_string(),
+    })
+    .unwrap();
+    assert_eq!(
+        assert_paths_agree::<AccountV2>(&old),
+        Some(AccountV2 {
+            owner: "alice".to_string(),
+            balance: 0,
+        })
+    );
+    let new = AccountV2 {
+        owner: "bob".to_string(),
+        balance: 7,
+    };
+    assert_eq!(
+        assert_paths_agree::<AccountV2>(&to_vec(&new).unwrap()),
+        Some(new)
+    );
+}
+
+#[test]
+fn test_versioned_paths_agree() {
+    let mut old = vec![1];
+    old.extend(
+        to_vec(&AccountV1 {
+            owner: "alice".to_string(),
+        })
+        .unwrap(),
+    );
+    assert_eq!(
+        assert_paths_agree::<Account>(&old),
+        Some(Account {
+            owner: "alice".to_string(),
+            balance: 0,
+        })
+    );
+    let current = Account {
+        owner: "bob".to_string(),
+        balance: 7,
+    };
+    assert_eq!(
+        assert_paths_agree::<Account>(&to_vec(&current).unwrap()),
+        Some(current)
+    );
+    assert_eq!(assert_paths_agree::<Account>(&[9]), None);
+}
+
+#[test]
+fn test_deserialize_from_advances_slice() {
+    let bytes = to_vec(&(7u32, "abc".to_string())).unwrap();
+    let mut input = &bytes[..];
+    assert_eq!(u32::deserialize_from(&mut input).unwrap(), 7);
+    assert_eq!(input.remaining(), 7);
+    assert_eq!(String::deserialize_from(&mut input).unwrap(), "abc");
+    assert_eq!(input.remaining(), 0);
+    assert!(u8::deserialize_from(&mut input).is_err());
+}
@@ -0,0 +1,149 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::de::{Progress, PushDecoder};
+use borsh::{to_vec, BorshDeserialize, BorshSchema, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSchema, PartialEq, Debug)]
+struct Record {
+    id: u64,
+    name: String,
+    scores: Vec<u32>,
+    weights: [f32; 3],
+    tag: Option<char>,
+    nested: Box<(i16, bool)>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSchema, PartialEq, Debug)]
+enum Event {
+    Created(Record),
+    Renamed { id: u64, name: String },
+    Deleted,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSchema, PartialEq, Debug, Clone)]
+enum List {
+    Nil,
+    Cons(u8, Box<List>),
+}
+
+fn record() -> Record {
+    Record {
+        id: 42,
+        name: "borsh".to_string(),
+        scores: vec![1, 2, 3, 5, 8],
+        weights: [0.5, 1.5, -2.0],
+        tag: Some('λ'),
+        nested: Box::new((-7, true)),
+    }
+}
+
+fn events() -> Vec<Event> {
+    vec![
+        Event::Created(record()),
+        Event::Renamed {
+            id: 1,
+            name: "new".to_string(),
+        },
+        Event::Deleted,
+    ]
+}
+
+/// feeds `bytes` in chunks of `chunk_size`, collecting the decoded values
+fn decode_chunked<T: BorshDeserialize + BorshSchema>(bytes: &[u8], chunk_size: usize) -> Vec<T> {
+    let mut decoder = PushDecoder::<T>::new().unwrap();
+    let mut values = Vec::new();
+    for chunk in bytes.chunks(chunk_size) {
+        let mut progress = decoder.feed(chunk).unwrap();
+        while let Progress::Done(value) = progress {
+            values.push(value);
+            progress = decoder.feed(&[]).unwrap();
+        }
+    }
+    assert_eq!(decoder.buffered(), 0);
+    values
+}
+
+#[test]
+fn test_chunked_input() {
+    let mut bytes = Vec::new();
+    for event in events() {
+        bytes.extend(to_vec(&event).unwrap());
+    }
+    for chunk_size in [1, 2, 3, 7, 64, bytes.len()] {
+        assert_eq!(decode_chunked::<Event>(&bytes, chunk_size), events());
+    }
+}
+
+#[test]
+fn test_recursive_type() {
+    let list = List::Cons(1, Box::new(List::Cons(2, Box::new(List::Nil))));
+    let bytes = to_vec(&list).unwrap();
+    assert_eq!(decode_chunked::<List>(&bytes, 1), vec![list]);
+}
+
+#[test]
+fn test_need_more() {
+    let bytes = to_vec(&vec![7u8; 1000]).unwrap();
+    let mut decoder = PushDecoder::<Vec<u8>>::new().unwrap();
+    assert_eq!(decoder.feed(&[]).unwrap(), Progress::NeedMore(4));
+    assert_eq!(decoder.feed(&bytes[..1]).unwrap(), Progress::NeedMore(3));
+    // the payload is of fixed size per element, so it's skipped as a whole
+    assert_eq!(
+        decoder.feed(&bytes[1..4]).unwrap(),
+        Progress::NeedMore(1000)
+    );
+    assert_eq!(
+        decoder.feed(&bytes[4..504]).unwrap(),
+        Progress::NeedMore(500)
+    );
+    assert_eq!(
+        decoder.feed(&bytes[504..]).unwrap(),
+        Progress::Done(vec![7u8; 1000])
+    );
+}
+
+#[test]
+fn test_trailing_bytes_are_kept() {
+    let mut bytes = to_vec(&Event::Deleted).unwrap();
+    bytes.extend(
+        to_vec(&Event::Renamed {
+            id: 3,
+            name: "x".to_string(),
+        })
+        .unwrap(),
+    );
+    let mut decoder = PushDecoder::<Event>::new().unwrap();
+    assert_eq!(
+        decoder.feed(&bytes[..5]).unwrap(),
+        Progress::Done(Event::Deleted)
+    );
+    assert_eq!(decoder.buffered(), 4);
+    assert_eq!(decoder.feed(&[]).unwrap(), Progress::NeedMore(5));
+    assert_eq!(
+        decoder.feed(&bytes[5..]).unwrap(),
+        Progress::Done(Event::Renamed {
+            id: 3,
+            name: "x".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_invalid_input() {
+    let mut decoder = PushDecoder::<Event>::new().unwrap();
+    let err = decoder.feed(&[3]).unwrap_err();
+    assert_eq!(err.to_string(), "Unexpected variant tag: 3");
+
+    // contents are validated by deserialization, once the value is complete
+    let mut decoder = PushDecoder::<String>::new().unwrap();
+    assert_eq!(
+        decoder.feed(&[2, 0, 0, 0, 0xff]).unwrap(),
+        Progress::NeedMore(1)
+    );
+    assert!(decoder.feed(&[0xfe]).is_err());
+}
@@ -0,0 +1,47 @@
+use alloc::string::ToString;
+
+#[cfg(feature = "bitflags")]
+#[test]
+fn test_bitflags_unknown_bits() {
+    bitflags::bitflags! {
+        #[derive(Debug)]
+        struct Permissions: u8 {
+            const READ = 0b01;
+            const WRITE = 0b10;
+        }
+    }
+
+    let encoded = [0b101u8];
+    let err =
+        borsh::de::bitflags::deserialize_strict::<_, Permissions>(&mut &encoded[..]).unwrap_err();
+    assert_eq!(err.to_string(), "Flags contain unknown bits");
+}
+
+#[cfg(feature = "bitvec")]
+#[test]
+fn test_bitvec_non_zero_unused_bits() {
+    use bitvec::vec::BitVec;
+
+    // 3 bits, the 4th bit is set
+    let encoded = [3, 0, 0, 0, 0b0000_1101];
+    assert_eq!(
+        borsh::from_slice::<BitVec>(&encoded)
+            .unwrap_err()
+            .to_string(),
+        "Unused bits of BitVec must be zero"
+    );
+}
+
+#[cfg(feature = "bitvec")]
+#[test]
+fn test_bitvec_not_enough_bytes() {
+    use bitvec::vec::BitVec;
+
+    let encoded = [9, 0, 0, 0, 0xff];
+    assert_eq!(
+        borsh::from_slice::<BitVec>(&encoded)
+            .unwrap_err()
+            .to_string(),
+        "Unexpected length of input"
+    );
+}
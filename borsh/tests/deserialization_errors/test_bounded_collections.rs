@@ -0,0 +1,67 @@
+use alloc::{string::ToString, vec};
+
+use borsh::{from_slice, to_vec};
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_arrayvec_capacity_overflow() {
+    let buf = to_vec(&vec![1u8, 2, 3, 4]).unwrap();
+    assert_eq!(
+        from_slice::<arrayvec::ArrayVec<u8, 3>>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Length 4 exceeds the capacity of the collection: 3"
+    );
+
+    let buf = to_vec("four").unwrap();
+    assert_eq!(
+        from_slice::<arrayvec::ArrayString<3>>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Length 4 exceeds the capacity of the collection: 3"
+    );
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_arrayvec_non_utf8() {
+    let buf = to_vec(&vec![0xffu8]).unwrap();
+    assert_eq!(
+        from_slice::<arrayvec::ArrayString<3>>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "invalid utf-8 sequence of 1 bytes from index 0"
+    );
+}
+
+#[cfg(feature = "tinyvec")]
+#[test]
+fn test_tinyvec_capacity_overflow() {
+    let buf = to_vec(&vec![1u32, 2, 3]).unwrap();
+    assert_eq!(
+        from_slice::<tinyvec::ArrayVec<[u32; 2]>>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Length 3 exceeds the capacity of the collection: 2"
+    );
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_heapless_capacity_overflow() {
+    let buf = to_vec(&vec![1u16, 2, 3]).unwrap();
+    assert_eq!(
+        from_slice::<heapless::Vec<u16, 2>>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Length 3 exceeds the capacity of the collection: 2"
+    );
+
+    let buf = to_vec("three").unwrap();
+    assert_eq!(
+        from_slice::<heapless::String<4>>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Length 5 exceeds the capacity of the collection: 4"
+    );
+}
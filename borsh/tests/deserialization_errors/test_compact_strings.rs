@@ -0,0 +1,64 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::from_slice;
+
+/// Inputs, which are rejected for `String`, paired with their errors.
+fn invalid_strings() -> Vec<(Vec<u8>, String)> {
+    let mut long_invalid = borsh::to_vec(&"x".repeat(100)).unwrap();
+    long_invalid[50] = 0xff;
+    vec![
+        (
+            vec![2, 0, 0, 0, 0xc3, 0x28],
+            "invalid utf-8 sequence of 1 bytes from index 0".to_string(),
+        ),
+        (
+            long_invalid,
+            "invalid utf-8 sequence of 1 bytes from index 46".to_string(),
+        ),
+        (
+            vec![5, 0, 0, 0, b'a', b'b'],
+            "Unexpected length of input".to_string(),
+        ),
+        (
+            vec![100, 0, 0, 0, b'a', b'b'],
+            "Unexpected length of input".to_string(),
+        ),
+    ]
+}
+
+#[test]
+fn test_string_errors() {
+    for (input, error) in invalid_strings() {
+        assert_eq!(from_slice::<String>(&input).unwrap_err().to_string(), error);
+    }
+}
+
+#[cfg(feature = "smol_str")]
+#[test]
+fn test_smol_str_errors() {
+    for (input, error) in invalid_strings() {
+        assert_eq!(
+            from_slice::<smol_str::SmolStr>(&input)
+                .unwrap_err()
+                .to_string(),
+            error
+        );
+    }
+}
+
+#[cfg(feature = "compact_str")]
+#[test]
+fn test_compact_str_errors() {
+    for (input, error) in invalid_strings() {
+        assert_eq!(
+            from_slice::<compact_str::CompactString>(&input)
+                .unwrap_err()
+                .to_string(),
+            error
+        );
+    }
+}
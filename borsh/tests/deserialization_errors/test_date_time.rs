@@ -0,0 +1,81 @@
+use alloc::string::ToString;
+
+use borsh::{from_slice, to_vec};
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_out_of_range() {
+    let buf = to_vec(&(i64::MAX, 0u32)).unwrap();
+    assert_eq!(
+        from_slice::<chrono::DateTime<chrono::Utc>>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Timestamp is out of range"
+    );
+
+    let buf = to_vec(&(0i64, 2_000_000_000u32)).unwrap();
+    assert_eq!(
+        from_slice::<chrono::NaiveDateTime>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Timestamp is out of range"
+    );
+
+    let buf = to_vec(&i32::MAX).unwrap();
+    assert_eq!(
+        from_slice::<chrono::NaiveDate>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Date is out of range"
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_out_of_range() {
+    let buf = to_vec(&(i64::MAX, 0u32)).unwrap();
+    assert_eq!(
+        from_slice::<time::OffsetDateTime>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Timestamp is out of range"
+    );
+
+    let buf = to_vec(&(0i64, 1_000_000_000u32)).unwrap();
+    assert_eq!(
+        from_slice::<time::PrimitiveDateTime>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Nanoseconds must be less than one second"
+    );
+
+    let buf = to_vec(&i32::MIN).unwrap();
+    assert_eq!(
+        from_slice::<time::Date>(&buf).unwrap_err().to_string(),
+        "Date is out of range"
+    );
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_jiff_out_of_range() {
+    let buf = to_vec(&(i64::MIN, 0u32)).unwrap();
+    assert_eq!(
+        from_slice::<jiff::Timestamp>(&buf).unwrap_err().to_string(),
+        "Timestamp is out of range"
+    );
+
+    let buf = to_vec(&(0i64, u32::MAX)).unwrap();
+    assert_eq!(
+        from_slice::<jiff::Timestamp>(&buf).unwrap_err().to_string(),
+        "Nanoseconds must be less than one second"
+    );
+
+    let buf = to_vec(&i32::MAX).unwrap();
+    assert_eq!(
+        from_slice::<jiff::civil::Date>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Date is out of range"
+    );
+}
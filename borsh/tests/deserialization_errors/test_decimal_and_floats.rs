@@ -0,0 +1,59 @@
+use alloc::string::ToString;
+
+use borsh::from_slice;
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn test_decimal_invalid_flags() {
+    use rust_decimal::Decimal;
+
+    // scale of 29
+    let mut bytes = [0u8; 16];
+    bytes[2] = 29;
+    assert_eq!(
+        from_slice::<Decimal>(&bytes).unwrap_err().to_string(),
+        "Invalid Decimal representation"
+    );
+    // unused bits of flags
+    let mut bytes = [0u8; 16];
+    bytes[0] = 1;
+    assert_eq!(
+        from_slice::<Decimal>(&bytes).unwrap_err().to_string(),
+        "Invalid Decimal representation"
+    );
+}
+
+#[cfg(feature = "ordered-float")]
+#[test]
+fn test_ordered_float_nan() {
+    use ordered_float::{NotNan, OrderedFloat};
+
+    let bytes = f64::NAN.to_le_bytes();
+    assert_eq!(
+        from_slice::<OrderedFloat<f64>>(&bytes)
+            .unwrap_err()
+            .to_string(),
+        "For portability reasons we do not allow to deserialize NaNs."
+    );
+    assert_eq!(
+        from_slice::<NotNan<f64>>(&bytes).unwrap_err().to_string(),
+        "For portability reasons we do not allow to deserialize NaNs."
+    );
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_half_nan() {
+    assert_eq!(
+        from_slice::<half::f16>(&half::f16::NAN.to_bits().to_le_bytes())
+            .unwrap_err()
+            .to_string(),
+        "For portability reasons we do not allow to deserialize NaNs."
+    );
+    assert_eq!(
+        from_slice::<half::bf16>(&half::bf16::NAN.to_bits().to_le_bytes())
+            .unwrap_err()
+            .to_string(),
+        "For portability reasons we do not allow to deserialize NaNs."
+    );
+}
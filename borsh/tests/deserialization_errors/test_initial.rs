@@ -156,6 +156,49 @@ fn test_nan_float() {
     );
 }
 
+#[test]
+fn test_nan_float_in_bulk() {
+    let mut bytes = borsh::to_vec(&vec![1.0f64; 100]).unwrap();
+    bytes[4 + 8 * 50..4 + 8 * 51].copy_from_slice(&f64::NAN.to_le_bytes());
+    assert_eq!(
+        from_slice::<Vec<f64>>(&bytes).unwrap_err().to_string(),
+        "For portability reasons we do not allow to deserialize NaNs."
+    );
+
+    let bytes = [0, 0, 128, 63, 0, 0, 192, 127];
+    assert_eq!(
+        from_slice::<[f32; 2]>(&bytes).unwrap_err().to_string(),
+        "For portability reasons we do not allow to deserialize NaNs."
+    );
+}
+
+#[test]
+fn test_nan_float_serialize_in_bulk() {
+    let floats = [1.0f32, f32::NAN, 2.0];
+    assert_eq!(
+        borsh::to_vec(&floats).unwrap_err().to_string(),
+        "For portability reasons we do not allow to serialize NaNs."
+    );
+    assert_eq!(
+        borsh::to_vec(&floats.to_vec()).unwrap_err().to_string(),
+        "For portability reasons we do not allow to serialize NaNs."
+    );
+}
+
+#[test]
+fn test_missing_bytes_in_bulk() {
+    // 3 elements declared, 2.5 present
+    let bytes = [3, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0];
+    assert_eq!(
+        from_slice::<Vec<u32>>(&bytes).unwrap_err().to_string(),
+        ERROR_UNEXPECTED_LENGTH_OF_INPUT
+    );
+    assert_eq!(
+        from_slice::<[u16; 6]>(&bytes[4..]).unwrap_err().to_string(),
+        ERROR_UNEXPECTED_LENGTH_OF_INPUT
+    );
+}
+
 #[test]
 fn test_evil_bytes_vec_with_extra() {
     // Should fail to allocate given length
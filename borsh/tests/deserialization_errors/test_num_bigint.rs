@@ -0,0 +1,48 @@
+use alloc::string::ToString;
+
+use borsh::from_slice;
+use num_bigint::{BigInt, BigUint};
+
+#[test]
+fn test_big_uint_leading_zero_bytes() {
+    assert_eq!(
+        from_slice::<BigUint>(&[2, 0, 0, 0, 5, 0])
+            .unwrap_err()
+            .to_string(),
+        "BigUint magnitude has leading zero bytes"
+    );
+    assert_eq!(
+        from_slice::<BigUint>(&[1, 0, 0, 0, 0])
+            .unwrap_err()
+            .to_string(),
+        "BigUint magnitude has leading zero bytes"
+    );
+}
+
+#[test]
+fn test_big_int_invalid_sign() {
+    assert_eq!(
+        from_slice::<BigInt>(&[3, 0, 0, 0, 0])
+            .unwrap_err()
+            .to_string(),
+        "Invalid Sign variant: 3"
+    );
+}
+
+#[test]
+fn test_big_int_sign_mismatch() {
+    // `NoSign` with non-zero magnitude
+    assert_eq!(
+        from_slice::<BigInt>(&[1, 1, 0, 0, 0, 5])
+            .unwrap_err()
+            .to_string(),
+        "BigInt sign doesn't match its magnitude"
+    );
+    // negative zero
+    assert_eq!(
+        from_slice::<BigInt>(&[0, 0, 0, 0, 0])
+            .unwrap_err()
+            .to_string(),
+        "BigInt sign doesn't match its magnitude"
+    );
+}
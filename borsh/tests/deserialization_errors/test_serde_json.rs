@@ -0,0 +1,62 @@
+use alloc::{string::ToString, vec, vec::Vec};
+
+use borsh::{de::serde_json::MAX_DEPTH, from_slice};
+use serde_json::{Number, Value};
+
+#[test]
+fn test_invalid_value_tag() {
+    assert_eq!(
+        from_slice::<Value>(&[6]).unwrap_err().to_string(),
+        "Invalid JSON value representation: 6"
+    );
+}
+
+#[test]
+fn test_invalid_number_tag() {
+    assert_eq!(
+        from_slice::<Number>(&[3, 0, 0, 0, 0, 0, 0, 0, 0])
+            .unwrap_err()
+            .to_string(),
+        "Invalid JSON number representation: 3"
+    );
+}
+
+#[test]
+fn test_infinite_number() {
+    let mut bytes = vec![2];
+    bytes.extend_from_slice(&f64::INFINITY.to_le_bytes());
+    assert_eq!(
+        from_slice::<Number>(&bytes).unwrap_err().to_string(),
+        "Invalid JSON number: inf"
+    );
+}
+
+#[test]
+fn test_depth_limit_exceeded() {
+    // `MAX_DEPTH + 1` nested empty arrays
+    let mut bytes = Vec::new();
+    for _ in 0..MAX_DEPTH {
+        bytes.extend_from_slice(&[4, 1, 0, 0, 0]);
+    }
+    bytes.extend_from_slice(&[4, 0, 0, 0, 0]);
+    assert_eq!(
+        from_slice::<Value>(&bytes).unwrap_err().to_string(),
+        "JSON value exceeds the maximum nesting depth: 128"
+    );
+}
+
+#[cfg(feature = "de_strict_order")]
+#[test]
+fn test_map_wrong_order_of_keys() {
+    let bytes = [
+        2, 0, 0, 0, //
+        1, 0, 0, 0, b'b', 0, //
+        1, 0, 0, 0, b'a', 0,
+    ];
+    assert_eq!(
+        from_slice::<serde_json::Map<alloc::string::String, Value>>(&bytes)
+            .unwrap_err()
+            .to_string(),
+        "keys were not serialized in ascending order"
+    );
+}
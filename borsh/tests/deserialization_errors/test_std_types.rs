@@ -0,0 +1,64 @@
+use alloc::{ffi::CString, string::ToString, vec};
+use core::cmp::Ordering;
+use core::ops::Bound;
+use core::time::Duration;
+
+use borsh::from_slice;
+
+#[test]
+fn test_invalid_char() {
+    // surrogate code points aren't valid `char`s
+    let buf = borsh::to_vec(&0xd800u32).unwrap();
+    assert_eq!(
+        from_slice::<char>(&buf).unwrap_err().to_string(),
+        "Invalid char representation: 0xd800"
+    );
+
+    let buf = borsh::to_vec(&0x110000u32).unwrap();
+    assert_eq!(
+        from_slice::<char>(&buf).unwrap_err().to_string(),
+        "Invalid char representation: 0x110000"
+    );
+}
+
+#[test]
+fn test_invalid_duration_nanos() {
+    let buf = borsh::to_vec(&(1u64, 1_000_000_000u32)).unwrap();
+    assert_eq!(
+        from_slice::<Duration>(&buf).unwrap_err().to_string(),
+        "Nanoseconds must be less than one second"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_invalid_system_time() {
+    let buf = borsh::to_vec(&(1i64, 1_000_000_000u32)).unwrap();
+    assert_eq!(
+        from_slice::<std::time::SystemTime>(&buf)
+            .unwrap_err()
+            .to_string(),
+        "Nanoseconds must be less than one second"
+    );
+}
+
+#[test]
+fn test_c_string_interior_nul() {
+    let buf = borsh::to_vec(&vec![b'a', 0, b'b']).unwrap();
+    assert_eq!(
+        from_slice::<CString>(&buf).unwrap_err().to_string(),
+        "nul byte found in provided data at position: 1"
+    );
+}
+
+#[test]
+fn test_invalid_bound_and_ordering() {
+    assert_eq!(
+        from_slice::<Bound<u8>>(&[3]).unwrap_err().to_string(),
+        "Invalid Bound variant: 3"
+    );
+    assert_eq!(
+        from_slice::<Ordering>(&[3]).unwrap_err().to_string(),
+        "Invalid Ordering variant: 3"
+    );
+}
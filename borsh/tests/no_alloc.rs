@@ -0,0 +1,60 @@
+#![cfg(feature = "std")]
+// Checks that serialization into a caller-provided buffer doesn't allocate.
+// It's a separate test crate, as it installs a global allocator, which counts allocations.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations_during<T>(f: impl FnOnce() -> T) -> (usize, T) {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let result = f();
+    (ALLOCATIONS.load(Ordering::SeqCst) - before, result)
+}
+
+#[test]
+fn test_to_slice_and_to_array_dont_allocate() {
+    let value = (
+        "hello".to_string(),
+        vec![1u64, 2, 3],
+        Some([1u16; 4]),
+        std::collections::BTreeMap::from([(1u8, "one".to_string())]),
+    );
+    let mut buf = [0u8; 128];
+
+    let (allocations, len) = allocations_during(|| borsh::to_slice(&value, &mut buf).unwrap());
+    assert_eq!(allocations, 0);
+    assert_eq!(&buf[..len], borsh::to_vec(&value).unwrap().as_slice());
+
+    let (allocations, array) = allocations_during(|| borsh::to_array::<12>(&(1u64, 2u32)).unwrap());
+    assert_eq!(allocations, 0);
+    assert_eq!(array, [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0]);
+
+    // neither do their errors
+    let (allocations, err) =
+        allocations_during(|| borsh::to_slice(&value, &mut buf[..8]).unwrap_err());
+    assert_eq!(allocations, 0);
+    assert_eq!(err.kind(), borsh::io::ErrorKind::WriteZero);
+
+    let (allocations, err) =
+        allocations_during(|| borsh::to_array::<16>(&(1u64, 2u32)).unwrap_err());
+    assert_eq!(allocations, 0);
+    assert_eq!(err.kind(), borsh::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,150 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::{from_slice, to_vec, BorshDeserialize, BorshSerialize};
+
+/// stand-in for a large payload, which is read from a file or a socket
+struct Source {
+    data: Vec<u8>,
+}
+
+/// what's kept of the payload on the receiving side
+#[derive(Debug, PartialEq)]
+struct Digest {
+    len: usize,
+    sum: u64,
+}
+
+mod blob_impl {
+    use super::{Digest, Source};
+    use borsh::io::{Read, Write};
+
+    pub(super) fn serialize_source<W: Write>(
+        source: &Source,
+        writer: &mut W,
+    ) -> Result<(), borsh::io::Error> {
+        borsh::ser::write_blob(&mut &source.data[..], source.data.len(), writer)
+    }
+
+    pub(super) fn deserialize_digest<R: Read>(reader: &mut R) -> Result<Digest, borsh::io::Error> {
+        borsh::de::read_blob(reader, |blob| {
+            let len = blob.remaining();
+            let mut sum = 0u64;
+            let mut byte = [0u8; 1];
+            while blob.remaining() > 0 {
+                blob.read_exact(&mut byte)?;
+                sum += u64::from(byte[0]);
+            }
+            Ok(Digest { len, sum })
+        })
+    }
+}
+
+#[derive(BorshSerialize)]
+struct UploadOut {
+    name: String,
+    #[borsh(serialize_with = "blob_impl::serialize_source")]
+    payload: Source,
+    footer: u8,
+}
+
+#[derive(BorshDeserialize, Debug, PartialEq)]
+struct UploadIn {
+    name: String,
+    #[borsh(deserialize_with = "blob_impl::deserialize_digest")]
+    payload: Digest,
+    footer: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq)]
+struct Upload {
+    name: String,
+    payload: Vec<u8>,
+    footer: u8,
+}
+
+fn data() -> Vec<u8> {
+    // spans several copy buffers
+    (0..20_000u32).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn test_blob_wire_format_matches_vec() {
+    let out = UploadOut {
+        name: "snapshot".to_string(),
+        payload: Source { data: data() },
+        footer: 7,
+    };
+    let bytes = to_vec(&out).unwrap();
+    let upload = Upload {
+        name: "snapshot".to_string(),
+        payload: data(),
+        footer: 7,
+    };
+    assert_eq!(bytes, to_vec(&upload).unwrap());
+    assert_eq!(from_slice::<Upload>(&bytes).unwrap(), upload);
+}
+
+#[test]
+fn test_read_blob_in_derived_struct() {
+    let bytes = to_vec(&Upload {
+        name: "snapshot".to_string(),
+        payload: data(),
+        footer: 7,
+    })
+    .unwrap();
+    let expected = UploadIn {
+        name: "snapshot".to_string(),
+        payload: Digest {
+            len: data().len(),
+            sum: data().iter().map(|b| u64::from(*b)).sum(),
+        },
+        footer: 7,
+    };
+    assert_eq!(from_slice::<UploadIn>(&bytes).unwrap(), expected);
+    assert_eq!(
+        UploadIn::try_from_reader(&mut &bytes[..]).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_unread_contents_are_skipped() {
+    let bytes = to_vec(&(data(), 9u8)).unwrap();
+    let mut reader = &bytes[..];
+    let len = borsh::de::read_blob(&mut reader, |blob| Ok(blob.remaining())).unwrap();
+    assert_eq!(len, data().len());
+    assert_eq!(reader, &[9]);
+}
+
+#[test]
+fn test_copy_blob() {
+    let bytes = to_vec(&(data(), 9u8)).unwrap();
+    let mut reader = &bytes[..];
+    let mut sink = Vec::new();
+    assert_eq!(
+        borsh::de::copy_blob(&mut reader, &mut sink).unwrap(),
+        data().len()
+    );
+    assert_eq!(sink, data());
+    assert_eq!(reader, &[9]);
+}
+
+#[test]
+fn test_truncated_blobs() {
+    let bytes = to_vec(&data()).unwrap();
+    let err = borsh::de::copy_blob(&mut &bytes[..bytes.len() - 1], &mut Vec::new()).unwrap_err();
+    assert_eq!(err.to_string(), "Unexpected length of input");
+
+    let mut sink = Vec::new();
+    let err = borsh::ser::write_blob(&mut &[1u8, 2][..], 3, &mut sink).unwrap_err();
+    assert_eq!(err.kind(), borsh::io::ErrorKind::InvalidData);
+
+    assert_eq!(
+        from_slice::<Vec<u8>>(&to_vec(&vec![0u8; 0]).unwrap()).unwrap(),
+        Vec::<u8>::new()
+    );
+}
@@ -0,0 +1,88 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
+
+use borsh::{from_slice, to_vec, BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+enum CommandV1 {
+    #[borsh(tag = 1)]
+    Start,
+    Stop,
+    #[borsh(tag = 10)]
+    Rename {
+        name: String,
+    },
+    Resize(u32, u32),
+}
+
+/// `CommandV1` with variants reordered, `Stop` deleted and `Pause` added in its place
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+enum CommandV2 {
+    #[borsh(tag = 11)]
+    Resize(u32, u32),
+    #[borsh(tag = 2)]
+    Pause,
+    #[borsh(tag = 10)]
+    Rename { name: String },
+    #[borsh(tag = 1)]
+    Start,
+}
+
+#[test]
+fn test_explicit_tags() {
+    assert_eq!(to_vec(&CommandV1::Start).unwrap(), vec![1]);
+    assert_eq!(to_vec(&CommandV1::Stop).unwrap(), vec![2]);
+    assert_eq!(to_vec(&CommandV1::Resize(3, 4)).unwrap()[0], 11);
+    assert_eq!(to_vec(&CommandV2::Pause).unwrap(), vec![2]);
+
+    let values = vec![
+        CommandV1::Start,
+        CommandV1::Stop,
+        CommandV1::Rename { name: "x".into() },
+        CommandV1::Resize(3, 4),
+    ];
+    for value in values {
+        let encoded = to_vec(&value).unwrap();
+        assert_eq!(from_slice::<CommandV1>(&encoded).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_explicit_tags_reordered() {
+    let encoded = to_vec(&CommandV1::Rename { name: "x".into() }).unwrap();
+    assert_eq!(
+        from_slice::<CommandV2>(&encoded).unwrap(),
+        CommandV2::Rename { name: "x".into() }
+    );
+    let encoded = to_vec(&CommandV1::Resize(3, 4)).unwrap();
+    assert_eq!(
+        from_slice::<CommandV2>(&encoded).unwrap(),
+        CommandV2::Resize(3, 4)
+    );
+    let encoded = to_vec(&CommandV2::Start).unwrap();
+    assert_eq!(from_slice::<CommandV1>(&encoded).unwrap(), CommandV1::Start);
+}
+
+#[test]
+fn test_explicit_tags_unexpected() {
+    let err = from_slice::<CommandV1>(&[0]).unwrap_err();
+    assert_eq!(err.to_string(), "Unexpected variant tag: 0");
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+#[borsh(use_discriminant = false)]
+#[repr(u16)]
+enum Flags {
+    #[borsh(tag = 3)]
+    A = 1000,
+    B = 2000,
+}
+
+#[test]
+fn test_explicit_tags_non_u8_repr() {
+    assert_eq!(to_vec(&Flags::A).unwrap(), vec![3]);
+    assert_eq!(to_vec(&Flags::B).unwrap(), vec![4]);
+    assert_eq!(from_slice::<Flags>(&[4]).unwrap(), Flags::B);
+}
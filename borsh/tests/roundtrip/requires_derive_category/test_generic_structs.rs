@@ -1,7 +1,7 @@
 use borsh::{from_slice, to_vec, BorshDeserialize, BorshSerialize};
 use core::marker::PhantomData;
 
-#[cfg(feature = "hashbrown")]
+#[cfg(all(feature = "hashbrown", not(feature = "std")))]
 use hashbrown::HashMap;
 
 #[cfg(hash_collections)]
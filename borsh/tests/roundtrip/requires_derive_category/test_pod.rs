@@ -0,0 +1,120 @@
+use alloc::string::ToString;
+
+use borsh::pod::BorshPod;
+use borsh::{from_slice, to_vec, BorshDeserialize, BorshPod, BorshSerialize};
+
+#[derive(BorshPod, BorshSerialize, BorshDeserialize, Clone, Copy, PartialEq, Debug)]
+#[repr(transparent)]
+struct Lamports(u64);
+
+#[derive(BorshPod, BorshSerialize, BorshDeserialize, Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+struct Header {
+    version: u8,
+    frozen: bool,
+    kind: i16,
+    nonce: u32,
+}
+
+#[derive(BorshPod, BorshSerialize, BorshDeserialize, Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+struct Account {
+    header: Header,
+    balance: Lamports,
+    owner: [u8; 32],
+    delegates: [[u8; 4]; 2],
+    // aligns `history` without padding
+    reserved: [u8; 8],
+    history: [i128; 2],
+}
+
+/// keeps the data aligned for views of [Account]
+#[repr(C, align(16))]
+struct Aligned([u8; 96]);
+
+fn account() -> Account {
+    Account {
+        header: Header {
+            version: 1,
+            frozen: true,
+            kind: -3,
+            nonce: 77,
+        },
+        balance: Lamports(1_000_000),
+        owner: [9; 32],
+        delegates: [[1, 2, 3, 4], [5, 6, 7, 8]],
+        reserved: [0; 8],
+        history: [-1, i128::MAX],
+    }
+}
+
+fn aligned(bytes: &[u8]) -> Aligned {
+    let mut aligned = Aligned([0; 96]);
+    aligned.0.copy_from_slice(bytes);
+    aligned
+}
+
+#[test]
+fn test_layout_matches_encoding() {
+    let bytes = to_vec(&account()).unwrap();
+    assert_eq!(bytes.len(), core::mem::size_of::<Account>());
+    assert_eq!(account().as_bytes(), &bytes[..]);
+    assert_eq!(Account::read_unaligned(&bytes).unwrap(), account());
+    let data = aligned(&bytes);
+    assert_eq!(Account::from_bytes(&data.0).unwrap(), &account());
+}
+
+#[test]
+fn test_mutable_view() {
+    let mut data = aligned(&to_vec(&account()).unwrap());
+    let view = Account::from_bytes_mut(&mut data.0).unwrap();
+    view.header.frozen = false;
+    view.balance.0 -= 1;
+    view.history[0] = 5;
+
+    let expected = Account {
+        header: Header {
+            frozen: false,
+            ..account().header
+        },
+        balance: Lamports(999_999),
+        history: [5, i128::MAX],
+        ..account()
+    };
+    assert_eq!(from_slice::<Account>(&data.0).unwrap(), expected);
+}
+
+#[test]
+fn test_invalid_bool() {
+    let mut bytes = to_vec(&account()).unwrap();
+    bytes[1] = 2;
+    let err = Account::read_unaligned(&bytes).unwrap_err();
+    assert_eq!(err.to_string(), "Invalid bool representation: 2");
+    assert_eq!(
+        from_slice::<Account>(&bytes).unwrap_err().to_string(),
+        err.to_string()
+    );
+    let mut data = aligned(&bytes);
+    assert!(Account::from_bytes(&data.0).is_err());
+    assert!(Account::from_bytes_mut(&mut data.0).is_err());
+}
+
+#[test]
+fn test_length_and_alignment() {
+    let data = aligned(&to_vec(&account()).unwrap());
+    let err = Account::from_bytes(&data.0[..95]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unexpected length of input: 95 bytes, expected 96"
+    );
+    assert!(Account::read_unaligned(&data.0[..95]).is_err());
+
+    let mut shifted = [0u8; 97];
+    shifted[1..].copy_from_slice(&data.0);
+    let err = Account::from_bytes(&shifted[1..]).unwrap_err();
+    assert_eq!(err.to_string(), "Input isn't aligned to 16 bytes");
+    assert_eq!(Account::read_unaligned(&shifted[1..]).unwrap(), account());
+
+    // types of alignment 1 can be viewed anywhere
+    assert_eq!(<[u8; 4]>::from_bytes(&shifted[1..5]).unwrap(), &data.0[..4]);
+}
@@ -0,0 +1,167 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::{to_vec, BorshSerialize};
+
+#[derive(BorshSerialize, Clone)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(BorshSerialize)]
+struct Tagged<T> {
+    tag: [u8; 4],
+    value: T,
+}
+
+#[derive(BorshSerialize)]
+struct Account {
+    owner: String,
+    balance: u64,
+    points: Vec<Point>,
+    #[borsh(skip)]
+    #[allow(unused)]
+    cache: Option<String>,
+}
+
+#[derive(BorshSerialize)]
+#[borsh(version = 3)]
+struct Versioned {
+    value: u32,
+}
+
+#[derive(BorshSerialize)]
+enum Message {
+    Ping,
+    Data(u32, Vec<u8>),
+    Custom {
+        #[borsh(serialize_with = "third_party_impl::serialize_as_u64")]
+        value: u8,
+    },
+}
+
+#[derive(BorshSerialize)]
+enum Shape {
+    Empty,
+    Circle {
+        center: Point,
+        radius: u32,
+    },
+    Polygon(Vec<Point>),
+    #[borsh(unknown)]
+    Unknown {
+        tag: u8,
+        payload: Vec<u8>,
+    },
+}
+
+mod third_party_impl {
+    use borsh::io::{Result, Write};
+    use borsh::BorshSerialize;
+
+    pub(super) fn serialize_as_u64<W: Write>(value: &u8, writer: &mut W) -> Result<()> {
+        u64::from(*value).serialize(writer)
+    }
+}
+
+#[derive(BorshSerialize)]
+struct WithSerializeWith {
+    #[borsh(serialize_with = "third_party_impl::serialize_as_u64")]
+    value: u8,
+    rest: u8,
+}
+
+macro_rules! assert_size_hint {
+    ($($value: expr),+ $(,)?) => {
+        $(
+            let value = $value;
+            assert_eq!(
+                value.serialized_size_hint(),
+                Some(to_vec(&value).unwrap().len()),
+                "{}",
+                stringify!($value)
+            );
+        )+
+    };
+}
+
+fn point() -> Point {
+    Point { x: 1, y: -1 }
+}
+
+#[test]
+fn test_structs() {
+    assert_size_hint!(
+        point(),
+        Tagged {
+            tag: *b"abcd",
+            value: point(),
+        },
+        Tagged {
+            tag: *b"abcd",
+            value: "hello".to_string(),
+        },
+        Account {
+            owner: "alice".to_string(),
+            balance: 100,
+            points: vec![point(), point()],
+            cache: Some("skipped".to_string()),
+        },
+        Versioned { value: 5 },
+    );
+
+    let value = WithSerializeWith { value: 5, rest: 6 };
+    assert_eq!(value.serialized_size_hint(), None);
+    assert_eq!(value.serialized_size().unwrap(), 8 + 1);
+}
+
+#[test]
+fn test_enums() {
+    assert_size_hint!(
+        Shape::Empty,
+        Shape::Circle {
+            center: point(),
+            radius: 5,
+        },
+        Shape::Polygon(vec![point(), point(), point()]),
+        Shape::Unknown {
+            tag: 9,
+            payload: vec![1, 2, 3],
+        },
+        vec![
+            Shape::Empty,
+            Shape::Polygon(vec![point()]),
+            Shape::Circle {
+                center: point(),
+                radius: 1,
+            }
+        ],
+    );
+}
+
+#[test]
+fn test_enum_variant_without_size_hint() {
+    assert_size_hint!(Message::Ping, Message::Data(5, vec![1, 2]));
+
+    let custom = Message::Custom { value: 5 };
+    assert_eq!(custom.serialized_size_hint(), None);
+    assert_eq!(custom.serialized_size().unwrap(), 1 + 8);
+    assert_eq!(to_vec(&custom).unwrap().len(), 1 + 8);
+}
+
+#[test]
+fn test_fixed_size_structs() {
+    assert_eq!(Point::fixed_serialized_size(), Some(8));
+    assert_eq!(Tagged::<Point>::fixed_serialized_size(), Some(12));
+    assert_eq!(Tagged::<String>::fixed_serialized_size(), None);
+    assert_eq!(Versioned::fixed_serialized_size(), Some(1 + 4));
+    assert_eq!(WithSerializeWith::fixed_serialized_size(), None);
+    assert_eq!(Shape::fixed_serialized_size(), None);
+
+    let points = vec![Point { x: 0, y: 0 }; 1 << 16];
+    assert_eq!(points.serialized_size_hint(), Some(4 + (1 << 16) * 8));
+}
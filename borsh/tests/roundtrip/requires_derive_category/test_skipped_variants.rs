@@ -0,0 +1,66 @@
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
+
+use borsh::{from_slice, to_vec, BorshDeserialize, BorshSerialize};
+
+/// not serializable
+#[derive(PartialEq, Debug)]
+struct Task;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+enum State {
+    Idle,
+    #[borsh(skip)]
+    Pending(Box<Task>),
+    Done {
+        result: u32,
+    },
+}
+
+#[test]
+fn test_skipped_variant_tags_stable() {
+    assert_eq!(to_vec(&State::Idle).unwrap(), vec![0]);
+    let done = State::Done { result: 7 };
+    let encoded = to_vec(&done).unwrap();
+    assert_eq!(encoded, vec![2, 7, 0, 0, 0]);
+    assert_eq!(from_slice::<State>(&encoded).unwrap(), done);
+}
+
+#[test]
+fn test_skipped_variant_serialize_error() {
+    let err = to_vec(&State::Pending(Box::new(Task))).unwrap_err();
+    assert_eq!(err.kind(), borsh::io::ErrorKind::InvalidInput);
+    assert_eq!(
+        err.to_string(),
+        "enum variant `State::Pending` is skipped and can't be serialized"
+    );
+}
+
+#[test]
+fn test_skipped_variant_never_deserialized() {
+    let err = from_slice::<State>(&[1]).unwrap_err();
+    assert_eq!(err.to_string(), "Unexpected variant tag: 1");
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+#[allow(unused)]
+enum Relayed {
+    A(u8),
+    #[borsh(skip)]
+    Local(Task),
+    #[borsh(unknown)]
+    Unknown {
+        tag: u8,
+        payload: Vec<u8>,
+    },
+}
+
+#[test]
+fn test_skipped_variant_tag_is_unknown() {
+    assert_eq!(
+        from_slice::<Relayed>(&[1, 5]).unwrap(),
+        Relayed::Unknown {
+            tag: 1,
+            payload: vec![5]
+        }
+    );
+}
@@ -0,0 +1,115 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::{from_slice, to_vec, BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+struct AccountV1 {
+    owner: String,
+    balance: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+struct AccountV2 {
+    owner: String,
+    balance: u64,
+    #[borsh(trailing_default)]
+    frozen: bool,
+    #[borsh(trailing_default)]
+    delegates: Vec<String>,
+}
+
+#[test]
+fn test_trailing_default_absent() {
+    let old = AccountV1 {
+        owner: "alice".to_string(),
+        balance: 100,
+    };
+    let encoded = to_vec(&old).unwrap();
+    assert_eq!(
+        from_slice::<AccountV2>(&encoded).unwrap(),
+        AccountV2 {
+            owner: "alice".to_string(),
+            balance: 100,
+            frozen: false,
+            delegates: vec![],
+        }
+    );
+}
+
+#[test]
+fn test_trailing_default_partially_present() {
+    let mut encoded = to_vec(&AccountV1 {
+        owner: "alice".to_string(),
+        balance: 100,
+    })
+    .unwrap();
+    encoded.push(1);
+    let decoded = from_slice::<AccountV2>(&encoded).unwrap();
+    assert!(decoded.frozen);
+    assert!(decoded.delegates.is_empty());
+}
+
+#[test]
+fn test_trailing_default_roundtrip() {
+    let value = AccountV2 {
+        owner: "bob".to_string(),
+        balance: 7,
+        frozen: true,
+        delegates: vec!["carol".to_string()],
+    };
+    let encoded = to_vec(&value).unwrap();
+    assert_eq!(from_slice::<AccountV2>(&encoded).unwrap(), value);
+}
+
+#[test]
+fn test_trailing_default_truncated_field() {
+    let mut encoded = to_vec(&AccountV1 {
+        owner: "alice".to_string(),
+        balance: 100,
+    })
+    .unwrap();
+    // `frozen` is present, `delegates` length prefix is cut in the middle
+    encoded.extend_from_slice(&[0, 1, 0]);
+    let err = from_slice::<AccountV2>(&encoded).unwrap_err();
+    assert_eq!(err.to_string(), "Unexpected length of input");
+}
+
+#[test]
+fn test_trailing_default_in_length_prefixed_frame() {
+    #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+    struct Envelope {
+        account: Vec<u8>,
+        checksum: u32,
+    }
+
+    let envelope = Envelope {
+        account: to_vec(&AccountV1 {
+            owner: "alice".to_string(),
+            balance: 100,
+        })
+        .unwrap(),
+        checksum: 42,
+    };
+    let decoded = from_slice::<Envelope>(&to_vec(&envelope).unwrap()).unwrap();
+    let account = from_slice::<AccountV2>(&decoded.account).unwrap();
+    assert_eq!(account.balance, 100);
+    assert!(!account.frozen);
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+enum Event {
+    Created(u64, #[borsh(trailing_default)] Option<u32>),
+}
+
+#[test]
+fn test_trailing_default_enum_variant() {
+    let encoded = vec![0, 1, 0, 0, 0, 0, 0, 0, 0];
+    assert_eq!(
+        from_slice::<Event>(&encoded).unwrap(),
+        Event::Created(1, None)
+    );
+}
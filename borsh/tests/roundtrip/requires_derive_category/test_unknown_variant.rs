@@ -0,0 +1,107 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use borsh::{from_slice, to_vec, BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+enum MessageV1 {
+    Ping(u64),
+    Text {
+        body: String,
+    },
+    #[borsh(unknown)]
+    Unknown {
+        tag: u8,
+        payload: Vec<u8>,
+    },
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+enum MessageV2 {
+    Ping(u64),
+    Text {
+        body: String,
+    },
+    Transfer {
+        amount: u128,
+        memo: String,
+    },
+    #[borsh(unknown)]
+    Unknown(u8, Vec<u8>),
+}
+
+#[test]
+fn test_known_variants_roundtrip() {
+    let values = vec![
+        MessageV1::Ping(42),
+        MessageV1::Text {
+            body: "hello".into(),
+        },
+    ];
+    for value in values {
+        let encoded = to_vec(&value).unwrap();
+        assert_eq!(from_slice::<MessageV1>(&encoded).unwrap(), value);
+    }
+    assert_eq!(to_vec(&MessageV1::Ping(42)).unwrap()[0], 0);
+    assert_eq!(to_vec(&MessageV2::Ping(42)).unwrap()[0], 0);
+}
+
+#[test]
+fn test_unknown_variant_relay() {
+    let newer = MessageV2::Transfer {
+        amount: 1_000_000,
+        memo: "rent".into(),
+    };
+    let encoded = to_vec(&newer).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let older = from_slice::<MessageV1>(&encoded).unwrap();
+    assert_eq!(
+        older,
+        MessageV1::Unknown {
+            tag: 2,
+            payload: encoded[1..].to_vec(),
+        }
+    );
+
+    let relayed = to_vec(&older).unwrap();
+    assert_eq!(relayed, encoded);
+    assert_eq!(from_slice::<MessageV2>(&relayed).unwrap(), newer);
+}
+
+#[test]
+fn test_unknown_variant_empty_payload() {
+    let older = from_slice::<MessageV1>(&[7]).unwrap();
+    assert_eq!(
+        older,
+        MessageV1::Unknown {
+            tag: 7,
+            payload: vec![],
+        }
+    );
+    assert_eq!(to_vec(&older).unwrap(), vec![7]);
+}
+
+#[test]
+fn test_unknown_variant_in_length_prefixed_envelope() {
+    #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+    struct Envelope {
+        message: Vec<u8>,
+        nonce: u32,
+    }
+
+    let newer = MessageV2::Transfer {
+        amount: 5,
+        memo: "memo".into(),
+    };
+    let envelope = Envelope {
+        message: to_vec(&newer).unwrap(),
+        nonce: 3,
+    };
+    let encoded = to_vec(&envelope).unwrap();
+
+    let decoded = from_slice::<Envelope>(&encoded).unwrap();
+    let older = from_slice::<MessageV1>(&decoded.message).unwrap();
+    assert!(matches!(older, MessageV1::Unknown { tag: 2, .. }));
+    assert_eq!(to_vec(&older).unwrap(), envelope.message);
+}
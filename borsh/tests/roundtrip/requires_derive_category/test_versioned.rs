@@ -0,0 +1,110 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::{from_slice, to_vec, BorshDeserialize, BorshSerialize};
+
+/// layout of `State` as it was written with `#[borsh(version = 1)]`
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+struct StateV1 {
+    owner: String,
+}
+
+/// layout of `State` as it was written with `#[borsh(version = 2)]`
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+struct StateV2 {
+    owner: String,
+    balance: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+#[borsh(version = 3, migrate_from(StateV1 = 1, StateV2 = 2))]
+struct State {
+    owner: String,
+    balance: u64,
+    delegates: Vec<String>,
+}
+
+impl From<StateV1> for State {
+    fn from(value: StateV1) -> Self {
+        StateV2 {
+            owner: value.owner,
+            balance: 0,
+        }
+        .into()
+    }
+}
+
+impl From<StateV2> for State {
+    fn from(value: StateV2) -> Self {
+        Self {
+            owner: value.owner,
+            balance: value.balance,
+            delegates: vec![],
+        }
+    }
+}
+
+fn with_version(version: u8, value: &impl BorshSerialize) -> Vec<u8> {
+    let mut result = vec![version];
+    result.extend(to_vec(value).unwrap());
+    result
+}
+
+#[test]
+fn test_versioned_roundtrip() {
+    let value = State {
+        owner: "alice".to_string(),
+        balance: 10,
+        delegates: vec!["bob".to_string()],
+    };
+    let encoded = to_vec(&value).unwrap();
+    assert_eq!(encoded[0], 3);
+    assert_eq!(
+        &encoded[1..],
+        &to_vec(&("alice", 10u64, vec!["bob"])).unwrap()[..]
+    );
+    assert_eq!(from_slice::<State>(&encoded).unwrap(), value);
+}
+
+#[test]
+fn test_versioned_migration() {
+    let v1 = with_version(
+        1,
+        &StateV1 {
+            owner: "alice".to_string(),
+        },
+    );
+    assert_eq!(
+        from_slice::<State>(&v1).unwrap(),
+        State {
+            owner: "alice".to_string(),
+            balance: 0,
+            delegates: vec![],
+        }
+    );
+
+    let v2 = with_version(
+        2,
+        &StateV2 {
+            owner: "alice".to_string(),
+            balance: 5,
+        },
+    );
+    assert_eq!(
+        from_slice::<State>(&v2).unwrap(),
+        State {
+            owner: "alice".to_string(),
+            balance: 5,
+            delegates: vec![],
+        }
+    );
+}
+
+#[test]
+fn test_versioned_unknown_version() {
+    let err = from_slice::<State>(&[4]).unwrap_err();
+    assert_eq!(err.to_string(), "Unexpected version: 4");
+}
@@ -0,0 +1,159 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::{to_vec, BorshDeserialize, BorshSerialize, BorshView};
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshView, PartialEq, Debug)]
+struct Record {
+    id: u64,
+    origin: Point,
+    path: Vec<Point>,
+    label: Option<String>,
+    tags: Vec<String>,
+    values: [u16; 3],
+    result: Result<u8, String>,
+    #[borsh(skip)]
+    cache: u32,
+    flag: bool,
+}
+
+fn record() -> Record {
+    Record {
+        id: 42,
+        origin: Point { x: -1, y: 1 },
+        path: vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }],
+        label: Some("route".to_string()),
+        tags: vec!["a".to_string(), "bc".to_string()],
+        values: [5, 6, 7],
+        result: Err("failed".to_string()),
+        cache: 0,
+        flag: true,
+    }
+}
+
+#[test]
+fn test_view_accessors() {
+    let record = record();
+    let data = to_vec(&record).unwrap();
+    let view = RecordView::new(&data);
+
+    assert_eq!(view.as_bytes(), &data[..]);
+    assert_eq!(view.id().unwrap(), record.id);
+    assert_eq!(view.origin().unwrap(), record.origin);
+    assert_eq!(view.path().unwrap(), record.path);
+    assert_eq!(view.label().unwrap(), record.label);
+    assert_eq!(view.tags().unwrap(), record.tags);
+    assert_eq!(view.values().unwrap(), record.values);
+    assert_eq!(view.result().unwrap(), record.result);
+    assert_eq!(view.flag().unwrap(), record.flag);
+}
+
+#[test]
+fn test_view_truncated_input() {
+    let data = to_vec(&record()).unwrap();
+    let view = RecordView::new(&data[..data.len() - 1]);
+
+    assert_eq!(view.result().unwrap(), Err("failed".to_string()));
+    assert_eq!(
+        view.flag().unwrap_err().to_string(),
+        "Unexpected length of input"
+    );
+}
+
+#[test]
+fn test_view_invalid_preceding_field() {
+    let mut data = to_vec(&record()).unwrap();
+    // `label` option flag
+    data[36] = 2;
+    let view = RecordView::new(&data);
+
+    assert_eq!(view.path().unwrap(), record().path);
+    assert_eq!(
+        view.flag().unwrap_err().to_string(),
+        "Invalid Option representation: 2. The first byte must be 0 or 1"
+    );
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshView, PartialEq, Debug)]
+struct Extended {
+    name: String,
+    #[borsh(trailing_default)]
+    limit: Option<u32>,
+    #[borsh(trailing_default)]
+    aliases: Vec<String>,
+}
+
+#[test]
+fn test_view_trailing_default() {
+    let data = to_vec(&"old".to_string()).unwrap();
+    let view = ExtendedView::new(&data);
+
+    assert_eq!(view.name().unwrap(), "old");
+    assert_eq!(view.limit().unwrap(), None);
+    assert_eq!(view.aliases().unwrap(), Vec::<String>::new());
+
+    let extended = Extended {
+        name: "new".to_string(),
+        limit: Some(3),
+        aliases: vec!["n".to_string()],
+    };
+    let data = to_vec(&extended).unwrap();
+    let view = ExtendedView::new(&data);
+
+    assert_eq!(view.limit().unwrap(), extended.limit);
+    assert_eq!(view.aliases().unwrap(), extended.aliases);
+}
+
+#[test]
+fn test_skip() {
+    let record = record();
+    let mut data = to_vec(&record).unwrap();
+    data.extend_from_slice(&[1, 2]);
+
+    let mut buf = &data[..];
+    Record::skip(&mut buf).unwrap();
+    assert_eq!(buf, &[1, 2]);
+
+    let mut buf = &data[..data.len() - 3];
+    assert!(Record::skip(&mut buf).is_err());
+}
+
+#[allow(unused)]
+#[derive(BorshDeserialize, BorshView)]
+struct Timed {
+    elapsed: core::time::Duration,
+    count: core::num::NonZeroU32,
+    initial: char,
+    label: String,
+}
+
+#[test]
+fn test_skip_fixed_size_fields_unvalidated() {
+    // invalid nanoseconds, zero count and a surrogate code point
+    let data = to_vec(&(7u64, u32::MAX, 0u32, 0xd800u32, "ok".to_string())).unwrap();
+
+    let view = TimedView::new(&data);
+    assert!(view.elapsed().is_err());
+    assert!(view.count().is_err());
+    assert!(view.initial().is_err());
+    assert_eq!(view.label().unwrap(), "ok");
+
+    let mut buf = &data[..];
+    core::time::Duration::skip(&mut buf).unwrap();
+    core::num::NonZeroU32::skip(&mut buf).unwrap();
+    char::skip(&mut buf).unwrap();
+    assert_eq!(String::deserialize(&mut buf).unwrap(), "ok");
+
+    let mut buf = &data[..];
+    Timed::skip(&mut buf).unwrap();
+    assert!(buf.is_empty());
+}
@@ -0,0 +1,31 @@
+use alloc::{vec, vec::Vec};
+
+use arrayvec::{ArrayString, ArrayVec};
+use borsh::{from_slice, to_vec};
+
+#[test]
+fn test_arrayvec_roundtrip() {
+    let mut items = ArrayVec::<u16, 4>::new();
+    items.extend([10, 20, 30]);
+    let want = (items, ArrayString::<8>::from("żółw").unwrap());
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+    assert_eq!(encoded, to_vec(&(vec![10u16, 20, 30], "żółw")).unwrap());
+
+    let got: (ArrayVec<u16, 4>, ArrayString<8>) = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_arrayvec_full_capacity() {
+    let want: ArrayVec<u8, 3> = [1, 2, 3].into();
+
+    let encoded = to_vec(&want).unwrap();
+    let got: ArrayVec<u8, 3> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+
+    let got: Vec<u8> = from_slice(&encoded).unwrap();
+    assert_eq!(got, vec![1, 2, 3]);
+}
@@ -0,0 +1,62 @@
+use alloc::{vec, vec::Vec};
+
+use bitflags::bitflags;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Permissions: u16 {
+        const READ = 0b001;
+        const WRITE = 0b010;
+        const EXECUTE = 0b100;
+    }
+}
+
+#[test]
+fn test_bitflags_roundtrip() {
+    let want = Permissions::READ | Permissions::EXECUTE;
+
+    let mut encoded = Vec::new();
+    borsh::ser::bitflags::serialize(&want, &mut encoded).unwrap();
+    assert_eq!(encoded, vec![0b101, 0]);
+
+    let got: Permissions = borsh::de::bitflags::deserialize(&mut encoded.as_slice()).unwrap();
+    assert_eq!(want, got);
+    let got: Permissions =
+        borsh::de::bitflags::deserialize_strict(&mut encoded.as_slice()).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_bitflags_unknown_bits_retained() {
+    let encoded = [0b1001, 0];
+    let got: Permissions = borsh::de::bitflags::deserialize(&mut &encoded[..]).unwrap();
+    assert_eq!(got.bits(), 0b1001);
+
+    let mut reencoded = Vec::new();
+    borsh::ser::bitflags::serialize(&got, &mut reencoded).unwrap();
+    assert_eq!(reencoded, encoded);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_bitflags_field() {
+    use borsh::{from_slice, to_vec, BorshDeserialize, BorshSerialize};
+
+    #[derive(Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+    struct Account {
+        id: u64,
+        #[borsh(
+            serialize_with = "borsh::ser::bitflags::serialize",
+            deserialize_with = "borsh::de::bitflags::deserialize_strict"
+        )]
+        permissions: Permissions,
+    }
+
+    let want = Account {
+        id: 7,
+        permissions: Permissions::WRITE,
+    };
+    let encoded = to_vec(&want).unwrap();
+    assert_eq!(encoded, vec![7, 0, 0, 0, 0, 0, 0, 0, 0b010, 0]);
+    assert_eq!(from_slice::<Account>(&encoded).unwrap(), want);
+}
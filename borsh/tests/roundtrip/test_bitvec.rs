@@ -0,0 +1,37 @@
+use alloc::{vec, vec::Vec};
+
+use bitvec::prelude::*;
+use borsh::{from_slice, to_vec};
+
+#[test]
+fn test_bitvec_roundtrip() {
+    let want: Vec<BitVec> = vec![
+        BitVec::new(),
+        bitvec![1],
+        bitvec![1, 0, 1, 1, 0, 0, 0, 1],
+        bitvec![0, 1, 1, 0, 1, 0, 0, 1, 1, 1, 0],
+        BitVec::repeat(true, 100),
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<BitVec> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_bitvec_layout() {
+    assert_eq!(to_vec(&BitVec::<u8>::new()).unwrap(), vec![0, 0, 0, 0]);
+    // bit `i` is stored at position `i % 8` of byte `i / 8`, unused bits are zero
+    let bits: BitVec = bitvec![1, 0, 1, 1, 0, 0, 0, 1, 1, 1];
+    let expected = vec![10, 0, 0, 0, 0b1000_1101, 0b0000_0011];
+    assert_eq!(to_vec(&bits).unwrap(), expected);
+    // the layout doesn't depend on storage type and bit order
+    let bits: BitVec<u32, Msb0> = bitvec![u32, Msb0; 1, 0, 1, 1, 0, 0, 0, 1, 1, 1];
+    assert_eq!(to_vec(&bits).unwrap(), expected);
+    assert_eq!(from_slice::<BitVec<u32, Msb0>>(&expected).unwrap(), bits);
+    // a slice is serialized the same way
+    assert_eq!(to_vec(&bits[..]).unwrap(), expected);
+}
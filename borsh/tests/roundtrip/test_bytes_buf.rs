@@ -0,0 +1,129 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::io::{ErrorKind, Result, Write};
+use borsh::{from_buf, from_slice, to_buf_mut, to_vec, BorshSerialize};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+type Message = (u64, String, Vec<u8>, Bytes, Option<u32>);
+
+fn message() -> Message {
+    (
+        42,
+        "borsh".to_string(),
+        vec![1, 2, 3],
+        Bytes::from(vec![7u8; 1000]),
+        Some(5),
+    )
+}
+
+/// splits `bytes` into a chain of buffers at `split`
+fn chained(bytes: &[u8], split: usize) -> impl Buf {
+    let (head, tail) = bytes.split_at(split);
+    Bytes::copy_from_slice(head).chain(Bytes::copy_from_slice(tail))
+}
+
+#[test]
+fn test_from_buf_contiguous() {
+    let encoded = to_vec(&message()).unwrap();
+    assert_eq!(from_buf::<_, Message>(&encoded[..]).unwrap(), message());
+    assert_eq!(
+        from_buf::<_, Message>(Bytes::from(encoded)).unwrap(),
+        message()
+    );
+}
+
+#[test]
+fn test_from_buf_chained() {
+    let encoded = to_vec(&message()).unwrap();
+    for split in 0..=encoded.len() {
+        assert_eq!(
+            from_buf::<_, Message>(chained(&encoded, split)).unwrap(),
+            message(),
+            "split at {}",
+            split
+        );
+    }
+}
+
+#[test]
+fn test_from_buf_errors() {
+    let encoded = to_vec(&message()).unwrap();
+    for len in [0, 1, 9, 20, encoded.len() - 1] {
+        let err = from_buf::<_, Message>(chained(&encoded[..len], len / 2)).unwrap_err();
+        let expected = from_slice::<Message>(&encoded[..len]).unwrap_err();
+        assert_eq!(err.to_string(), expected.to_string());
+    }
+    let mut trailing = encoded.clone();
+    trailing.push(0);
+    let err = from_buf::<_, Message>(chained(&trailing, 10)).unwrap_err();
+    assert_eq!(err.to_string(), "Not all bytes read");
+}
+
+#[test]
+fn test_from_buf_shares_bytes_allocation() {
+    let encoded = Bytes::from(to_vec(&(1u8, Bytes::from(vec![7u8; 1000]))).unwrap());
+    let range = encoded.as_ptr_range();
+    let (_, payload) = from_buf::<_, (u8, Bytes)>(encoded.clone()).unwrap();
+    assert_eq!(payload.len(), 1000);
+    assert!(range.contains(&payload.as_ptr()));
+
+    // a `Bytes` source is advanced past the decoded value, when borrowed
+    let mut source = encoded.clone();
+    let (_, payload) = from_buf::<_, (u8, Bytes)>(&mut source).unwrap();
+    assert!(range.contains(&payload.as_ptr()));
+    assert!(source.is_empty());
+
+    let mut encoded = BytesMut::from(&encoded[..]);
+    let range = encoded.as_ptr_range();
+    let (_, payload) = from_buf::<_, (u8, Bytes)>(&mut encoded).unwrap();
+    assert!(range.contains(&payload.as_ptr()));
+}
+
+#[test]
+fn test_to_buf_mut() {
+    let mut buf = BytesMut::new();
+    to_buf_mut(&message(), &mut buf).unwrap();
+    assert_eq!(&buf[..], &to_vec(&message()).unwrap()[..]);
+
+    // appends to what's already in the buffer
+    let mut buf = vec![9u8];
+    to_buf_mut(&5u16, &mut buf).unwrap();
+    assert_eq!(buf, vec![9, 5, 0]);
+
+    let mut array = [0u8; 16];
+    let mut slice = &mut array[..];
+    to_buf_mut(&(1u8, 2u32), &mut slice).unwrap();
+    assert_eq!(slice.remaining_mut(), 11);
+    let err = to_buf_mut(&[0u64; 2], &mut slice).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+    assert_eq!(
+        err.to_string(),
+        "Buffer too small: 16 bytes required, 11 available"
+    );
+    assert_eq!(&array[..6], &[1, 2, 0, 0, 0, 0]);
+}
+
+/// Type without size hint.
+struct Unsized(u64);
+
+impl BorshSerialize for Unsized {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+#[test]
+fn test_to_buf_mut_without_size_hint() {
+    let mut buf = BytesMut::new();
+    to_buf_mut(&Unsized(5), &mut buf).unwrap();
+    assert_eq!(&buf[..], &to_vec(&5u64).unwrap()[..]);
+
+    // written until the buffer runs out of capacity
+    let mut array = [0u8; 4];
+    let err = to_buf_mut(&Unsized(5), &mut array[..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+}
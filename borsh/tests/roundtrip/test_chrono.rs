@@ -0,0 +1,56 @@
+use alloc::{vec, vec::Vec};
+
+use borsh::{from_slice, to_vec};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+#[test]
+fn test_chrono_date_time_roundtrip() {
+    let want: Vec<DateTime<Utc>> = vec![
+        DateTime::UNIX_EPOCH,
+        DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap(),
+        DateTime::from_timestamp(-2, 500_000_000).unwrap(),
+        DateTime::<Utc>::MIN_UTC,
+        DateTime::<Utc>::MAX_UTC,
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<DateTime<Utc>> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+
+    let naive: Vec<NaiveDateTime> = from_slice(&encoded).unwrap();
+    assert_eq!(to_vec(&naive).unwrap(), encoded);
+}
+
+#[test]
+fn test_chrono_date_time_encoding() {
+    // UNIX_EPOCH - 1.5s
+    let value = DateTime::from_timestamp_millis(-1_500).unwrap();
+    assert_eq!(
+        to_vec(&value).unwrap(),
+        to_vec(&(-2i64, 500_000_000u32)).unwrap()
+    );
+}
+
+#[test]
+fn test_chrono_naive_date_roundtrip() {
+    let want = vec![
+        NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+        NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(),
+        NaiveDate::MIN,
+        NaiveDate::MAX,
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<NaiveDate> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+
+    assert_eq!(to_vec(&want[0]).unwrap(), to_vec(&0i32).unwrap());
+    assert_eq!(to_vec(&want[2]).unwrap(), to_vec(&-1i32).unwrap());
+}
@@ -0,0 +1,61 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::{from_slice, to_vec};
+
+fn strings() -> Vec<String> {
+    vec![
+        String::new(),
+        "alice.near".to_string(),
+        // 23 and 24 bytes are the bounds of inline storage
+        "a".repeat(23),
+        "b".repeat(24),
+        "ünïcödé-àccöünt-ïdéntïfïér".to_string(),
+        "x".repeat(1000),
+    ]
+}
+
+#[cfg(feature = "smol_str")]
+#[test]
+fn test_smol_str_roundtrip() {
+    use smol_str::SmolStr;
+
+    let want = strings().into_iter().map(SmolStr::from).collect::<Vec<_>>();
+
+    let encoded = to_vec(&want).unwrap();
+    // `SmolStr` is serialized the same way as `String`
+    assert_eq!(encoded, to_vec(&strings()).unwrap());
+
+    let got: Vec<SmolStr> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+    assert!(!got[1].is_heap_allocated());
+    assert!(got[5].is_heap_allocated());
+
+    // longer than strings, which are allocated before their bytes are read
+    let want = SmolStr::from("z".repeat(1024 * 1024 + 1));
+    let got: SmolStr = from_slice(&to_vec(&want).unwrap()).unwrap();
+    assert_eq!(want, got);
+}
+
+#[cfg(feature = "compact_str")]
+#[test]
+fn test_compact_str_roundtrip() {
+    use compact_str::CompactString;
+
+    let want = strings()
+        .into_iter()
+        .map(CompactString::from)
+        .collect::<Vec<_>>();
+
+    let encoded = to_vec(&want).unwrap();
+    // `CompactString` is serialized the same way as `String`
+    assert_eq!(encoded, to_vec(&strings()).unwrap());
+
+    let got: Vec<CompactString> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+    assert!(!got[1].is_heap_allocated());
+    assert!(got[5].is_heap_allocated());
+}
@@ -0,0 +1,36 @@
+use borsh::{from_slice, to_vec};
+use ethnum::{I256, U256};
+
+#[test]
+fn test_ethnum_roundtrip() {
+    let want = (U256::MAX - 1, I256::MIN, I256::new(-2));
+
+    let encoded = to_vec(&want).unwrap();
+    assert_eq!(encoded.len(), 3 * 32);
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_ethnum_matches_primitive_integers() {
+    assert_eq!(
+        to_vec(&U256::new(u128::MAX)).unwrap(),
+        to_vec(&(u128::MAX, 0u128)).unwrap()
+    );
+    assert_eq!(
+        to_vec(&I256::new(-1)).unwrap(),
+        to_vec(&(-1i128, -1i128)).unwrap()
+    );
+}
+
+#[cfg(feature = "primitive-types")]
+#[test]
+fn test_ethnum_u256_matches_primitive_types_u256() {
+    let value = U256::from_words(0x0102, 0x0304);
+    let encoded = to_vec(&value).unwrap();
+    let other: primitive_types::U256 = from_slice(&encoded).unwrap();
+    assert_eq!(to_vec(&other).unwrap(), encoded);
+}
@@ -0,0 +1,39 @@
+use alloc::{vec, vec::Vec};
+
+use borsh::{from_slice, to_vec};
+use half::{bf16, f16};
+
+#[test]
+fn test_f16_roundtrip() {
+    let want = vec![
+        f16::ZERO,
+        f16::ONE,
+        f16::from_f32(-2.5),
+        f16::MAX,
+        f16::INFINITY,
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<f16> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_bf16_roundtrip() {
+    let want = vec![bf16::ZERO, bf16::ONE, bf16::from_f32(-2.5), bf16::MIN];
+
+    let encoded = to_vec(&want).unwrap();
+    assert_eq!(to_vec(&bf16::ONE).unwrap(), vec![0x80, 0x3f]);
+
+    let got: Vec<bf16> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_half_nan() {
+    assert!(to_vec(&f16::NAN).is_err());
+    assert!(to_vec(&bf16::NAN).is_err());
+}
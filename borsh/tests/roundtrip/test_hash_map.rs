@@ -1,7 +1,7 @@
 #[cfg(feature = "std")]
 use core::hash::BuildHasher;
 
-#[cfg(feature = "hashbrown")]
+#[cfg(all(feature = "hashbrown", not(feature = "std")))]
 use hashbrown::{HashMap, HashSet};
 #[cfg(feature = "std")]
 use std::collections::{
@@ -211,3 +211,22 @@ map_wrong_order_test!(test_hashmap_deser_err_wrong_order, HashMap<String, String
 
 #[cfg(feature = "std")]
 map_wrong_order_test!(test_generic_hashmap_deser_err_wrong_order, HashMap<String, String, NewHasher>);
+
+#[cfg(all(feature = "std", feature = "hashbrown"))]
+#[test]
+fn test_hashbrown_alongside_std() {
+    let std_map: HashMap<u32, String> = (0..20).map(|i| (i, i.to_string())).collect();
+    let hashbrown_map: hashbrown::HashMap<u32, String> = std_map.clone().into_iter().collect();
+    let std_set: HashSet<u32> = (0..20).collect();
+    let hashbrown_set: hashbrown::HashSet<u32> = std_set.iter().copied().collect();
+
+    let data = borsh::to_vec(&hashbrown_map).unwrap();
+    assert_eq!(data, borsh::to_vec(&std_map).unwrap());
+    let actual_map = from_slice::<hashbrown::HashMap<u32, String>>(&data).unwrap();
+    assert_eq!(hashbrown_map, actual_map);
+
+    let data = borsh::to_vec(&hashbrown_set).unwrap();
+    assert_eq!(data, borsh::to_vec(&std_set).unwrap());
+    let actual_set = from_slice::<hashbrown::HashSet<u32>>(&data).unwrap();
+    assert_eq!(hashbrown_set, actual_set);
+}
@@ -0,0 +1,19 @@
+use alloc::vec;
+use core::convert::TryFrom;
+
+use borsh::{from_slice, to_vec};
+
+#[test]
+fn test_heapless_roundtrip() {
+    let mut items = heapless::Vec::<i64, 4>::new();
+    items.extend_from_slice(&[-1, 0, 1]).unwrap();
+    let want = (items, heapless::String::<16>::try_from("firmware").unwrap());
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+    assert_eq!(encoded, to_vec(&(vec![-1i64, 0, 1], "firmware")).unwrap());
+
+    let got: (heapless::Vec<i64, 4>, heapless::String<16>) = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
@@ -0,0 +1,73 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::{from_slice, to_vec, BorshSerialize};
+use im::{HashMap, OrdMap, Vector};
+
+#[test]
+fn test_im_hashmap_roundtrip() {
+    let mut map = HashMap::<String, u32>::new();
+    map.insert("various".to_string(), 1);
+    map.insert("foo".to_string(), 2);
+    map.insert("many".to_string(), 3);
+
+    let encoded = to_vec(&map).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    // keys are written in ascending order, the same way as for `BTreeMap`
+    let btree = map
+        .iter()
+        .map(|(k, v)| (k.clone(), *v))
+        .collect::<alloc::collections::BTreeMap<_, _>>();
+    assert_eq!(encoded, to_vec(&btree).unwrap());
+
+    let decoded = from_slice::<HashMap<String, u32>>(&encoded).unwrap();
+    assert_eq!(map, decoded);
+}
+
+#[test]
+fn test_im_ordmap_roundtrip() {
+    let mut map = OrdMap::<String, String>::new();
+    map.insert("key".to_string(), "powers".to_string());
+    map.insert("more".to_string(), "of".to_string());
+    map.insert("different".to_string(), "two".to_string());
+
+    let encoded = to_vec(&map).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let btree = map
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<alloc::collections::BTreeMap<_, _>>();
+    assert_eq!(encoded, to_vec(&btree).unwrap());
+
+    let decoded = from_slice::<OrdMap<String, String>>(&encoded).unwrap();
+    assert_eq!(map, decoded);
+}
+
+#[test]
+fn test_im_vector_roundtrip() {
+    let vector: Vector<u16> = (0..300).collect();
+
+    let encoded = to_vec(&vector).unwrap();
+    // `Vector<T>` is serialized the same way as `Vec<T>`
+    assert_eq!(encoded, to_vec(&(0..300).collect::<Vec<u16>>()).unwrap());
+
+    let decoded = from_slice::<Vector<u16>>(&encoded).unwrap();
+    assert_eq!(vector, decoded);
+
+    let empty = Vector::<u16>::new();
+    assert_eq!(to_vec(&empty).unwrap(), vec![0, 0, 0, 0]);
+}
+
+#[cfg(feature = "de_strict_order")]
+const ERROR_WRONG_ORDER_OF_KEYS: &str = "keys were not serialized in ascending order";
+
+map_wrong_order_test!(test_im_hashmap_deser_err_wrong_order, HashMap<String, String>);
+
+map_wrong_order_test!(test_im_ordmap_deser_err_wrong_order, OrdMap<String, String>);
@@ -0,0 +1,63 @@
+use alloc::{vec, vec::Vec};
+
+use borsh::{from_slice, to_vec};
+use jiff::{civil, Timestamp};
+
+#[test]
+fn test_jiff_timestamp_roundtrip() {
+    let want = vec![
+        Timestamp::UNIX_EPOCH,
+        Timestamp::new(1_700_000_000, 123_456_789).unwrap(),
+        Timestamp::new(-1, -500_000_000).unwrap(),
+        Timestamp::MIN,
+        Timestamp::MAX,
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<Timestamp> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+
+    // nanoseconds are non-negative on the wire
+    assert_eq!(
+        to_vec(&want[2]).unwrap(),
+        to_vec(&(-2i64, 500_000_000u32)).unwrap()
+    );
+}
+
+#[test]
+fn test_jiff_date_roundtrip() {
+    let want = vec![
+        civil::date(1970, 1, 1),
+        civil::date(2024, 2, 29),
+        civil::date(1969, 12, 31),
+        civil::Date::MIN,
+        civil::Date::MAX,
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<civil::Date> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+
+    assert_eq!(to_vec(&want[0]).unwrap(), to_vec(&0i32).unwrap());
+    assert_eq!(to_vec(&want[2]).unwrap(), to_vec(&-1i32).unwrap());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_jiff_matches_chrono() {
+    let timestamp = Timestamp::new(-1_000, -1).unwrap();
+    let encoded = to_vec(&timestamp).unwrap();
+    let datetime: chrono::DateTime<chrono::Utc> = from_slice(&encoded).unwrap();
+    assert_eq!(datetime.timestamp_nanos_opt(), Some(-1_000_000_000_001));
+
+    let date = civil::date(2024, 2, 29);
+    let encoded = to_vec(&date).unwrap();
+    let naive: chrono::NaiveDate = from_slice(&encoded).unwrap();
+    assert_eq!(naive, chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+}
@@ -0,0 +1,52 @@
+use alloc::{vec, vec::Vec};
+
+use borsh::{from_slice, to_vec};
+use num_bigint::{BigInt, BigUint};
+
+#[test]
+fn test_big_uint_roundtrip() {
+    let want = vec![
+        BigUint::from(0u8),
+        BigUint::from(1u8),
+        BigUint::from(0x0100u16),
+        BigUint::from(u128::MAX) * BigUint::from(u128::MAX),
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<BigUint> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_big_uint_canonical_encoding() {
+    // zero has an empty magnitude
+    assert_eq!(to_vec(&BigUint::from(0u8)).unwrap(), vec![0, 0, 0, 0]);
+    // no leading zero bytes
+    assert_eq!(
+        to_vec(&BigUint::from(0x0100u16)).unwrap(),
+        vec![2, 0, 0, 0, 0x00, 0x01]
+    );
+}
+
+#[test]
+fn test_big_int_roundtrip() {
+    let want = vec![
+        BigInt::from(0),
+        BigInt::from(-1),
+        BigInt::from(i64::MIN),
+        BigInt::from(u128::MAX) * BigInt::from(-3),
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<BigInt> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+
+    assert_eq!(to_vec(&BigInt::from(0)).unwrap(), vec![1, 0, 0, 0, 0]);
+    assert_eq!(to_vec(&BigInt::from(-5)).unwrap(), vec![0, 1, 0, 0, 0, 5]);
+}
@@ -0,0 +1,42 @@
+use alloc::{vec, vec::Vec};
+
+use borsh::{from_slice, to_vec};
+use ordered_float::{NotNan, OrderedFloat};
+
+#[test]
+fn test_ordered_float_roundtrip() {
+    let want = vec![
+        OrderedFloat(0.0f64),
+        OrderedFloat(-1.5),
+        OrderedFloat(f64::INFINITY),
+        OrderedFloat(f64::MIN_POSITIVE),
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    assert_eq!(
+        encoded,
+        to_vec(&vec![0.0f64, -1.5, f64::INFINITY, f64::MIN_POSITIVE]).unwrap()
+    );
+
+    let got: Vec<OrderedFloat<f64>> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_ordered_float_nan() {
+    assert!(to_vec(&OrderedFloat(f32::NAN)).is_err());
+}
+
+#[test]
+fn test_not_nan_roundtrip() {
+    let want = vec![
+        NotNan::new(1.25f32).unwrap(),
+        NotNan::new(f32::NEG_INFINITY).unwrap(),
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    assert_eq!(encoded, to_vec(&vec![1.25f32, f32::NEG_INFINITY]).unwrap());
+
+    let got: Vec<NotNan<f32>> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
@@ -0,0 +1,28 @@
+use borsh::{from_slice, to_vec};
+use primitive_types::{U128, U256, U512};
+
+#[test]
+fn test_primitive_types_roundtrip() {
+    let want = (
+        U128::from(0x0102_0304u32),
+        U256::MAX - U256::from(1u8),
+        U512::from(u128::MAX) << 200,
+    );
+
+    let encoded = to_vec(&want).unwrap();
+    assert_eq!(encoded.len(), 16 + 32 + 64);
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_u256_is_little_endian() {
+    let encoded = to_vec(&U256::from(0x0102u16)).unwrap();
+    let mut expected = [0u8; 32];
+    expected[0] = 0x02;
+    expected[1] = 0x01;
+    assert_eq!(encoded, expected);
+}
@@ -35,3 +35,16 @@ fn test_slice_arc() {
     let deserialized = from_slice::<sync::Arc<[i32]>>(&serialized).unwrap();
     assert_eq!(original, &*deserialized);
 }
+
+#[test]
+fn test_str_arc() {
+    let shared: sync::Arc<str> = sync::Arc::from("alice.near");
+    let serialized = to_vec(&shared).unwrap();
+    // `Arc<str>` is serialized the same way as `String`
+    assert_eq!(
+        serialized,
+        to_vec(&alloc::string::String::from("alice.near")).unwrap()
+    );
+    let deserialized = from_slice::<sync::Arc<str>>(&serialized).unwrap();
+    assert_eq!(shared, deserialized);
+}
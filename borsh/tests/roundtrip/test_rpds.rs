@@ -0,0 +1,111 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::{from_slice, to_vec, BorshSerialize};
+use rpds::{HashTrieMap, HashTrieMapSync, RedBlackTreeMap, Vector, VectorSync};
+
+#[test]
+fn test_rpds_hash_trie_map_roundtrip() {
+    let map = HashTrieMap::<String, u32>::new()
+        .insert("various".to_string(), 1)
+        .insert("foo".to_string(), 2)
+        .insert("many".to_string(), 3);
+
+    let encoded = to_vec(&map).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    // keys are written in ascending order, the same way as for `BTreeMap`
+    let btree = map
+        .iter()
+        .map(|(k, v)| (k.clone(), *v))
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(encoded, to_vec(&btree).unwrap());
+
+    let decoded = from_slice::<HashTrieMap<String, u32>>(&encoded).unwrap();
+    assert_eq!(map, decoded);
+
+    // the pointer kind doesn't affect the encoding
+    let decoded = from_slice::<HashTrieMapSync<String, u32>>(&encoded).unwrap();
+    assert_eq!(to_vec(&decoded).unwrap(), encoded);
+}
+
+#[test]
+fn test_rpds_red_black_tree_map_roundtrip() {
+    let map = RedBlackTreeMap::<String, String>::new()
+        .insert("key".to_string(), "powers".to_string())
+        .insert("more".to_string(), "of".to_string())
+        .insert("different".to_string(), "two".to_string());
+
+    let encoded = to_vec(&map).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let btree = map
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(encoded, to_vec(&btree).unwrap());
+
+    let decoded = from_slice::<RedBlackTreeMap<String, String>>(&encoded).unwrap();
+    assert_eq!(map, decoded);
+}
+
+#[test]
+fn test_rpds_vector_roundtrip() {
+    let vector: Vector<u16> = (0..300).collect();
+
+    let encoded = to_vec(&vector).unwrap();
+    // `Vector<T>` is serialized the same way as `Vec<T>`
+    assert_eq!(encoded, to_vec(&(0..300).collect::<Vec<u16>>()).unwrap());
+
+    let decoded = from_slice::<Vector<u16>>(&encoded).unwrap();
+    assert_eq!(vector, decoded);
+    let decoded = from_slice::<VectorSync<u16>>(&encoded).unwrap();
+    assert_eq!(to_vec(&decoded).unwrap(), encoded);
+
+    assert_eq!(to_vec(&Vector::<u16>::new()).unwrap(), vec![0, 0, 0, 0]);
+}
+
+#[test]
+fn test_rpds_maps_deser_wrong_order() {
+    let mut data = vec![];
+    let entries = [("various", 1u32), ("foo", 2), ("many", 3)];
+    (entries.len() as u32).serialize(&mut data).unwrap();
+    for (key, value) in entries {
+        key.to_string().serialize(&mut data).unwrap();
+        value.serialize(&mut data).unwrap();
+    }
+
+    let hash_trie = from_slice::<HashTrieMap<String, u32>>(&data);
+    let red_black = from_slice::<RedBlackTreeMap<String, u32>>(&data);
+
+    #[cfg(not(feature = "de_strict_order"))]
+    {
+        let hash_trie = hash_trie.unwrap();
+        let red_black = red_black.unwrap();
+        assert_eq!(hash_trie.size(), entries.len());
+        assert_eq!(red_black.size(), entries.len());
+        for (key, value) in entries {
+            assert_eq!(hash_trie.get(key), Some(&value));
+            assert_eq!(red_black.get(key), Some(&value));
+        }
+    }
+
+    #[cfg(feature = "de_strict_order")]
+    {
+        const ERROR_WRONG_ORDER_OF_KEYS: &str = "keys were not serialized in ascending order";
+        assert_eq!(
+            hash_trie.unwrap_err().to_string(),
+            ERROR_WRONG_ORDER_OF_KEYS
+        );
+        assert_eq!(
+            red_black.unwrap_err().to_string(),
+            ERROR_WRONG_ORDER_OF_KEYS
+        );
+    }
+}
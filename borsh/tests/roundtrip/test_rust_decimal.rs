@@ -0,0 +1,35 @@
+use alloc::{vec, vec::Vec};
+use core::str::FromStr;
+
+use borsh::{from_slice, to_vec};
+use rust_decimal::Decimal;
+
+#[test]
+fn test_decimal_roundtrip() {
+    let want = vec![
+        Decimal::ZERO,
+        Decimal::ONE,
+        Decimal::from_str("-123.4500").unwrap(),
+        Decimal::from_str("0.0000000000000000000000000001").unwrap(),
+        Decimal::MAX,
+        Decimal::MIN,
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<Decimal> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+    // scale is preserved
+    assert_eq!(got[2].scale(), 4);
+}
+
+#[test]
+fn test_decimal_layout() {
+    // -1.5 = -15 * 10^-1
+    assert_eq!(
+        to_vec(&Decimal::new(-15, 1)).unwrap(),
+        vec![0, 0, 1, 0x80, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+}
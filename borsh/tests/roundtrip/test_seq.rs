@@ -0,0 +1,78 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::de::SeqReader;
+use borsh::io::ErrorKind;
+use borsh::ser::SeqWriter;
+use borsh::{from_slice, to_vec};
+
+fn names() -> Vec<String> {
+    vec!["alice".to_string(), "bob".to_string(), String::new()]
+}
+
+#[test]
+fn test_seq_writer_matches_vec() {
+    let mut writer = SeqWriter::<String, _>::new(Vec::new(), 3).unwrap();
+    for name in names() {
+        writer.write(&name).unwrap();
+    }
+    assert_eq!(writer.remaining(), 0);
+    let bytes = writer.finish().unwrap();
+    assert_eq!(bytes, to_vec(&names()).unwrap());
+    assert_eq!(from_slice::<Vec<String>>(&bytes).unwrap(), names());
+}
+
+#[test]
+fn test_seq_reader_reads_vec() {
+    let mut bytes = to_vec(&names()).unwrap();
+    bytes.push(7);
+    let mut reader = SeqReader::<String, _>::new(&bytes[..]).unwrap();
+    assert_eq!(reader.len(), 3);
+    let read = reader.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(read, names());
+    assert!(reader.is_empty());
+    assert!(reader.next().is_none());
+    // the reader is left right after the sequence
+    assert_eq!(reader.into_inner(), &[7]);
+}
+
+#[test]
+fn test_seq_reader_empty() {
+    let bytes = to_vec(&Vec::<u64>::new()).unwrap();
+    let mut reader = SeqReader::<u64, _>::new(&bytes[..]).unwrap();
+    assert!(reader.is_empty());
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn test_seq_reader_stops_after_error() {
+    let bytes = to_vec(&vec![1u32, 2, 3]).unwrap();
+    let mut reader = SeqReader::<u32, _>::new(&bytes[..bytes.len() - 1]).unwrap();
+    assert_eq!(reader.next().unwrap().unwrap(), 1);
+    assert_eq!(reader.next().unwrap().unwrap(), 2);
+    assert!(reader.next().unwrap().is_err());
+    assert!(reader.next().is_none());
+
+    assert!(SeqReader::<u32, _>::new(&[1u8, 0][..]).is_err());
+}
+
+#[test]
+fn test_seq_writer_length_mismatch() {
+    let mut writer = SeqWriter::<u8, _>::new(Vec::new(), 1).unwrap();
+    writer.write(&1).unwrap();
+    assert_eq!(writer.write(&2).unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(writer.finish().unwrap(), to_vec(&vec![1u8]).unwrap());
+
+    let mut writer = SeqWriter::<u8, _>::new(Vec::new(), 2).unwrap();
+    writer.write(&1).unwrap();
+    assert_eq!(writer.finish().unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_zero_sized_elements_are_rejected() {
+    assert!(SeqWriter::<(), _>::new(Vec::new(), 1).is_err());
+    assert!(SeqReader::<(), _>::new(&[1u8, 0, 0, 0][..]).is_err());
+}
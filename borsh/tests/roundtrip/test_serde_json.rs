@@ -0,0 +1,104 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use borsh::{from_slice, to_vec};
+use serde_json::{json, Map, Number, Value};
+
+#[test]
+fn test_value_roundtrip() {
+    let want = json!({
+        "null": null,
+        "bool": true,
+        "positive_integer": 12345,
+        "negative_integer": -88888,
+        "float": -888.88,
+        "max": 1.7976931348623157e+308,
+        "string": "Larry",
+        "array": [null, 0, -1, 1.1, "Jake", [1, 2, 3]],
+        "object": {
+            "name": "Pumpkin",
+            "age": 8
+        }
+    });
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Value = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_value_tags() {
+    assert_eq!(to_vec(&Value::Null).unwrap(), vec![0]);
+    assert_eq!(to_vec(&json!(true)).unwrap(), vec![1, 1]);
+    assert_eq!(
+        to_vec(&json!(5)).unwrap(),
+        vec![2, 0, 5, 0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(to_vec(&json!("a")).unwrap(), vec![3, 1, 0, 0, 0, b'a']);
+    assert_eq!(to_vec(&json!([null])).unwrap(), vec![4, 1, 0, 0, 0, 0]);
+    assert_eq!(to_vec(&json!({})).unwrap(), vec![5, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_number_roundtrip() {
+    let want: Vec<Number> = vec![
+        0u64.into(),
+        u64::MAX.into(),
+        (-1i64).into(),
+        i64::MIN.into(),
+        Number::from_f64(0.5).unwrap(),
+        Number::from_f64(-1.0).unwrap(),
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    let got: Vec<Number> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+
+    assert_eq!(
+        to_vec(&Number::from(-1i64)).unwrap(),
+        vec![1, 255, 255, 255, 255, 255, 255, 255, 255]
+    );
+}
+
+#[test]
+fn test_map_canonical_order() {
+    let mut forward = Map::new();
+    let mut backward = Map::new();
+    let keys = ["b", "a", "c", "aa"];
+    for key in keys.iter() {
+        forward.insert(String::from(*key), Value::Null);
+    }
+    for key in keys.iter().rev() {
+        backward.insert(String::from(*key), Value::Null);
+    }
+
+    let encoded = to_vec(&forward).unwrap();
+    assert_eq!(encoded, to_vec(&backward).unwrap());
+    assert_eq!(
+        encoded,
+        vec![
+            4, 0, 0, 0, //
+            1, 0, 0, 0, b'a', 0, //
+            2, 0, 0, 0, b'a', b'a', 0, //
+            1, 0, 0, 0, b'b', 0, //
+            1, 0, 0, 0, b'c', 0,
+        ]
+    );
+
+    let got: Map<String, Value> = from_slice(&encoded).unwrap();
+    assert_eq!(forward, got);
+}
+
+#[test]
+fn test_nested_value_within_depth_limit() {
+    let mut want = Value::Null;
+    for _ in 0..borsh::de::serde_json::MAX_DEPTH {
+        want = Value::Array(vec![want]);
+    }
+
+    let encoded = to_vec(&want).unwrap();
+    let got: Value = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
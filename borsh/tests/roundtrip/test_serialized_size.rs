@@ -0,0 +1,278 @@
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    ffi::CString,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cell::Cell;
+use core::cmp::Reverse;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use core::num::{NonZeroU16, NonZeroUsize, Wrapping};
+use core::ops::Bound;
+use core::sync::atomic::AtomicU64;
+use core::time::Duration;
+
+use borsh::io::{Result, Write};
+use borsh::{to_vec, BorshSerialize};
+
+macro_rules! assert_size_hint {
+    ($($value: expr),+ $(,)?) => {
+        $(
+            let value = $value;
+            assert_eq!(
+                value.serialized_size_hint(),
+                Some(to_vec(&value).unwrap().len()),
+                "{}",
+                stringify!($value)
+            );
+        )+
+    };
+}
+
+#[test]
+fn test_primitives() {
+    assert_size_hint!(
+        0u8,
+        -1i16,
+        u32::MAX,
+        i64::MIN,
+        u128::MAX,
+        usize::MAX,
+        isize::MIN,
+        1.5f32,
+        -2.5f64,
+        true,
+        'ż',
+        (),
+        NonZeroU16::new(7).unwrap(),
+        NonZeroUsize::new(7).unwrap(),
+        AtomicU64::new(3),
+    );
+}
+
+#[test]
+fn test_strings() {
+    assert_size_hint!(
+        "",
+        "hello",
+        "żółw".to_string(),
+        CString::new("hello").unwrap(),
+        Cow::Borrowed("hello"),
+        Box::<str>::from("hello"),
+    );
+}
+
+#[test]
+fn test_options_and_results() {
+    assert_size_hint!(
+        None::<u64>,
+        Some(5u64),
+        Some("hello".to_string()),
+        Ok::<u8, String>(1),
+        Err::<u8, String>("error".to_string()),
+        Bound::Included(5u32),
+        Bound::<u32>::Unbounded,
+    );
+}
+
+#[test]
+fn test_sequences() {
+    assert_size_hint!(
+        Vec::<u64>::new(),
+        vec![1u8, 2, 3],
+        vec![1u64, 2, 3],
+        vec!["a".to_string(), "bc".to_string()],
+        vec![vec![1u16], vec![], vec![2, 3]],
+        vec![Some(1u32), None],
+        [1u32; 5],
+        [[1u8; 3]; 4],
+        ["a".to_string(), "bcd".to_string()],
+        [0u64; 0],
+        VecDeque::from(vec![1u32, 2, 3]),
+        vec!["a".to_string()].into_iter().collect::<LinkedList<_>>(),
+        BinaryHeap::from(vec![3u16, 1, 2]),
+        &[1i32, 2][..],
+    );
+}
+
+#[test]
+fn test_maps_and_sets() {
+    let mut map = BTreeMap::new();
+    map.insert(1u32, "one".to_string());
+    map.insert(2u32, "two".to_string());
+    let mut fixed_map = BTreeMap::new();
+    fixed_map.insert(1u32, 1u64);
+    assert_size_hint!(
+        map,
+        fixed_map,
+        vec!["a".to_string(), "bc".to_string()]
+            .into_iter()
+            .collect::<BTreeSet<_>>(),
+        vec![1u64, 2].into_iter().collect::<BTreeSet<_>>(),
+    );
+    #[cfg(hash_collections)]
+    {
+        use borsh::__private::maybestd::collections::{HashMap, HashSet};
+        assert_size_hint!(
+            vec![(1u8, "one".to_string()), (2, "two".to_string())]
+                .into_iter()
+                .collect::<HashMap<_, _>>(),
+            vec![1u64, 2, 3].into_iter().collect::<HashSet<_>>(),
+        );
+    }
+}
+
+#[test]
+fn test_tuples_and_wrappers() {
+    assert_size_hint!(
+        (1u8, 2u64, "three".to_string()),
+        (vec![1u32], (2u8, 3u16)),
+        Box::new(5u64),
+        Wrapping(5u32),
+        Reverse("hello"),
+        1u64..5,
+        1u8..=5,
+        ..5u16,
+        Duration::new(5, 30),
+        IpAddr::V4(Ipv4Addr::LOCALHOST),
+        IpAddr::V6(Ipv6Addr::LOCALHOST),
+        SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 80),
+        core::marker::PhantomData::<String>,
+    );
+}
+
+#[test]
+fn test_fixed_size_elements() {
+    let big = vec![[0u64; 4]; 1 << 16];
+    assert_eq!(big.serialized_size_hint(), Some(4 + (1 << 16) * 32));
+    let nested = vec![(0u8, 0u32); 1000];
+    assert_eq!(nested.serialized_size_hint(), Some(4 + 1000 * 5));
+}
+
+#[test]
+fn test_to_vec_allocates_exact_capacity() {
+    let value = vec!["a".to_string(), "bc".to_string()];
+    let encoded = to_vec(&value).unwrap();
+    assert_eq!(encoded.capacity(), encoded.len());
+    let encoded = to_vec(&5u8).unwrap();
+    assert_eq!(encoded.capacity(), 1);
+}
+
+/// Type without size hint, which counts how many times it's serialized.
+struct Counted<'a>(&'a Cell<usize>);
+
+impl BorshSerialize for Counted<'_> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.set(self.0.get() + 1);
+        0u16.serialize(writer)
+    }
+}
+
+#[test]
+fn test_no_size_hint() {
+    let count = Cell::new(0);
+    let value = vec![Counted(&count), Counted(&count)];
+    assert_eq!(value.serialized_size_hint(), None);
+    assert_eq!(Some(Counted(&count)).serialized_size_hint(), None);
+    assert_eq!((1u8, Counted(&count)).serialized_size_hint(), None);
+
+    assert_eq!(value.serialized_size().unwrap(), 4 + 2 * 2);
+    assert_eq!(count.get(), 2);
+
+    // serialized once, into a buffer of default capacity
+    count.set(0);
+    assert_eq!(to_vec(&value).unwrap().len(), 4 + 2 * 2);
+    assert_eq!(count.get(), 2);
+}
+
+/// Type, which claims the largest size hint, for checking overflows of sizes of sequences.
+struct Huge;
+
+impl BorshSerialize for Huge {
+    fn serialize<W: Write>(&self, _writer: &mut W) -> Result<()> {
+        unreachable!("only size hint is checked")
+    }
+
+    fn serialized_size_hint(&self) -> Option<usize> {
+        Some(usize::MAX)
+    }
+}
+
+#[test]
+fn test_size_hint_overflow() {
+    assert_eq!(vec![Huge].serialized_size_hint(), None);
+    assert_eq!([Huge, Huge].serialized_size_hint(), None);
+    assert_eq!(BTreeMap::from([(0u8, Huge)]).serialized_size_hint(), None);
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn test_big_integers() {
+    use num_bigint::{BigInt, BigUint};
+
+    assert_size_hint!(
+        BigUint::from(0u8),
+        BigUint::from(255u8),
+        BigUint::from(256u16),
+        BigUint::from(u128::MAX) * 3u8,
+        BigInt::from(-300),
+        BigInt::from(0),
+    );
+}
+
+#[cfg(feature = "bitvec")]
+#[test]
+fn test_bit_vectors() {
+    use bitvec::{bitvec, order::Lsb0};
+
+    assert_size_hint!(
+        bitvec![u8, Lsb0;],
+        bitvec![1; 8],
+        bitvec![1; 9],
+        bitvec![1, 0, 1]
+    );
+}
+
+#[cfg(all(feature = "chrono", feature = "time", feature = "jiff"))]
+#[test]
+fn test_date_time() {
+    assert_size_hint!(
+        chrono::DateTime::from_timestamp(1_700_000_000, 5).unwrap(),
+        chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+        time::OffsetDateTime::UNIX_EPOCH,
+        time::Date::MIN,
+        jiff::Timestamp::UNIX_EPOCH,
+        jiff::civil::date(2024, 2, 29),
+    );
+}
+
+#[cfg(feature = "im")]
+#[test]
+fn test_im_collections() {
+    assert_size_hint!(
+        im::vector!["a".to_string(), "bc".to_string()],
+        im::ordmap! {1u8 => "one".to_string()},
+        im::hashmap! {1u8 => 2u64, 3 => 4},
+    );
+}
+
+#[cfg(feature = "rpds")]
+#[test]
+fn test_rpds_collections() {
+    assert_size_hint!(
+        rpds::vector!["a".to_string(), "bc".to_string()],
+        rpds::rbt_map! {1u8 => "one".to_string()},
+        rpds::ht_map! {1u8 => 2u64, 3 => 4},
+    );
+}
+
+#[test]
+fn test_ref_cell() {
+    let cell = core::cell::RefCell::new(vec![1u32, 2]);
+    assert_size_hint!(&cell);
+    let _borrowed = cell.borrow_mut();
+    assert_eq!(cell.serialized_size_hint(), None);
+}
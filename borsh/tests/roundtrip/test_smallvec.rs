@@ -0,0 +1,32 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use borsh::{from_slice, to_vec};
+use smallvec::{smallvec, SmallVec};
+
+#[test]
+fn test_smallvec_roundtrip() {
+    let inline: SmallVec<[u32; 4]> = smallvec![1, 2, 3];
+    let spilled: SmallVec<[String; 1]> = smallvec!["a".into(), "bc".into()];
+    let want = (inline, spilled);
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+    assert_eq!(
+        encoded,
+        to_vec(&(vec![1u32, 2, 3], vec!["a", "bc"])).unwrap()
+    );
+
+    let got: (SmallVec<[u32; 4]>, SmallVec<[String; 1]>) = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_smallvec_exceeding_inline_capacity() {
+    let want: Vec<u8> = (0..=255).collect();
+    let encoded = to_vec(&want).unwrap();
+
+    let got: SmallVec<[u8; 8]> = from_slice(&encoded).unwrap();
+    assert!(got.spilled());
+    assert_eq!(got.as_slice(), want.as_slice());
+}
@@ -0,0 +1,206 @@
+use alloc::{
+    boxed::Box,
+    collections::BinaryHeap,
+    ffi::CString,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cmp::{Ordering, Reverse};
+use core::ffi::CStr;
+use core::num::{Saturating, Wrapping};
+use core::ops::Bound;
+use core::sync::atomic::{self, AtomicBool, AtomicI32, AtomicU64, AtomicUsize};
+use core::time::Duration;
+
+use borsh::{from_slice, to_vec};
+
+#[test]
+fn test_chars() {
+    let want = ('a', 'ż', '🐢', '\0', char::MAX);
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_duration() {
+    let want = vec![
+        Duration::ZERO,
+        Duration::new(5, 30),
+        Duration::from_nanos(999_999_999),
+        Duration::MAX,
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<Duration> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_system_time() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let want = vec![
+        UNIX_EPOCH,
+        UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789),
+        UNIX_EPOCH - Duration::from_millis(1_500),
+        UNIX_EPOCH - Duration::from_secs(2),
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<SystemTime> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+
+    assert_eq!(
+        to_vec(&(UNIX_EPOCH - Duration::from_millis(1_500))).unwrap(),
+        to_vec(&(-2i64, 500_000_000u32)).unwrap()
+    );
+}
+
+#[test]
+fn test_transparent_wrappers() {
+    let want = (
+        Wrapping(7u8),
+        Saturating(-3i32),
+        Reverse("reversed".to_string()),
+    );
+
+    let encoded = to_vec(&want).unwrap();
+    assert_eq!(encoded, to_vec(&(7u8, -3i32, "reversed")).unwrap());
+
+    let got = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_binary_heap() {
+    let want: BinaryHeap<u32> = vec![5, 1, 8, 3, 8, 2].into();
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+    // NOTE: elements are serialized in ascending order, regardless of the order of insertion
+    assert_eq!(encoded, to_vec(&vec![1u32, 2, 3, 5, 8, 8]).unwrap());
+
+    let got: BinaryHeap<u32> = from_slice(&encoded).unwrap();
+    assert_eq!(want.into_sorted_vec(), got.into_sorted_vec());
+}
+
+#[test]
+fn test_binary_heap_wrong_order() {
+    let encoded = to_vec(&vec![3u32, 1, 2]).unwrap();
+
+    let result = from_slice::<BinaryHeap<u32>>(&encoded);
+
+    #[cfg(not(feature = "de_strict_order"))]
+    assert_eq!(result.unwrap().into_sorted_vec(), vec![1, 2, 3]);
+
+    #[cfg(feature = "de_strict_order")]
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "keys were not serialized in ascending order"
+    );
+}
+
+#[test]
+fn test_c_strings() {
+    let want = CString::new("hello").unwrap();
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+    assert_eq!(encoded, to_vec("hello").unwrap());
+    assert_eq!(encoded, to_vec(want.as_c_str()).unwrap());
+
+    let got: CString = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+
+    let got: Box<CStr> = from_slice(&encoded).unwrap();
+    assert_eq!(want.as_c_str(), &*got);
+}
+
+#[test]
+fn test_unsized_boxes() {
+    let want: (Box<str>, Box<[u16]>) = ("boxed".into(), vec![1u16, 2, 3].into());
+
+    let encoded = to_vec(&want).unwrap();
+    assert_eq!(encoded, to_vec(&("boxed", vec![1u16, 2, 3])).unwrap());
+
+    let got: (Box<str>, Box<[u16]>) = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[cfg(feature = "rc")]
+#[test]
+fn test_unsized_rcs() {
+    use alloc::{rc::Rc, sync::Arc};
+
+    let want: (Rc<str>, Arc<[u16]>, Arc<CStr>) = (
+        "rc".into(),
+        vec![4u16, 5].into(),
+        CString::new("arc").unwrap().into(),
+    );
+
+    let encoded = to_vec(&want).unwrap();
+    assert_eq!(encoded, to_vec(&("rc", vec![4u16, 5], "arc")).unwrap());
+
+    let got: (Rc<str>, Arc<[u16]>, Arc<CStr>) = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_bounds() {
+    let want = vec![
+        Bound::Included(String::from("a")),
+        Bound::Excluded(String::from("z")),
+        Bound::Unbounded,
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<Bound<String>> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_orderings() {
+    let want = [Ordering::Less, Ordering::Equal, Ordering::Greater];
+
+    let encoded = to_vec(&want).unwrap();
+    assert_eq!(encoded, vec![0u8, 1, 2]);
+
+    let got: [Ordering; 3] = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+}
+
+#[test]
+fn test_atomics() {
+    let want = (
+        AtomicBool::new(true),
+        AtomicI32::new(-42),
+        AtomicU64::new(u64::MAX),
+        AtomicUsize::new(17),
+    );
+
+    let encoded = to_vec(&want).unwrap();
+    assert_eq!(encoded, to_vec(&(true, -42i32, u64::MAX, 17usize)).unwrap());
+
+    let got: (AtomicBool, AtomicI32, AtomicU64, AtomicUsize) = from_slice(&encoded).unwrap();
+    assert!(got.0.load(atomic::Ordering::Relaxed));
+    assert_eq!(got.1.load(atomic::Ordering::Relaxed), -42);
+    assert_eq!(got.2.load(atomic::Ordering::Relaxed), u64::MAX);
+    assert_eq!(got.3.load(atomic::Ordering::Relaxed), 17);
+}
@@ -0,0 +1,68 @@
+use alloc::{vec, vec::Vec};
+
+use borsh::{from_slice, to_vec};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+#[test]
+fn test_time_offset_date_time_roundtrip() {
+    let want = vec![
+        OffsetDateTime::UNIX_EPOCH,
+        OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_123_456_789).unwrap(),
+        OffsetDateTime::from_unix_timestamp_nanos(-1_500_000_000).unwrap(),
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<OffsetDateTime> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+
+    assert_eq!(
+        to_vec(&want[2]).unwrap(),
+        to_vec(&(-2i64, 500_000_000u32)).unwrap()
+    );
+
+    let primitive: Vec<PrimitiveDateTime> = from_slice(&encoded).unwrap();
+    assert_eq!(to_vec(&primitive).unwrap(), encoded);
+}
+
+#[test]
+fn test_time_offset_is_not_preserved() {
+    let offset = UtcOffset::from_hms(3, 0, 0).unwrap();
+    let want = OffsetDateTime::from_unix_timestamp(1_700_000_000)
+        .unwrap()
+        .to_offset(offset);
+
+    let got: OffsetDateTime = from_slice(&to_vec(&want).unwrap()).unwrap();
+    // the instant is the same, but it's expressed in UTC
+    assert_eq!(want, got);
+    assert_eq!(got.offset(), UtcOffset::UTC);
+}
+
+#[test]
+fn test_time_date_roundtrip() {
+    let want = vec![
+        Date::from_calendar_date(1970, Month::January, 1).unwrap(),
+        Date::from_calendar_date(2024, Month::February, 29).unwrap(),
+        Date::from_calendar_date(1969, Month::December, 31).unwrap(),
+        // exist with and without `time/large-dates`, which bounds of `Date` depend on
+        Date::from_calendar_date(-9999, Month::January, 1).unwrap(),
+        Date::from_calendar_date(9999, Month::December, 31).unwrap(),
+    ];
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+
+    let got: Vec<Date> = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+
+    assert_eq!(to_vec(&want[0]).unwrap(), to_vec(&0i32).unwrap());
+    assert_eq!(to_vec(&want[2]).unwrap(), to_vec(&-1i32).unwrap());
+
+    for bound in [Date::MIN, Date::MAX] {
+        let got: Date = from_slice(&to_vec(&bound).unwrap()).unwrap();
+        assert_eq!(bound, got);
+    }
+}
@@ -0,0 +1,20 @@
+use alloc::vec;
+
+use borsh::{from_slice, to_vec};
+use tinyvec::{array_vec, tiny_vec, ArrayVec, TinyVec};
+
+#[test]
+fn test_tinyvec_roundtrip() {
+    let bounded: ArrayVec<[u32; 4]> = array_vec!([u32; 4] => 1, 2);
+    let spilled: TinyVec<[u8; 2]> = tiny_vec!([u8; 2] => 5, 6, 7);
+    let want = (bounded, spilled);
+
+    let encoded = to_vec(&want).unwrap();
+    #[cfg(feature = "std")]
+    insta::assert_debug_snapshot!(encoded);
+    assert_eq!(encoded, to_vec(&(vec![1u32, 2], vec![5u8, 6, 7])).unwrap());
+
+    let got: (ArrayVec<[u32; 4]>, TinyVec<[u8; 2]>) = from_slice(&encoded).unwrap();
+    assert_eq!(want, got);
+    assert!(got.1.is_heap());
+}
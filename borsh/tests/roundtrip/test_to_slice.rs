@@ -0,0 +1,70 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use borsh::io::ErrorKind;
+use borsh::{from_slice, to_array, to_slice, to_vec, BorshSerialize};
+
+#[test]
+fn test_to_slice_roundtrip() {
+    let value = ("hello".to_string(), vec![1u64, 2, 3], Some(-5i16), [7u8; 3]);
+    let mut buf = [0u8; 64];
+    let len = to_slice(&value, &mut buf).unwrap();
+    assert_eq!(&buf[..len], to_vec(&value).unwrap().as_slice());
+    assert_eq!(
+        from_slice::<(String, Vec<u64>, Option<i16>, [u8; 3])>(&buf[..len]).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn test_to_slice_exact_fit() {
+    let value = vec![1u32, 2];
+    let mut buf = [0u8; 12];
+    assert_eq!(to_slice(&value, &mut buf).unwrap(), 12);
+    assert_eq!(buf, [2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0]);
+    assert_eq!(to_slice(&(), &mut []).unwrap(), 0);
+}
+
+#[test]
+fn test_to_slice_buffer_too_small() {
+    let value = ("hello".to_string(), 5u64);
+    for available in [0, 3, 4, 8, 16] {
+        let mut buf = vec![0u8; available];
+        let err = to_slice(&value, &mut buf).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WriteZero);
+    }
+    // the required size is reported by `serialized_size`
+    assert_eq!(value.serialized_size().unwrap(), 17);
+}
+
+#[test]
+fn test_to_slice_passes_serialization_errors_through() {
+    let mut buf = [0u8; 64];
+    let err = to_slice(&vec![1.0f64, f64::NAN], &mut buf).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(
+        err.to_string(),
+        "For portability reasons we do not allow to serialize NaNs."
+    );
+    // NaN error is reported even if the buffer is too small for the rest of the value
+    let err = to_slice(&(f64::NAN, 5u64), &mut buf[..8]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_to_array() {
+    assert_eq!(
+        to_array::<8>(&(1u32, 2u16, [3u8; 2])).unwrap(),
+        [1, 0, 0, 0, 2, 0, 3, 3]
+    );
+    assert_eq!(to_array::<0>(&()).unwrap(), [0u8; 0]);
+
+    let err = to_array::<2>(&5u32).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+
+    let err = to_array::<8>(&5u32).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
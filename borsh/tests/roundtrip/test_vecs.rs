@@ -39,3 +39,52 @@ test_vecs!(test_vec_f32, 1000000000.0f32, f32);
 test_vecs!(test_vec_string, "a".to_string(), String);
 test_vecs!(test_vec_vec_u8, vec![100u8; 10], Vec<u8>);
 test_vecs!(test_vec_vec_u32, vec![100u32; 10], Vec<u32>);
+
+/// Checks, that bulk encoding of primitive numbers is the same
+/// as encoding them one by one, both for vectors and arrays.
+macro_rules! test_primitive_bulk_encoding {
+    ($test_name: ident, $t: ty, $gen: expr) => {
+        #[test]
+        fn $test_name() {
+            use borsh::BorshSerialize;
+
+            let gen: fn(usize) -> $t = $gen;
+            for len in [1usize, 3, 257, 70_000] {
+                let v: Vec<$t> = (0..len).map(gen).collect();
+                let mut expected = (len as u32).to_le_bytes().to_vec();
+                for el in &v {
+                    el.serialize(&mut expected).unwrap();
+                }
+                let buf = to_vec(&v).unwrap();
+                assert_eq!(buf, expected);
+                assert_eq!(from_slice::<Vec<$t>>(&buf).unwrap(), v);
+            }
+
+            let arr: [$t; 33] = core::array::from_fn(gen);
+            let mut expected = vec![];
+            for el in &arr {
+                el.serialize(&mut expected).unwrap();
+            }
+            let buf = to_vec(&arr).unwrap();
+            assert_eq!(buf, expected);
+            assert_eq!(from_slice::<[$t; 33]>(&buf).unwrap(), arr);
+        }
+    };
+}
+
+test_primitive_bulk_encoding!(test_bulk_i8, i8, |i| i as i8);
+test_primitive_bulk_encoding!(test_bulk_u16, u16, |i| (i as u16).wrapping_mul(0x9e37));
+test_primitive_bulk_encoding!(test_bulk_i16, i16, |i| (i as i16).wrapping_mul(-0x1e37));
+test_primitive_bulk_encoding!(test_bulk_u32, u32, |i| (i as u32).wrapping_mul(0x9e37_79b9));
+test_primitive_bulk_encoding!(test_bulk_i32, i32, |i| (i as i32)
+    .wrapping_mul(-0x1e37_79b9));
+test_primitive_bulk_encoding!(test_bulk_u64, u64, |i| (i as u64)
+    .wrapping_mul(0x9e37_79b9_7f4a_7c15));
+test_primitive_bulk_encoding!(test_bulk_i64, i64, |i| (i as i64)
+    .wrapping_mul(-0x1e37_79b9_7f4a_7c15));
+test_primitive_bulk_encoding!(test_bulk_u128, u128, |i| (i as u128)
+    .wrapping_mul(0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c834));
+test_primitive_bulk_encoding!(test_bulk_i128, i128, |i| (i as i128)
+    .wrapping_mul(-0x1e37_79b9_7f4a_7c15_f39c_c060_5ced_c834));
+test_primitive_bulk_encoding!(test_bulk_f32, f32, |i| i as f32 * -1.375);
+test_primitive_bulk_encoding!(test_bulk_f64, f64, |i| i as f64 * 1e100 - 0.1);
@@ -0,0 +1,75 @@
+use crate::common_macro::schema_imports::*;
+
+#[cfg(feature = "primitive-types")]
+#[test]
+fn test_primitive_types() {
+    assert_eq!("U256", <primitive_types::U256>::declaration());
+    let mut actual_defs = schema_map!();
+    <(
+        primitive_types::U128,
+        primitive_types::U256,
+        primitive_types::U512,
+    )>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "(U128, U256, U512)" => Definition::Tuple {
+                elements: vec!["U128".into(), "U256".into(), "U512".into()]
+            },
+            "U128" => Definition::Primitive(16),
+            "U256" => Definition::Primitive(32),
+            "U512" => Definition::Primitive(64)
+        },
+        actual_defs
+    );
+}
+
+#[cfg(feature = "ethnum")]
+#[test]
+fn test_ethnum() {
+    let mut actual_defs = schema_map!();
+    <(ethnum::U256, ethnum::I256)>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "(U256, I256)" => Definition::Tuple {
+                elements: vec!["U256".into(), "I256".into()]
+            },
+            "U256" => Definition::Primitive(32),
+            "I256" => Definition::Primitive(32)
+        },
+        actual_defs
+    );
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn test_num_bigint() {
+    assert_eq!("BigInt", <num_bigint::BigInt>::declaration());
+    let mut actual_defs = schema_map!();
+    <num_bigint::BigInt>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "BigInt" => Definition::Struct {
+                fields: Fields::NamedFields(vec![
+                    ("sign".into(), "Sign".into()),
+                    ("magnitude".into(), "BigUint".into()),
+                ])
+            },
+            "Sign" => Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (0, "Minus".into(), "()".into()),
+                    (1, "NoSign".into(), "()".into()),
+                    (2, "Plus".into(), "()".into()),
+                ]
+            },
+            "BigUint" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: "u8".into()
+            },
+            "()" => Definition::Primitive(0),
+            "u8" => Definition::Primitive(1)
+        },
+        actual_defs
+    );
+}
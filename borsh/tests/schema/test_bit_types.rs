@@ -0,0 +1,68 @@
+use crate::common_macro::schema_imports::*;
+
+#[cfg(feature = "bitvec")]
+#[test]
+fn test_bitvec() {
+    assert_eq!("BitVec", <bitvec::vec::BitVec>::declaration());
+    let mut actual_defs = schema_map!();
+    <bitvec::vec::BitVec>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "BitVec" => Definition::Struct {
+                fields: Fields::NamedFields(vec![
+                    ("len".into(), "u32".into()),
+                    ("bytes".into(), "BitVecBytes".into()),
+                ])
+            },
+            "BitVecBytes" => Definition::Sequence {
+                length_width: 0,
+                length_range: 0..=(1 << 29),
+                elements: "u8".into()
+            },
+            "u32" => Definition::Primitive(4),
+            "u8" => Definition::Primitive(1)
+        },
+        actual_defs
+    );
+    let container = BorshSchemaContainer::for_type::<bitvec::vec::BitVec>();
+    assert_eq!(Ok(()), container.validate());
+    assert_eq!(Ok(4 + (1 << 29)), container.max_serialized_size());
+}
+
+#[cfg(all(feature = "bitflags", feature = "derive"))]
+#[test]
+fn test_bitflags_field() {
+    bitflags::bitflags! {
+        struct Permissions: u16 {
+            const READ = 0b01;
+            const WRITE = 0b10;
+        }
+    }
+
+    #[allow(unused)]
+    #[derive(BorshSchema)]
+    struct Account {
+        id: u64,
+        #[borsh(schema(with_funcs(
+            declaration = "borsh::schema::bitflags::declaration::<Permissions>",
+            definitions = "borsh::schema::bitflags::add_definitions_recursively::<Permissions>"
+        )))]
+        permissions: Permissions,
+    }
+
+    let mut actual_defs = schema_map!();
+    Account::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "Account" => Definition::Struct {
+                fields: Fields::NamedFields(vec![
+                    ("id".into(), "u64".into()),
+                    ("permissions".into(), "u16".into()),
+                ])
+            },
+            "u64" => Definition::Primitive(8),
+            "u16" => Definition::Primitive(2)
+        },
+        actual_defs
+    );
+}
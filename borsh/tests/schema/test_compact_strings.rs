@@ -0,0 +1,31 @@
+use crate::common_macro::schema_imports::*;
+
+#[allow(unused)]
+fn string_definitions() -> BTreeMap<String, Definition> {
+    schema_map! {
+        "String" => Definition::Sequence {
+            length_width: Definition::DEFAULT_LENGTH_WIDTH,
+            length_range: Definition::DEFAULT_LENGTH_RANGE,
+            elements: "u8".into()
+        },
+        "u8" => Definition::Primitive(1)
+    }
+}
+
+#[cfg(feature = "smol_str")]
+#[test]
+fn test_smol_str() {
+    assert_eq!("String", smol_str::SmolStr::declaration());
+    let mut actual_defs = schema_map!();
+    smol_str::SmolStr::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(string_definitions(), actual_defs);
+}
+
+#[cfg(feature = "compact_str")]
+#[test]
+fn test_compact_str() {
+    assert_eq!("String", compact_str::CompactString::declaration());
+    let mut actual_defs = schema_map!();
+    compact_str::CompactString::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(string_definitions(), actual_defs);
+}
@@ -0,0 +1,71 @@
+use crate::common_macro::schema_imports::*;
+
+#[allow(unused)]
+fn unix_timestamp_map(declaration: &str) -> BTreeMap<String, Definition> {
+    schema_map! {
+        declaration => Definition::Struct {
+            fields: Fields::NamedFields(vec![
+                ("secs".into(), "i64".into()),
+                ("nanos".into(), "u32".into()),
+            ])
+        },
+        "i64" => Definition::Primitive(8),
+        "u32" => Definition::Primitive(4)
+    }
+}
+
+#[allow(unused)]
+fn unix_date_map(declaration: &str) -> BTreeMap<String, Definition> {
+    schema_map! {
+        declaration => Definition::Struct {
+            fields: Fields::NamedFields(vec![("days".into(), "i32".into())])
+        },
+        "i32" => Definition::Primitive(4)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono() {
+    type DateTimeUtc = chrono::DateTime<chrono::Utc>;
+    assert_eq!("DateTime<Utc>", DateTimeUtc::declaration());
+    let mut actual_defs = schema_map!();
+    DateTimeUtc::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(unix_timestamp_map("DateTime<Utc>"), actual_defs);
+
+    let mut actual_defs = schema_map!();
+    chrono::NaiveDateTime::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(unix_timestamp_map("NaiveDateTime"), actual_defs);
+
+    let mut actual_defs = schema_map!();
+    chrono::NaiveDate::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(unix_date_map("NaiveDate"), actual_defs);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time() {
+    let mut actual_defs = schema_map!();
+    time::OffsetDateTime::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(unix_timestamp_map("OffsetDateTime"), actual_defs);
+
+    let mut actual_defs = schema_map!();
+    time::PrimitiveDateTime::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(unix_timestamp_map("PrimitiveDateTime"), actual_defs);
+
+    let mut actual_defs = schema_map!();
+    time::Date::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(unix_date_map("Date"), actual_defs);
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_jiff() {
+    let mut actual_defs = schema_map!();
+    jiff::Timestamp::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(unix_timestamp_map("Timestamp"), actual_defs);
+
+    let mut actual_defs = schema_map!();
+    jiff::civil::Date::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(unix_date_map("Date"), actual_defs);
+}
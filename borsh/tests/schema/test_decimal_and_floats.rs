@@ -0,0 +1,65 @@
+use crate::common_macro::schema_imports::*;
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn test_rust_decimal() {
+    assert_eq!("Decimal", rust_decimal::Decimal::declaration());
+    let mut actual_defs = schema_map!();
+    rust_decimal::Decimal::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "Decimal" => Definition::Struct {
+                fields: Fields::NamedFields(vec![
+                    ("flags".into(), "u32".into()),
+                    ("lo".into(), "u32".into()),
+                    ("mid".into(), "u32".into()),
+                    ("hi".into(), "u32".into()),
+                ])
+            },
+            "u32" => Definition::Primitive(4)
+        },
+        actual_defs
+    );
+    assert_eq!(
+        Ok(16),
+        BorshSchemaContainer::for_type::<rust_decimal::Decimal>().max_serialized_size()
+    );
+}
+
+#[cfg(feature = "ordered-float")]
+#[test]
+fn test_ordered_float() {
+    use ordered_float::{NotNan, OrderedFloat};
+
+    assert_eq!("f64", <OrderedFloat<f64>>::declaration());
+    assert_eq!("f32", <NotNan<f32>>::declaration());
+    let mut actual_defs = schema_map!();
+    <(OrderedFloat<f64>, NotNan<f32>)>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "(f64, f32)" => Definition::Tuple {
+                elements: vec!["f64".into(), "f32".into()]
+            },
+            "f64" => Definition::Primitive(8),
+            "f32" => Definition::Primitive(4)
+        },
+        actual_defs
+    );
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_half() {
+    let mut actual_defs = schema_map!();
+    <(half::f16, half::bf16)>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "(f16, bf16)" => Definition::Tuple {
+                elements: vec!["f16".into(), "bf16".into()]
+            },
+            "f16" => Definition::Primitive(2),
+            "bf16" => Definition::Primitive(2)
+        },
+        actual_defs
+    );
+}
@@ -0,0 +1,33 @@
+use crate::common_macro::schema_imports::*;
+
+#[test]
+pub fn explicit_tags() {
+    #[allow(dead_code)]
+    #[derive(borsh::BorshSchema)]
+    enum A {
+        #[borsh(tag = 5)]
+        Bacon(u16),
+        Eggs,
+        #[borsh(tag = 1)]
+        Salad,
+    }
+    let mut defs = Default::default();
+    A::add_definitions_recursively(&mut defs);
+    assert_eq!(
+        schema_map! {
+            "A__Bacon" => Definition::Struct { fields: Fields::UnnamedFields(vec!["u16".to_string()]) },
+            "A__Eggs" => Definition::Struct { fields: Fields::Empty },
+            "A__Salad" => Definition::Struct { fields: Fields::Empty },
+            "A" => Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (5, "Bacon".to_string(), "A__Bacon".to_string()),
+                    (6, "Eggs".to_string(), "A__Eggs".to_string()),
+                    (1, "Salad".to_string(), "A__Salad".to_string()),
+                ]
+            },
+            "u16" => Definition::Primitive(2)
+        },
+        defs
+    );
+}
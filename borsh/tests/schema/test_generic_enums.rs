@@ -1,6 +1,6 @@
 use crate::common_macro::schema_imports::*;
 
-#[cfg(feature = "hashbrown")]
+#[cfg(all(feature = "hashbrown", not(feature = "std")))]
 use hashbrown::HashMap;
 #[cfg(feature = "std")]
 use std::collections::HashMap;
@@ -1,6 +1,6 @@
 use crate::common_macro::schema_imports::*;
 
-#[cfg(feature = "hashbrown")]
+#[cfg(all(feature = "hashbrown", not(feature = "std")))]
 use hashbrown::{HashMap, HashSet};
 #[cfg(feature = "std")]
 use std::collections::{HashMap, HashSet};
@@ -57,3 +57,37 @@ fn simple_set() {
         actual_defs
     );
 }
+
+#[cfg(all(feature = "std", feature = "hashbrown"))]
+#[test]
+fn hashbrown_alongside_std() {
+    assert_eq!(
+        "hashbrown::HashMap<u64, String>",
+        hashbrown::HashMap::<u64, String>::declaration()
+    );
+    assert_eq!(
+        "hashbrown::HashSet<u64>",
+        hashbrown::HashSet::<u64>::declaration()
+    );
+    let mut actual_defs = schema_map!();
+    <(HashSet<u64>, hashbrown::HashSet<u64>)>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "(HashSet<u64>, hashbrown::HashSet<u64>)" => Definition::Tuple {
+                elements: vec!["HashSet<u64>".to_string(), "hashbrown::HashSet<u64>".to_string()],
+            },
+            "HashSet<u64>" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: "u64".to_string(),
+            },
+            "hashbrown::HashSet<u64>" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: "u64".to_string(),
+            },
+            "u64" => Definition::Primitive(8)
+        },
+        actual_defs
+    );
+}
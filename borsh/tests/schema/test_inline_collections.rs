@@ -0,0 +1,105 @@
+use crate::common_macro::schema_imports::*;
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_smallvec() {
+    type Inline = smallvec::SmallVec<[u32; 4]>;
+    assert_eq!("Vec<u32>", Inline::declaration());
+    let mut actual_defs = schema_map!();
+    Inline::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "Vec<u32>" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: "u32".to_string()
+            },
+            "u32" => Definition::Primitive(4)
+        },
+        actual_defs
+    );
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_arrayvec() {
+    type Bounded = (arrayvec::ArrayVec<u32, 4>, arrayvec::ArrayString<8>);
+    let mut actual_defs = schema_map!();
+    Bounded::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "(ArrayVec<u32, 4>, ArrayString<8>)" => Definition::Tuple {
+                elements: vec!["ArrayVec<u32, 4>".to_string(), "ArrayString<8>".to_string()]
+            },
+            "ArrayVec<u32, 4>" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: 0..=4,
+                elements: "u32".to_string()
+            },
+            "ArrayString<8>" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: 0..=8,
+                elements: "u8".to_string()
+            },
+            "u32" => Definition::Primitive(4),
+            "u8" => Definition::Primitive(1)
+        },
+        actual_defs
+    );
+}
+
+#[cfg(feature = "tinyvec")]
+#[test]
+fn test_tinyvec() {
+    type Bounded = tinyvec::ArrayVec<[u32; 4]>;
+    assert_eq!("ArrayVec<u32, 4>", Bounded::declaration());
+    let mut actual_defs = schema_map!();
+    Bounded::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "ArrayVec<u32, 4>" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: 0..=4,
+                elements: "u32".to_string()
+            },
+            "u32" => Definition::Primitive(4)
+        },
+        actual_defs
+    );
+
+    assert_eq!("Vec<u8>", <tinyvec::TinyVec<[u8; 2]>>::declaration());
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_heapless() {
+    type Bounded = (heapless::Vec<u16, 3>, heapless::String<16>);
+    let mut actual_defs = schema_map!();
+    Bounded::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "(Vec<u16, 3>, String<16>)" => Definition::Tuple {
+                elements: vec!["Vec<u16, 3>".to_string(), "String<16>".to_string()]
+            },
+            "Vec<u16, 3>" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: 0..=3,
+                elements: "u16".to_string()
+            },
+            "String<16>" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: 0..=16,
+                elements: "u8".to_string()
+            },
+            "u16" => Definition::Primitive(2),
+            "u8" => Definition::Primitive(1)
+        },
+        actual_defs
+    );
+
+    // bounded capacity makes the maximum serialized size finite
+    assert_eq!(
+        Ok(4 + 3 * 2 + 4 + 16),
+        BorshSchemaContainer::for_type::<Bounded>().max_serialized_size()
+    );
+}
@@ -0,0 +1,90 @@
+use crate::common_macro::schema_imports::*;
+
+#[allow(unused)]
+fn string_map_definitions(declaration: &str) -> BTreeMap<String, Definition> {
+    schema_map! {
+        declaration => Definition::Sequence {
+            length_width: Definition::DEFAULT_LENGTH_WIDTH,
+            length_range: Definition::DEFAULT_LENGTH_RANGE,
+            elements: "(String, u64)".into()
+        },
+        "(String, u64)" => Definition::Tuple { elements: vec!["String".into(), "u64".into()] },
+        "String" => Definition::Sequence {
+            length_width: Definition::DEFAULT_LENGTH_WIDTH,
+            length_range: Definition::DEFAULT_LENGTH_RANGE,
+            elements: "u8".into()
+        },
+        "u64" => Definition::Primitive(8),
+        "u8" => Definition::Primitive(1)
+    }
+}
+
+#[allow(unused)]
+fn vec_definitions() -> BTreeMap<String, Definition> {
+    schema_map! {
+        "Vec<u32>" => Definition::Sequence {
+            length_width: Definition::DEFAULT_LENGTH_WIDTH,
+            length_range: Definition::DEFAULT_LENGTH_RANGE,
+            elements: "u32".into()
+        },
+        "u32" => Definition::Primitive(4)
+    }
+}
+
+#[cfg(feature = "im")]
+#[test]
+fn test_im_collections() {
+    type Map = im::HashMap<String, u64>;
+    assert_eq!("im::HashMap<String, u64>", Map::declaration());
+    let mut defs = Default::default();
+    Map::add_definitions_recursively(&mut defs);
+    assert_eq!(string_map_definitions("im::HashMap<String, u64>"), defs);
+
+    type Sorted = im::OrdMap<String, u64>;
+    assert_eq!("im::OrdMap<String, u64>", Sorted::declaration());
+    let mut defs = Default::default();
+    Sorted::add_definitions_recursively(&mut defs);
+    assert_eq!(string_map_definitions("im::OrdMap<String, u64>"), defs);
+
+    type Vector = im::Vector<u32>;
+    assert_eq!("Vec<u32>", Vector::declaration());
+    let mut defs = Default::default();
+    Vector::add_definitions_recursively(&mut defs);
+    assert_eq!(vec_definitions(), defs);
+    assert_eq!(
+        Ok(()),
+        BorshSchemaContainer::for_type::<Vector>().validate()
+    );
+}
+
+#[cfg(feature = "rpds")]
+#[test]
+fn test_rpds_collections() {
+    type Map = rpds::HashTrieMapSync<String, u64>;
+    assert_eq!("rpds::HashTrieMap<String, u64>", Map::declaration());
+    let mut defs = Default::default();
+    Map::add_definitions_recursively(&mut defs);
+    assert_eq!(
+        string_map_definitions("rpds::HashTrieMap<String, u64>"),
+        defs
+    );
+
+    type Sorted = rpds::RedBlackTreeMap<String, u64>;
+    assert_eq!("rpds::RedBlackTreeMap<String, u64>", Sorted::declaration());
+    let mut defs = Default::default();
+    Sorted::add_definitions_recursively(&mut defs);
+    assert_eq!(
+        string_map_definitions("rpds::RedBlackTreeMap<String, u64>"),
+        defs
+    );
+    assert_eq!(
+        Ok(()),
+        BorshSchemaContainer::for_type::<Sorted>().validate()
+    );
+
+    type Vector = rpds::Vector<u32>;
+    assert_eq!("Vec<u32>", Vector::declaration());
+    let mut defs = Default::default();
+    Vector::add_definitions_recursively(&mut defs);
+    assert_eq!(vec_definitions(), defs);
+}
@@ -51,3 +51,13 @@ fn test_slice_arc() {
     <sync::Arc<[i32]> as BorshSchema>::add_definitions_recursively(&mut actual_defs);
     assert_eq!(common_map_slice_i32(), actual_defs);
 }
+
+#[test]
+fn test_str_arc() {
+    assert_eq!("String", <sync::Arc<str> as BorshSchema>::declaration());
+    let mut actual_defs = schema_map!();
+    <sync::Arc<str> as BorshSchema>::add_definitions_recursively(&mut actual_defs);
+    let mut expected_defs = schema_map!();
+    String::add_definitions_recursively(&mut expected_defs);
+    assert_eq!(expected_defs, actual_defs);
+}
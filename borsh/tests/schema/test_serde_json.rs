@@ -0,0 +1,68 @@
+use crate::common_macro::schema_imports::*;
+
+use serde_json::{Map, Value};
+
+#[test]
+fn test_serde_json_value() {
+    assert_eq!("JsonValue", Value::declaration());
+    let mut actual_defs = schema_map!();
+    Value::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "JsonValue" => Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (0, "Null".into(), "()".into()),
+                    (1, "Bool".into(), "bool".into()),
+                    (2, "Number".into(), "JsonNumber".into()),
+                    (3, "String".into(), "String".into()),
+                    (4, "Array".into(), "Vec<JsonValue>".into()),
+                    (5, "Object".into(), "JsonMap".into()),
+                ]
+            },
+            "JsonNumber" => Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (0, "U64".into(), "u64".into()),
+                    (1, "I64".into(), "i64".into()),
+                    (2, "F64".into(), "f64".into()),
+                ]
+            },
+            "JsonMap" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: "(String, JsonValue)".into()
+            },
+            "(String, JsonValue)" => Definition::Tuple {
+                elements: vec!["String".into(), "JsonValue".into()]
+            },
+            "Vec<JsonValue>" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: "JsonValue".into()
+            },
+            "String" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: "u8".into()
+            },
+            "()" => Definition::Primitive(0),
+            "bool" => Definition::Primitive(1),
+            "u8" => Definition::Primitive(1),
+            "u64" => Definition::Primitive(8),
+            "i64" => Definition::Primitive(8),
+            "f64" => Definition::Primitive(8)
+        },
+        actual_defs
+    );
+}
+
+#[test]
+fn test_serde_json_map_container() {
+    let container = BorshSchemaContainer::for_type::<Map<String, Value>>();
+    assert_eq!(Ok(()), container.validate());
+    assert_eq!(
+        Err(SchemaMaxSerializedSizeError::Recursive),
+        container.max_serialized_size()
+    );
+}
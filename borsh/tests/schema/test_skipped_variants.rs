@@ -0,0 +1,36 @@
+use crate::common_macro::schema_imports::*;
+
+#[test]
+pub fn skipped_variant() {
+    /// doesn't implement `BorshSchema`
+    #[allow(unused)]
+    struct Task;
+
+    #[allow(unused)]
+    #[derive(borsh::BorshSchema)]
+    enum State {
+        Idle,
+        #[borsh(skip)]
+        Pending(Task),
+        Done {
+            result: u32,
+        },
+    }
+    let mut defs = Default::default();
+    State::add_definitions_recursively(&mut defs);
+    assert_eq!(
+        schema_map! {
+            "State__Idle" => Definition::Struct { fields: Fields::Empty },
+            "State__Done" => Definition::Struct { fields: Fields::NamedFields(vec![("result".to_string(), "u32".to_string())]) },
+            "State" => Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (0, "Idle".to_string(), "State__Idle".to_string()),
+                    (2, "Done".to_string(), "State__Done".to_string()),
+                ]
+            },
+            "u32" => Definition::Primitive(4)
+        },
+        defs
+    );
+}
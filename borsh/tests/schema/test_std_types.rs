@@ -0,0 +1,177 @@
+use crate::common_macro::schema_imports::*;
+
+#[test]
+fn test_char() {
+    assert_eq!("char", <char>::declaration());
+    let mut actual_defs = schema_map!();
+    <char>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "char" => Definition::Primitive(4)
+        },
+        actual_defs
+    );
+}
+
+#[test]
+fn test_duration() {
+    assert_eq!("Duration", <core::time::Duration>::declaration());
+    let mut actual_defs = schema_map!();
+    <core::time::Duration>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "Duration" => Definition::Struct {
+                fields: Fields::NamedFields(vec![
+                    ("secs".into(), "u64".into()),
+                    ("nanos".into(), "u32".into()),
+                ])
+            },
+            "u64" => Definition::Primitive(8),
+            "u32" => Definition::Primitive(4)
+        },
+        actual_defs
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_system_time() {
+    assert_eq!("SystemTime", <std::time::SystemTime>::declaration());
+    let mut actual_defs = schema_map!();
+    <std::time::SystemTime>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "SystemTime" => Definition::Struct {
+                fields: Fields::NamedFields(vec![
+                    ("secs".into(), "i64".into()),
+                    ("nanos".into(), "u32".into()),
+                ])
+            },
+            "i64" => Definition::Primitive(8),
+            "u32" => Definition::Primitive(4)
+        },
+        actual_defs
+    );
+}
+
+#[test]
+fn test_transparent_wrappers() {
+    type Wrappers = (
+        core::num::Wrapping<u8>,
+        core::num::Saturating<i32>,
+        core::cmp::Reverse<u8>,
+    );
+    assert_eq!("(u8, i32, u8)", <Wrappers>::declaration());
+    let mut actual_defs = schema_map!();
+    <Wrappers>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "(u8, i32, u8)" => Definition::Tuple {
+                elements: vec!["u8".into(), "i32".into(), "u8".into()]
+            },
+            "u8" => Definition::Primitive(1),
+            "i32" => Definition::Primitive(4)
+        },
+        actual_defs
+    );
+}
+
+#[test]
+fn test_binary_heap() {
+    assert_eq!(
+        "BinaryHeap<u32>",
+        <alloc::collections::BinaryHeap<u32>>::declaration()
+    );
+    let mut actual_defs = schema_map!();
+    <alloc::collections::BinaryHeap<u32>>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "BinaryHeap<u32>" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: "u32".into()
+            },
+            "u32" => Definition::Primitive(4)
+        },
+        actual_defs
+    );
+}
+
+#[test]
+fn test_c_string() {
+    assert_eq!("CString", <alloc::ffi::CString>::declaration());
+    assert_eq!("CString", <core::ffi::CStr>::declaration());
+    let mut actual_defs = schema_map!();
+    <alloc::ffi::CString>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "CString" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: "u8".into()
+            },
+            "u8" => Definition::Primitive(1)
+        },
+        actual_defs
+    );
+}
+
+#[test]
+fn test_bound() {
+    assert_eq!("Bound<u64>", <core::ops::Bound<u64>>::declaration());
+    let mut actual_defs = schema_map!();
+    <core::ops::Bound<u64>>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "Bound<u64>" => Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (0, "Included".into(), "u64".into()),
+                    (1, "Excluded".into(), "u64".into()),
+                    (2, "Unbounded".into(), "()".into()),
+                ]
+            },
+            "u64" => Definition::Primitive(8),
+            "()" => Definition::Primitive(0)
+        },
+        actual_defs
+    );
+}
+
+#[test]
+fn test_ordering() {
+    assert_eq!("Ordering", <core::cmp::Ordering>::declaration());
+    let mut actual_defs = schema_map!();
+    <core::cmp::Ordering>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "Ordering" => Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (0, "Less".into(), "()".into()),
+                    (1, "Equal".into(), "()".into()),
+                    (2, "Greater".into(), "()".into()),
+                ]
+            },
+            "()" => Definition::Primitive(0)
+        },
+        actual_defs
+    );
+}
+
+#[test]
+fn test_atomics() {
+    use core::sync::atomic::{AtomicBool, AtomicI64, AtomicUsize};
+
+    assert_eq!("bool", <AtomicBool>::declaration());
+    assert_eq!("i64", <AtomicI64>::declaration());
+    assert_eq!("u64", <AtomicUsize>::declaration());
+    let mut actual_defs = schema_map!();
+    <AtomicUsize>::add_definitions_recursively(&mut actual_defs);
+    assert_eq!(
+        schema_map! {
+            "u64" => Definition::Primitive(8)
+        },
+        actual_defs
+    );
+}
@@ -0,0 +1,39 @@
+use crate::common_macro::schema_imports::*;
+
+#[test]
+pub fn trailing_default() {
+    #[allow(unused)]
+    #[derive(borsh::BorshSchema)]
+    struct A {
+        x: u64,
+        #[borsh(trailing_default)]
+        y: u32,
+    }
+    let mut defs = Default::default();
+    A::add_definitions_recursively(&mut defs);
+    assert_eq!(
+        schema_map! {
+            "A" => Definition::Struct {
+                fields: Fields::NamedFields(vec![
+                    ("x".to_string(), "u64".to_string()),
+                    ("y".to_string(), "TrailingDefault<u32>".to_string()),
+                ])
+            },
+            "TrailingDefault<u32>" => Definition::Enum {
+                tag_width: 0,
+                variants: vec![
+                    (0, "Absent".to_string(), "()".to_string()),
+                    (1, "Present".to_string(), "u32".to_string()),
+                ]
+            },
+            "()" => Definition::Primitive(0),
+            "u32" => Definition::Primitive(4),
+            "u64" => Definition::Primitive(8)
+        },
+        defs
+    );
+
+    let schema = schema_container_of::<A>();
+    assert_eq!(Ok(()), schema.validate());
+    assert_eq!(Ok(12), schema.max_serialized_size());
+}
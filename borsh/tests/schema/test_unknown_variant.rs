@@ -0,0 +1,41 @@
+use crate::common_macro::schema_imports::*;
+
+#[test]
+pub fn unknown_variant() {
+    #[allow(dead_code)]
+    #[derive(borsh::BorshSchema)]
+    enum A {
+        Bacon(u16),
+        #[borsh(unknown)]
+        Unknown {
+            tag: u8,
+            payload: Vec<u8>,
+        },
+    }
+    let mut defs = Default::default();
+    A::add_definitions_recursively(&mut defs);
+    assert_eq!(
+        schema_map! {
+            "A__Bacon" => Definition::Struct { fields: Fields::UnnamedFields(vec!["u16".to_string()]) },
+            "A" => Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (0, "Bacon".to_string(), "A__Bacon".to_string()),
+                    (Definition::UNKNOWN_VARIANT_DISCRIMINANT, "Unknown".to_string(), "UnknownVariantPayload".to_string()),
+                ]
+            },
+            "UnknownVariantPayload" => Definition::Sequence {
+                length_width: Definition::ARRAY_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: "u8".to_string(),
+            },
+            "u16" => Definition::Primitive(2),
+            "u8" => Definition::Primitive(1)
+        },
+        defs
+    );
+
+    let schema = schema_container_of::<A>();
+    assert_eq!(Ok(()), schema.validate());
+    assert_eq!(Ok(1 + u32::MAX as usize), schema.max_serialized_size());
+}
@@ -0,0 +1,52 @@
+use crate::common_macro::schema_imports::*;
+
+#[test]
+pub fn versioned_struct() {
+    #[allow(unused)]
+    #[derive(borsh::BorshSchema)]
+    struct StateV1 {
+        owner: String,
+    }
+
+    #[allow(unused)]
+    #[derive(borsh::BorshSchema)]
+    #[borsh(version = 2, migrate_from(StateV1 = 1))]
+    struct State {
+        owner: String,
+        balance: u64,
+    }
+
+    let mut defs = Default::default();
+    State::add_definitions_recursively(&mut defs);
+    assert_eq!(
+        schema_map! {
+            "State" => Definition::Enum {
+                tag_width: 1,
+                variants: vec![
+                    (1, "V1".to_string(), "StateV1".to_string()),
+                    (2, "V2".to_string(), "State__V2".to_string()),
+                ]
+            },
+            "StateV1" => Definition::Struct {
+                fields: Fields::NamedFields(vec![("owner".to_string(), "String".to_string())])
+            },
+            "State__V2" => Definition::Struct {
+                fields: Fields::NamedFields(vec![
+                    ("owner".to_string(), "String".to_string()),
+                    ("balance".to_string(), "u64".to_string()),
+                ])
+            },
+            "String" => Definition::Sequence {
+                length_width: Definition::DEFAULT_LENGTH_WIDTH,
+                length_range: Definition::DEFAULT_LENGTH_RANGE,
+                elements: "u8".to_string()
+            },
+            "u8" => Definition::Primitive(1),
+            "u64" => Definition::Primitive(8)
+        },
+        defs
+    );
+
+    let schema = schema_container_of::<State>();
+    assert_eq!(Ok(()), schema.validate());
+}
@@ -33,3 +33,20 @@ fn test_to_writer() {
     let deserialized = from_slice::<u8>(&serialized).unwrap();
     assert_eq!(value, deserialized);
 }
+
+#[test]
+fn test_to_slice() {
+    let value = 42u8;
+    let mut serialized = [0; 4];
+    let len = borsh::to_slice(&value, &mut serialized).unwrap();
+    let deserialized = from_slice::<u8>(&serialized[..len]).unwrap();
+    assert_eq!(value, deserialized);
+}
+
+#[test]
+fn test_to_array() {
+    let value = 42u8;
+    let serialized = borsh::to_array::<1>(&value).unwrap();
+    let deserialized = from_slice::<u8>(&serialized).unwrap();
+    assert_eq!(value, deserialized);
+}
@@ -6,8 +6,12 @@ extern crate alloc;
 mod common_macro;
 
 mod custom_reader {
+    #[cfg(feature = "derive")]
+    mod test_borsh_read;
     #[cfg(feature = "derive")]
     mod test_custom_reader;
+    #[cfg(feature = "unstable__schema")]
+    mod test_push_decoder;
 }
 
 /// this module doesn't contain runnable tests;
@@ -41,6 +45,7 @@ mod roundtrip {
     mod test_ip_addr;
     mod test_nonzero_integers;
     mod test_range;
+    mod test_std_types;
     // mod test_phantom_data; // NOTE: there's nothing corresponding to `schema::test_phantom_data`
     // mod test_option; // NOTE: there's nothing corresponding to `schema::test_option`
     // mod test_box; // NOTE: there's nothing corresponding to `schema::test_box`
@@ -55,6 +60,49 @@ mod roundtrip {
     mod test_indexmap;
     #[cfg(feature = "uuid")]
     mod test_uuid;
+    #[cfg(feature = "primitive-types")]
+    mod test_primitive_types;
+    #[cfg(feature = "ethnum")]
+    mod test_ethnum;
+    #[cfg(feature = "num-bigint")]
+    mod test_num_bigint;
+    #[cfg(feature = "chrono")]
+    mod test_chrono;
+    #[cfg(feature = "time")]
+    mod test_time;
+    #[cfg(feature = "jiff")]
+    mod test_jiff;
+    #[cfg(feature = "smallvec")]
+    mod test_smallvec;
+    #[cfg(feature = "arrayvec")]
+    mod test_arrayvec;
+    #[cfg(feature = "tinyvec")]
+    mod test_tinyvec;
+    #[cfg(feature = "heapless")]
+    mod test_heapless;
+    #[cfg(feature = "serde_json")]
+    mod test_serde_json;
+    #[cfg(feature = "rust_decimal")]
+    mod test_rust_decimal;
+    #[cfg(feature = "ordered-float")]
+    mod test_ordered_float;
+    #[cfg(feature = "half")]
+    mod test_half;
+    #[cfg(feature = "bitflags")]
+    mod test_bitflags;
+    #[cfg(feature = "bitvec")]
+    mod test_bitvec;
+    #[cfg(feature = "im")]
+    mod test_im;
+    #[cfg(feature = "rpds")]
+    mod test_rpds;
+    #[cfg(any(feature = "smol_str", feature = "compact_str"))]
+    mod test_compact_strings;
+    mod test_serialized_size;
+    mod test_to_slice;
+    mod test_seq;
+    #[cfg(feature = "bytes")]
+    mod test_bytes_buf;
 
     #[cfg(feature = "derive")]
     mod requires_derive_category {
@@ -66,6 +114,15 @@ mod roundtrip {
         mod test_recursive_enums;
         mod test_serde_with_third_party;
         mod test_enum_discriminants;
+        mod test_unknown_variant;
+        mod test_enum_tags;
+        mod test_skipped_variants;
+        mod test_trailing_default;
+        mod test_versioned;
+        mod test_serialized_size;
+        mod test_blobs;
+        mod test_pod;
+        mod test_view;
         #[cfg(feature = "bytes")]
         mod test_ultimate_many_features_combined;
         #[cfg(feature = "bson")]
@@ -98,6 +155,28 @@ mod schema {
     mod test_cells;
     #[cfg(feature = "rc")]
     mod test_rc;
+    mod test_std_types;
+    #[cfg(any(feature = "primitive-types", feature = "ethnum", feature = "num-bigint"))]
+    mod test_big_integers;
+    #[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+    mod test_date_time;
+    #[cfg(any(
+        feature = "smallvec",
+        feature = "arrayvec",
+        feature = "tinyvec",
+        feature = "heapless"
+    ))]
+    mod test_inline_collections;
+    #[cfg(feature = "serde_json")]
+    mod test_serde_json;
+    #[cfg(any(feature = "rust_decimal", feature = "ordered-float", feature = "half"))]
+    mod test_decimal_and_floats;
+    #[cfg(any(feature = "bitflags", feature = "bitvec"))]
+    mod test_bit_types;
+    #[cfg(any(feature = "im", feature = "rpds"))]
+    mod test_persistent_collections;
+    #[cfg(any(feature = "smol_str", feature = "compact_str"))]
+    mod test_compact_strings;
     mod test_simple_structs;
     mod test_generic_structs;
     mod test_simple_enums;
@@ -106,6 +185,11 @@ mod schema {
     mod test_recursive_enums;
     mod test_schema_with_third_party; // NOTE: this test corresponds to `roundtrip::test_serde_with_third_party`
     mod test_enum_discriminants;
+    mod test_unknown_variant;
+    mod test_enum_tags;
+    mod test_skipped_variants;
+    mod test_trailing_default;
+    mod test_versioned;
     // mod test_ultimate_many_features_combined;  // NOTE: there's nothing corresponding to `roundtrip::test_ultimate_many_features_combined`
     // mod test_bson_object_ids; // NOTE: there's nothing corresponding to `roundtrip::test_bson_object_ids`
     mod schema_conflict {
@@ -121,8 +205,23 @@ mod schema {
 mod deserialization_errors {
     #[cfg(feature = "ascii")]
     mod test_ascii_strings;
+    #[cfg(any(feature = "bitflags", feature = "bitvec"))]
+    mod test_bit_types;
+    #[cfg(any(feature = "arrayvec", feature = "tinyvec", feature = "heapless"))]
+    mod test_bounded_collections;
     mod test_cells;
+    #[cfg(any(feature = "smol_str", feature = "compact_str"))]
+    mod test_compact_strings;
+    #[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+    mod test_date_time;
+    #[cfg(any(feature = "rust_decimal", feature = "ordered-float", feature = "half"))]
+    mod test_decimal_and_floats;
     mod test_initial;
+    #[cfg(feature = "num-bigint")]
+    mod test_num_bigint;
+    #[cfg(feature = "serde_json")]
+    mod test_serde_json;
+    mod test_std_types;
 }
 
 mod init_in_deserialize {
@@ -3,7 +3,7 @@ use alloc::{string::ToString, vec, vec::Vec};
 #[cfg(feature = "std")]
 use std::collections::{HashMap, HashSet};
 
-#[cfg(feature = "hashbrown")]
+#[cfg(all(feature = "hashbrown", not(feature = "std")))]
 use hashbrown::{HashMap, HashSet};
 
 use alloc::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};